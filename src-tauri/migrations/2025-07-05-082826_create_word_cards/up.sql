@@ -1,5 +1,6 @@
 -- Your SQL goes here
-CREATE TABLE word_cards (
+-- IF NOT EXISTS: 舊版程式會在沒有遷移紀錄的情況下自行建立此表格
+CREATE TABLE IF NOT EXISTS word_cards (
   id INTEGER PRIMARY KEY AUTOINCREMENT,
   word TEXT NOT NULL UNIQUE,
   pos TEXT,
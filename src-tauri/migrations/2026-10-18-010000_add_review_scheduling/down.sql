@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_word_cards_due_at;
+ALTER TABLE word_cards DROP COLUMN due_at;
+ALTER TABLE word_cards DROP COLUMN repetitions;
+ALTER TABLE word_cards DROP COLUMN interval_days;
+ALTER TABLE word_cards DROP COLUMN ease_factor;
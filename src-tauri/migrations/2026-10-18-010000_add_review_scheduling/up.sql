@@ -0,0 +1,7 @@
+-- SM-2 排程狀態；due_at 為 NULL 代表尚未複習過的新卡片
+ALTER TABLE word_cards ADD COLUMN ease_factor REAL NOT NULL DEFAULT 2.5;
+ALTER TABLE word_cards ADD COLUMN interval_days INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE word_cards ADD COLUMN repetitions INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE word_cards ADD COLUMN due_at TEXT;
+
+CREATE INDEX idx_word_cards_due_at ON word_cards (due_at);
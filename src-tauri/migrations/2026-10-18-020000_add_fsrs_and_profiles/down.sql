@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS profiles;
+ALTER TABLE word_cards DROP COLUMN last_reviewed_at;
+ALTER TABLE word_cards DROP COLUMN difficulty;
+ALTER TABLE word_cards DROP COLUMN stability;
@@ -0,0 +1,15 @@
+-- FSRS 記憶狀態；NULL 代表尚未以 FSRS 排程過
+ALTER TABLE word_cards ADD COLUMN stability REAL;
+ALTER TABLE word_cards ADD COLUMN difficulty REAL;
+ALTER TABLE word_cards ADD COLUMN last_reviewed_at TEXT;
+
+-- 學習設定檔，決定複習時使用的排程演算法
+CREATE TABLE profiles (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL UNIQUE,
+  scheduler TEXT NOT NULL DEFAULT 'sm2',
+  is_active INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO profiles (name, scheduler, is_active) VALUES ('default', 'sm2', 1);
@@ -0,0 +1,15 @@
+-- 每次作答的紀錄；previous_state / new_state 為 ReviewState 的 JSON
+CREATE TABLE review_logs (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  card_id INTEGER NOT NULL REFERENCES word_cards (id) ON DELETE CASCADE,
+  reviewed_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  grade INTEGER NOT NULL,
+  scheduler TEXT NOT NULL,
+  elapsed_days INTEGER NOT NULL DEFAULT 0,
+  duration_ms INTEGER,
+  previous_state TEXT NOT NULL,
+  new_state TEXT NOT NULL
+);
+
+CREATE INDEX idx_review_logs_card_id ON review_logs (card_id, reviewed_at);
+CREATE INDEX idx_review_logs_reviewed_at ON review_logs (reviewed_at);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS word_cards_fts_after_update;
+DROP TRIGGER IF EXISTS word_cards_fts_after_delete;
+DROP TRIGGER IF EXISTS word_cards_fts_after_insert;
+DROP TABLE IF EXISTS word_cards_fts;
@@ -0,0 +1,27 @@
+-- 單字與定義（含例句）的全文檢索索引，以 external content 方式對應 word_cards
+CREATE VIRTUAL TABLE word_cards_fts USING fts5(
+  word,
+  definition,
+  content = 'word_cards',
+  content_rowid = 'id',
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO word_cards_fts (word_cards_fts) VALUES ('rebuild');
+
+CREATE TRIGGER word_cards_fts_after_insert AFTER INSERT ON word_cards BEGIN
+  INSERT INTO word_cards_fts (rowid, word, definition)
+  VALUES (new.id, new.word, new.definition);
+END;
+
+CREATE TRIGGER word_cards_fts_after_delete AFTER DELETE ON word_cards BEGIN
+  INSERT INTO word_cards_fts (word_cards_fts, rowid, word, definition)
+  VALUES ('delete', old.id, old.word, old.definition);
+END;
+
+CREATE TRIGGER word_cards_fts_after_update AFTER UPDATE OF word, definition ON word_cards BEGIN
+  INSERT INTO word_cards_fts (word_cards_fts, rowid, word, definition)
+  VALUES ('delete', old.id, old.word, old.definition);
+  INSERT INTO word_cards_fts (rowid, word, definition)
+  VALUES (new.id, new.word, new.definition);
+END;
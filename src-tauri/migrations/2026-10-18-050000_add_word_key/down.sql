@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_word_cards_word_key;
+ALTER TABLE word_cards DROP COLUMN word_key;
@@ -0,0 +1,5 @@
+-- 正規化後的單字（小寫、NFC、合併空白），作為唯一索引
+-- 既有資料的 key 由應用程式在遷移後計算，並合併重複的單字卡
+ALTER TABLE word_cards ADD COLUMN word_key TEXT;
+
+CREATE UNIQUE INDEX idx_word_cards_word_key ON word_cards (word_key);
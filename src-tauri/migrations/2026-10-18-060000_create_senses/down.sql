@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS sense_antonyms;
+DROP TABLE IF EXISTS sense_synonyms;
+DROP TABLE IF EXISTS sense_examples;
+DROP TABLE IF EXISTS senses;
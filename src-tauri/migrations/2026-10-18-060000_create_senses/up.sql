@@ -0,0 +1,38 @@
+-- 結構化的詞義：每張單字卡有多個詞義，每個詞義有例句、同義詞與反義詞
+-- word_cards.definition 改為由詞義產生的檢索用文字；既有資料由應用程式在遷移後解析
+CREATE TABLE senses (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  card_id INTEGER NOT NULL REFERENCES word_cards (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  part_of_speech TEXT NOT NULL DEFAULT '',
+  definition TEXT NOT NULL
+);
+
+CREATE INDEX idx_senses_card_id ON senses (card_id, position);
+
+CREATE TABLE sense_examples (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  sense_id INTEGER NOT NULL REFERENCES senses (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  text TEXT NOT NULL
+);
+
+CREATE INDEX idx_sense_examples_sense_id ON sense_examples (sense_id, position);
+
+CREATE TABLE sense_synonyms (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  sense_id INTEGER NOT NULL REFERENCES senses (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  word TEXT NOT NULL
+);
+
+CREATE INDEX idx_sense_synonyms_sense_id ON sense_synonyms (sense_id, position);
+
+CREATE TABLE sense_antonyms (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  sense_id INTEGER NOT NULL REFERENCES senses (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  word TEXT NOT NULL
+);
+
+CREATE INDEX idx_sense_antonyms_sense_id ON sense_antonyms (sense_id, position);
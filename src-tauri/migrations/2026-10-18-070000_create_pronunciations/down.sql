@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE word_cards ADD COLUMN pronunciation TEXT;
+
+UPDATE word_cards
+SET pronunciation = (
+  SELECT json_object('phonetic', COALESCE(p.ipa, ''), 'audio', COALESCE(p.audio_url, ''))
+  FROM pronunciations p
+  WHERE p.card_id = word_cards.id
+  ORDER BY p.position
+  LIMIT 1
+);
+
+DROP TABLE IF EXISTS pronunciations;
@@ -0,0 +1,33 @@
+-- 單字的發音，取代 word_cards.pronunciation 中的 {"phonetic","audio"} JSON 字串
+CREATE TABLE pronunciations (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  card_id INTEGER NOT NULL REFERENCES word_cards (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  region TEXT CHECK (region IN ('uk', 'us')),
+  ipa TEXT,
+  audio_url TEXT,
+  local_path TEXT
+);
+
+CREATE INDEX idx_pronunciations_card_id ON pronunciations (card_id, position);
+
+-- 轉換既有的 JSON；IPA 去除外圍的斜線與方括號，地區由 dictionaryapi.dev 音檔名稱的 -uk / -us 推斷
+INSERT INTO pronunciations (card_id, position, region, ipa, audio_url)
+SELECT id,
+       0,
+       CASE
+         WHEN lower(audio) LIKE '%-uk.%' THEN 'uk'
+         WHEN lower(audio) LIKE '%-us.%' THEN 'us'
+       END,
+       ipa,
+       audio
+FROM (
+  SELECT id,
+         NULLIF(trim(json_extract(pronunciation, '$.phonetic'), ' /[]'), '') AS ipa,
+         NULLIF(trim(json_extract(pronunciation, '$.audio')), '') AS audio
+  FROM word_cards
+  WHERE json_valid(pronunciation) AND json_type(pronunciation) = 'object'
+)
+WHERE ipa IS NOT NULL OR audio IS NOT NULL;
+
+ALTER TABLE word_cards DROP COLUMN pronunciation;
@@ -0,0 +1,15 @@
+-- 單字卡的標籤；name_key 為正規化後的小寫名稱，用於唯一索引
+CREATE TABLE tags (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  name_key TEXT NOT NULL UNIQUE,
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE card_tags (
+  card_id INTEGER NOT NULL REFERENCES word_cards (id) ON DELETE CASCADE,
+  tag_id INTEGER NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+  PRIMARY KEY (card_id, tag_id)
+);
+
+CREATE INDEX idx_card_tags_tag_id ON card_tags (tag_id);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_word_cards_deck_id;
+ALTER TABLE word_cards DROP COLUMN learning_step;
+ALTER TABLE word_cards DROP COLUMN deck_id;
+DROP TABLE IF EXISTS decks;
@@ -0,0 +1,25 @@
+-- 牌組；parent_id 為上層牌組，NULL 代表最上層
+-- learning_steps / relearning_steps 為學習步驟的分鐘數 JSON 陣列，空陣列代表不使用學習步驟
+CREATE TABLE decks (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  parent_id INTEGER REFERENCES decks (id),
+  new_cards_per_day INTEGER NOT NULL DEFAULT 20,
+  reviews_per_day INTEGER NOT NULL DEFAULT 200,
+  learning_steps TEXT NOT NULL DEFAULT '[]',
+  relearning_steps TEXT NOT NULL DEFAULT '[]',
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_decks_parent_id ON decks (parent_id);
+
+-- 預設牌組，既有與未指定牌組的單字卡都放在這裡，不能刪除
+INSERT INTO decks (id, name) VALUES (1, 'Default');
+
+-- SQLite 不允許以 ALTER TABLE 新增有非 NULL 預設值的外鍵欄位，牌組是否存在由應用程式檢查
+ALTER TABLE word_cards ADD COLUMN deck_id INTEGER NOT NULL DEFAULT 1;
+
+-- 目前所在的學習步驟（從 0 開始）；NULL 代表不在學習或重新學習階段
+ALTER TABLE word_cards ADD COLUMN learning_step INTEGER;
+
+CREATE INDEX idx_word_cards_deck_id ON word_cards (deck_id);
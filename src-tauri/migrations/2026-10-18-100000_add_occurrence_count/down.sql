@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE word_cards DROP COLUMN occurrence_count;
@@ -0,0 +1,2 @@
+-- 單字在匯入的檔案與文字中累計出現的次數；手動新增的單字卡為 0
+ALTER TABLE word_cards ADD COLUMN occurrence_count INTEGER NOT NULL DEFAULT 0;
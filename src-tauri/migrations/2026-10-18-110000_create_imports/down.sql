@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS card_sources;
+DROP TABLE IF EXISTS imports;
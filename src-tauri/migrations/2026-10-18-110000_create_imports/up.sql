@@ -0,0 +1,30 @@
+-- 匯入紀錄；file_hash 為檔案內容的 SHA-256，status 為 running / completed / cancelled / undone
+CREATE TABLE imports (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  file_name TEXT,
+  file_hash TEXT,
+  status TEXT NOT NULL DEFAULT 'running',
+  total_count INTEGER NOT NULL DEFAULT 0,
+  success_count INTEGER NOT NULL DEFAULT 0,
+  exists_count INTEGER NOT NULL DEFAULT 0,
+  failed_count INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+  finished_at TEXT
+);
+
+CREATE INDEX idx_imports_file_hash ON imports (file_hash);
+
+-- 單字卡的來源：建立或更新單字卡的匯入（kind = 'import'）或手動查詢（kind = 'lookup'，import_id 為 NULL）
+-- created_card 代表單字卡是否由此來源建立；occurrence_count 為此來源累加的出現次數
+CREATE TABLE card_sources (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  card_id INTEGER NOT NULL REFERENCES word_cards (id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  import_id INTEGER REFERENCES imports (id) ON DELETE CASCADE,
+  created_card BOOLEAN NOT NULL DEFAULT 0,
+  occurrence_count INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_card_sources_card_id ON card_sources (card_id);
+CREATE INDEX idx_card_sources_import_id ON card_sources (import_id);
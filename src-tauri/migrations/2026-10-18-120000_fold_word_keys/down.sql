@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+-- 重新計算後的 key 無法還原為舊的小寫形式，也不需要還原
+SELECT 1;
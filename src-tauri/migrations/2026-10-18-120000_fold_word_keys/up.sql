@@ -0,0 +1,4 @@
+-- word_key 改為 Unicode 大小寫摺疊後，含有摺疊結果與小寫不同字元（ß、ς、ſ 與拉丁連字）的 key 需要重新計算
+-- 清除這些 key 後由應用程式在遷移後重新計算，並合併因此重複的單字卡
+UPDATE word_cards SET word_key = NULL
+WHERE word_key GLOB '*[ßςſﬀﬁﬂﬃﬄﬅﬆ]*';
@@ -0,0 +1,312 @@
+use log::{error, info, warn};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::application::dto::anki::{AnkiImportReport, AnkiSkippedNote, ImportAnkiPackageCommand};
+use crate::application::errors::AppError;
+use crate::application::queries::anki::load_package;
+use crate::domain::entities::imports::{ImportOutcome, ImportStatus, NewCardSource, NewImportRecord};
+use crate::domain::entities::word_cards::{NewWordCard, SaveOutcome};
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+use crate::domain::services::anki::{
+    field_mapping, plain_text, review_logs, review_state, AnkiCard, AnkiCollection, AnkiNote, AnkiPackageReader,
+    AnkiReview, CARD_TYPE_NEW,
+};
+use crate::domain::services::spreadsheet::{build_card, validate_mapping, ColumnField};
+
+/// 同一個單字的所有筆記合併而成的單字卡
+struct PendingCard<'c> {
+    card: NewWordCard,
+    notes: Vec<(&'c AnkiNote, String)>,
+    cards: Vec<&'c AnkiCard>,
+}
+
+/// 將 Anki 套件（.apkg / .colpkg）的筆記匯入為單字卡
+///
+/// 筆記欄位依欄位對應轉為詞義，同一個單字的多則筆記合併為一張單字卡；無法轉換的筆記略過並回報原因。
+/// 新增的單字卡可帶入 Anki 的排程狀態與作答紀錄：一則筆記有多張卡片時，以複習次數最多的一張為準。
+pub struct ImportAnkiPackage<'a> {
+    repository: &'a dyn WordCardRepository,
+    imports: &'a dyn ImportRepository,
+    reader: &'a dyn AnkiPackageReader,
+}
+
+impl<'a> ImportAnkiPackage<'a> {
+    pub fn new(
+        repository: &'a dyn WordCardRepository,
+        imports: &'a dyn ImportRepository,
+        reader: &'a dyn AnkiPackageReader,
+    ) -> Self {
+        Self { repository, imports, reader }
+    }
+
+    pub fn handle(&self, command: ImportAnkiPackageCommand) -> Result<AnkiImportReport, AppError> {
+        let policy = command.policy.unwrap_or_default();
+        let include_history = command.include_history();
+        let collection = load_package(self.reader, &command.path)?;
+        if collection.notes.is_empty() {
+            warn!("Anki 套件沒有筆記");
+            return Err(AppError::Validation("套件中沒有筆記需要匯入".to_string()));
+        }
+
+        let note_types = collection
+            .note_types
+            .iter()
+            .map(|note_type| {
+                let mapping = field_mapping(note_type, &command.field_map);
+                let error = validate_mapping(&mapping).err();
+                (note_type.id, (note_type.name.clone(), mapping, error))
+            })
+            .collect::<HashMap<_, _>>();
+        let cards_by_note = collection.cards_by_note();
+
+        let mut skipped = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut pending: Vec<PendingCard> = Vec::new();
+        for note in &collection.notes {
+            let Some((type_name, mapping, mapping_error)) = note_types.get(&note.note_type_id) else {
+                skipped.push(AnkiSkippedNote {
+                    note_id: note.id,
+                    note_type: note.note_type_id.to_string(),
+                    word: None,
+                    reason: format!("找不到筆記類型 {}", note.note_type_id),
+                });
+                continue;
+            };
+            let values = note.fields.iter().map(|field| plain_text(field)).collect::<Vec<_>>();
+            let word = mapping
+                .iter()
+                .zip(&values)
+                .find(|(field, value)| **field == ColumnField::Word && !value.is_empty())
+                .map(|(_, value)| value.clone());
+            if let Some(reason) = mapping_error {
+                skipped.push(AnkiSkippedNote { note_id: note.id, note_type: type_name.clone(), word, reason: reason.clone() });
+                continue;
+            }
+            let card = match build_card(mapping, &values) {
+                Ok(card) => card,
+                Err(reason) => {
+                    skipped.push(AnkiSkippedNote { note_id: note.id, note_type: type_name.clone(), word, reason });
+                    continue;
+                }
+            };
+
+            let cards = cards_by_note.get(&note.id).cloned().unwrap_or_default();
+            let key = card.word_key.clone().unwrap_or_default();
+            match positions.get(&key) {
+                Some(&position) => {
+                    let existing = &mut pending[position];
+                    existing.card.absorb(card);
+                    existing.notes.push((note, type_name.clone()));
+                    existing.cards.extend(cards);
+                }
+                None => {
+                    positions.insert(key, pending.len());
+                    pending.push(PendingCard { card, notes: vec![(note, type_name.clone())], cards });
+                }
+            }
+        }
+
+        let file_name = Path::new(command.path.trim()).file_name().map(|name| name.to_string_lossy().into_owned());
+        let record = NewImportRecord {
+            file_name,
+            file_hash: Some(collection.content_hash.clone()),
+            total_count: collection.notes.len() as i32,
+        };
+        let record = self.imports.create_import(&record).map_err(|e| {
+            error!("❌ 建立匯入紀錄失敗 - 檔案: {:?}, 錯誤: {}", record.file_name, e);
+            AppError::from(e)
+        })?;
+        let import_id = record.id.unwrap_or_default();
+        info!("開始匯入 Anki 套件 {}: {} 則筆記, {} 個單字", import_id, collection.notes.len(), pending.len());
+
+        let reviews_by_card = collection.reviews_by_card();
+        let mut report = AnkiImportReport {
+            import_id,
+            note_count: collection.notes.len(),
+            inserted_count: 0,
+            exists_count: 0,
+            history_count: 0,
+            review_log_count: 0,
+            skipped: Vec::new(),
+        };
+        for PendingCard { card, notes, cards } in pending {
+            let skip_all = |skipped: &mut Vec<AnkiSkippedNote>, word: Option<String>, reason: String| {
+                skipped.extend(notes.iter().map(|(note, note_type)| AnkiSkippedNote {
+                    note_id: note.id,
+                    note_type: note_type.clone(),
+                    word: word.clone(),
+                    reason: reason.clone(),
+                }));
+            };
+            let word = card.word.clone();
+            let card = match card.normalized() {
+                Ok(card) => card,
+                Err(reason) => {
+                    skip_all(&mut skipped, Some(word), reason);
+                    continue;
+                }
+            };
+            let (outcome, entry) = match self.repository.save(&card, policy) {
+                Ok(saved) => saved,
+                Err(e) => {
+                    error!("❌ 保存單字卡失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+                    skip_all(&mut skipped, Some(card.word.clone()), AppError::from(e).message());
+                    continue;
+                }
+            };
+
+            let created_card = outcome == SaveOutcome::Inserted;
+            let Some(card_id) = entry.card.id else { continue };
+            // 沒有來源的單字卡不會被復原匯入刪除，因此來源寫入失敗時視為略過
+            if let Err(e) = self.imports.add_card_source(&NewCardSource::import(card_id, import_id, created_card, 0)) {
+                error!("❌ 記錄單字卡來源失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+                skip_all(&mut skipped, Some(card.word.clone()), AppError::from(e).message());
+                continue;
+            }
+            if created_card {
+                report.inserted_count += 1;
+            } else {
+                report.exists_count += 1;
+            }
+            if created_card && include_history {
+                if let Some(log_count) = self.restore_history(card_id, &card.word, &cards, &reviews_by_card, &collection) {
+                    report.history_count += 1;
+                    report.review_log_count += log_count;
+                }
+            }
+        }
+
+        skipped.sort_by_key(|note| note.note_id);
+        report.skipped = skipped;
+
+        let outcome = ImportOutcome {
+            status: ImportStatus::Completed,
+            success_count: report.inserted_count as i32,
+            exists_count: report.exists_count as i32,
+            failed_count: report.skipped.len() as i32,
+        };
+        if let Err(e) = self.imports.finish_import(import_id, &outcome) {
+            error!("❌ 更新匯入紀錄失敗 - ID: {}, 錯誤: {}", import_id, e);
+        }
+
+        info!(
+            "✅ Anki 套件匯入 {} 完成: 新增 {}、已存在 {}、帶入排程 {}、略過 {} 則筆記",
+            import_id,
+            report.inserted_count,
+            report.exists_count,
+            report.history_count,
+            report.skipped.len()
+        );
+        Ok(report)
+    }
+
+    /// 以複習次數最多的 Anki 卡片（相同時取 `ord` 較小者）帶入排程狀態與作答紀錄，回傳寫入的作答紀錄數量
+    ///
+    /// 新卡片沒有排程可帶入；失敗時只記錄警告，不影響匯入。
+    fn restore_history(
+        &self,
+        card_id: i32,
+        word: &str,
+        cards: &[&AnkiCard],
+        reviews_by_card: &HashMap<i64, Vec<&AnkiReview>>,
+        collection: &AnkiCollection,
+    ) -> Option<usize> {
+        let reviews_of = |card: &AnkiCard| reviews_by_card.get(&card.id).map(Vec::as_slice).unwrap_or_default();
+        let primary = cards
+            .iter()
+            .filter(|card| card.card_type != CARD_TYPE_NEW)
+            .max_by_key(|card| (reviews_of(card).len(), Reverse(card.ord)))?;
+        let reviews = reviews_of(primary);
+
+        let state = review_state(primary, reviews, collection.created_at);
+        let logs = review_logs(card_id, reviews)
+            .map_err(|e| warn!("⚠️ 轉換作答紀錄失敗 - 單字: '{}', 錯誤: {}", word, e))
+            .ok()?;
+        match self.repository.restore_review_history(card_id, &state, &logs) {
+            Ok(true) => Some(logs.len()),
+            Ok(false) => None,
+            Err(e) => {
+                warn!("⚠️ 帶入排程狀態失敗 - 單字: '{}', 錯誤: {}", word, e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::anki::{AnkiNoteType, CARD_TYPE_REVIEW};
+    use crate::infrastructure::repositories::{InMemoryImportRepository, InMemoryStore, InMemoryWordCardRepository};
+
+    struct FakeReader(AnkiCollection);
+
+    impl AnkiPackageReader for FakeReader {
+        fn read(&self, _path: &str) -> Result<AnkiCollection, String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn note(id: i64, note_type_id: i64, front: &str, back: &str) -> AnkiNote {
+        AnkiNote { id, note_type_id, fields: vec![front.to_string(), back.to_string()] }
+    }
+
+    fn card(id: i64, note_id: i64, ord: i32, card_type: i32) -> AnkiCard {
+        AnkiCard { id, note_id, ord, card_type, queue: card_type, due: 30, interval: 12, factor: 2500, reps: 2, lapses: 0 }
+    }
+
+    fn review(id: i64, card_id: i64) -> AnkiReview {
+        AnkiReview { id, card_id, ease: 3, interval: 12, factor: 2500, duration_ms: 3000, kind: 1 }
+    }
+
+    fn collection() -> AnkiCollection {
+        AnkiCollection {
+            created_at: 1_704_067_200,
+            note_types: vec![AnkiNoteType { id: 1, name: "Basic".to_string(), fields: vec!["Front".into(), "Back".into()] }],
+            notes: vec![
+                note(10, 1, "apple", "<b>蘋果</b>"),
+                note(11, 1, "light", "光"),
+                note(12, 1, "Light", "輕的"),
+                note(13, 1, "cat", ""),
+                note(14, 9, "dog", "狗"),
+            ],
+            cards: vec![card(20, 10, 0, CARD_TYPE_REVIEW), card(21, 10, 1, CARD_TYPE_REVIEW), card(22, 11, 0, CARD_TYPE_NEW)],
+            reviews: vec![review(1_704_153_600_000, 21), review(1_704_240_000_000, 21)],
+            content_hash: "a".repeat(64),
+        }
+    }
+
+    #[test]
+    fn test_imports_notes_with_history_and_skipped_report() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let reader = FakeReader(collection());
+        let import = ImportAnkiPackage::new(&repository, &imports, &reader);
+        let command = ImportAnkiPackageCommand {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string(),
+            ..ImportAnkiPackageCommand::default()
+        };
+
+        let report = import.handle(command.clone()).unwrap();
+        assert_eq!((report.note_count, report.inserted_count, report.exists_count), (5, 2, 0));
+        assert_eq!((report.history_count, report.review_log_count), (1, 2));
+        assert_eq!(report.skipped.iter().map(|note| note.note_id).collect::<Vec<_>>(), [13, 14]);
+        assert_eq!(report.skipped[0].word.as_deref(), Some("cat"));
+
+        let apple = repository.find_by_key("apple").unwrap().unwrap();
+        assert!(apple.definition.as_deref().unwrap().contains("蘋果"));
+        assert_eq!(apple.interval_days, 12);
+        let light = repository.find_by_key("light").unwrap().unwrap();
+        assert!(light.definition.as_deref().unwrap().contains("輕的"));
+        assert_eq!(light.interval_days, 0);
+        let record = imports.find_import(report.import_id).unwrap().unwrap();
+        assert_eq!((record.file_name.as_deref(), record.failed_count), (Some("Cargo.toml"), 2));
+
+        let again = import.handle(ImportAnkiPackageCommand { include_history: Some(false), ..command }).unwrap();
+        assert_eq!((again.inserted_count, again.exists_count, again.history_count), (0, 2, 0));
+    }
+}
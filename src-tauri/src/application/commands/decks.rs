@@ -0,0 +1,261 @@
+use log::{error, info, warn};
+
+use super::{validate_id, validate_ids};
+use crate::application::dto::decks::{
+    CreateDeckCommand, Deck, DeleteDeckCommand, MoveCardsToDeckCommand, MoveDeckCommand, RenameDeckCommand,
+    UpdateDeckSettingsCommand,
+};
+use crate::application::errors::AppError;
+use crate::domain::aggregates::deck::{DeckError, DeckTree, DEFAULT_DECK_ID};
+use crate::domain::repositories::deck_repository::DeckRepository;
+
+fn deck_error(e: DeckError) -> AppError {
+    warn!("⚠️ 牌組操作不合法: {}", e);
+    AppError::from(e)
+}
+
+fn deck_not_found(deck_id: i32) -> AppError {
+    let error_msg = format!("找不到 ID 為 {} 的牌組", deck_id);
+    warn!("⚠️ {}", error_msg);
+    AppError::NotFound(error_msg)
+}
+
+fn load_tree(repository: &dyn DeckRepository) -> Result<DeckTree, AppError> {
+    let decks = repository.list_decks().map_err(|e| {
+        error!("❌ 查詢牌組失敗: {}", e);
+        AppError::from(e)
+    })?;
+    Ok(DeckTree::new(decks))
+}
+
+/// 從樹中取出要修改的牌組
+fn existing(tree: &DeckTree, deck_id: i32) -> Result<Deck, AppError> {
+    validate_id(deck_id, "牌組")?;
+    tree.get(deck_id).cloned().ok_or_else(|| deck_not_found(deck_id))
+}
+
+/// 檢查牌組在樹中的位置與名稱後保存
+fn save(repository: &dyn DeckRepository, tree: &DeckTree, deck: &Deck) -> Result<Deck, AppError> {
+    tree.check_placement(deck).map_err(deck_error)?;
+    repository.save_deck(deck).map_err(|e| {
+        let error_msg = format!("保存牌組失敗 - 名稱: '{}', 錯誤: {}", deck.name, e);
+        error!("❌ {}", error_msg);
+        AppError::from(e)
+    })
+}
+
+/// 建立牌組，可放在其他牌組之下
+pub struct CreateDeck<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> CreateDeck<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: CreateDeckCommand) -> Result<Deck, AppError> {
+        if let Some(parent_id) = command.parent_id {
+            validate_id(parent_id, "上層牌組")?;
+        }
+        let deck = Deck::new(&command.name, command.parent_id, command.settings.unwrap_or_default())
+            .map_err(deck_error)?;
+        info!("建立牌組: '{}' (上層 {:?})", deck.name, deck.parent_id);
+
+        let created = save(self.repository, &load_tree(self.repository)?, &deck)?;
+        info!("✅ 牌組建立成功: '{}' (ID {:?})", created.name, created.id);
+        Ok(created)
+    }
+}
+
+/// 更改牌組名稱；同一層不能有相同名稱
+pub struct RenameDeck<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> RenameDeck<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: RenameDeckCommand) -> Result<Deck, AppError> {
+        let tree = load_tree(self.repository)?;
+        let mut deck = existing(&tree, command.deck_id)?;
+        deck.rename(&command.name).map_err(deck_error)?;
+        info!("更改牌組名稱: ID {} -> '{}'", command.deck_id, deck.name);
+
+        let renamed = save(self.repository, &tree, &deck)?;
+        info!("✅ 牌組名稱更改成功: ID {} -> '{}'", command.deck_id, renamed.name);
+        Ok(renamed)
+    }
+}
+
+/// 將牌組（連同子牌組）移到其他牌組之下或最上層
+pub struct MoveDeck<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> MoveDeck<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: MoveDeckCommand) -> Result<Deck, AppError> {
+        if let Some(parent_id) = command.parent_id {
+            validate_id(parent_id, "上層牌組")?;
+        }
+        let tree = load_tree(self.repository)?;
+        let mut deck = existing(&tree, command.deck_id)?;
+        deck.move_to(command.parent_id);
+        info!("移動牌組: ID {} -> 上層 {:?}", command.deck_id, command.parent_id);
+
+        let moved = save(self.repository, &tree, &deck)?;
+        info!("✅ 牌組移動成功: '{}'", moved.name);
+        Ok(moved)
+    }
+}
+
+/// 更新牌組的每日上限與學習步驟
+pub struct UpdateDeckSettings<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> UpdateDeckSettings<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: UpdateDeckSettingsCommand) -> Result<Deck, AppError> {
+        let tree = load_tree(self.repository)?;
+        let mut deck = existing(&tree, command.deck_id)?;
+        deck.update_settings(command.settings).map_err(deck_error)?;
+        info!("更新牌組設定: ID {} -> {:?}", command.deck_id, deck.settings);
+
+        let updated = save(self.repository, &tree, &deck)?;
+        info!("✅ 牌組設定更新成功: '{}'", updated.name);
+        Ok(updated)
+    }
+}
+
+/// 刪除牌組；子牌組與單字卡移到上層牌組，預設牌組不能刪除
+pub struct DeleteDeck<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> DeleteDeck<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: DeleteDeckCommand) -> Result<(), AppError> {
+        let deck_id = command.deck_id;
+        validate_id(deck_id, "牌組")?;
+        if deck_id == DEFAULT_DECK_ID {
+            warn!("嘗試刪除預設牌組");
+            return Err(AppError::Validation("預設牌組不能刪除".to_string()));
+        }
+
+        info!("刪除牌組: ID {}", deck_id);
+        let deleted = self.repository.delete_deck(deck_id).map_err(|e| {
+            let error_msg = format!("刪除牌組失敗 - ID: {}, 錯誤: {}", deck_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        if !deleted {
+            return Err(deck_not_found(deck_id));
+        }
+
+        info!("✅ 牌組刪除成功: ID {}", deck_id);
+        Ok(())
+    }
+}
+
+/// 批次將單字卡移到指定牌組；回傳實際移動的數量
+pub struct MoveCardsToDeck<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> MoveCardsToDeck<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: MoveCardsToDeckCommand) -> Result<usize, AppError> {
+        validate_ids(&command.card_ids, "單字卡")?;
+        validate_id(command.deck_id, "牌組")?;
+
+        info!("移動單字卡: {} 張 -> 牌組 ID {}", command.card_ids.len(), command.deck_id);
+        let moved = self
+            .repository
+            .move_cards(&command.card_ids, command.deck_id)
+            .map_err(|e| {
+                let error_msg = format!("移動單字卡失敗 - 牌組: {}, 錯誤: {}", command.deck_id, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?;
+
+        info!("✅ 成功移動 {} 張單字卡", moved);
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::decks::DeckSettings;
+    use crate::infrastructure::repositories::{InMemoryDeckRepository, InMemoryStore};
+
+    fn create(repository: &InMemoryDeckRepository, name: &str, parent_id: Option<i32>) -> Result<Deck, AppError> {
+        CreateDeck::new(repository).handle(CreateDeckCommand { name: name.to_string(), parent_id, settings: None })
+    }
+
+    #[test]
+    fn test_create_rename_and_move_check_the_tree() {
+        let repository = InMemoryDeckRepository::new(InMemoryStore::new());
+        let english = create(&repository, "English", None).unwrap().id.unwrap();
+        let toeic = create(&repository, "TOEIC", Some(english)).unwrap().id.unwrap();
+        create(&repository, "GRE", Some(english)).unwrap();
+
+        assert_eq!(create(&repository, "toeic", Some(english)).unwrap_err().code(), "CONFLICT");
+        assert_eq!(create(&repository, "Reading", Some(99)).unwrap_err().code(), "NOT_FOUND");
+        assert_eq!(create(&repository, " ", None).unwrap_err().code(), "VALIDATION");
+
+        let rename = RenameDeck::new(&repository);
+        let clash = rename.handle(RenameDeckCommand { deck_id: toeic, name: "gre".to_string() });
+        assert_eq!(clash.unwrap_err().code(), "CONFLICT");
+        assert_eq!(rename.handle(RenameDeckCommand { deck_id: toeic, name: "TOEFL".to_string() }).unwrap().name, "TOEFL");
+
+        let move_deck = MoveDeck::new(&repository);
+        let cycle = move_deck.handle(MoveDeckCommand { deck_id: english, parent_id: Some(toeic) });
+        assert_eq!(cycle.unwrap_err().code(), "VALIDATION");
+        let top = move_deck.handle(MoveDeckCommand { deck_id: toeic, parent_id: None }).unwrap();
+        assert_eq!(top.parent_id, None);
+    }
+
+    #[test]
+    fn test_settings_and_delete_rules() {
+        let repository = InMemoryDeckRepository::new(InMemoryStore::new());
+        let english = create(&repository, "English", None).unwrap().id.unwrap();
+
+        let update = UpdateDeckSettings::new(&repository);
+        let settings = DeckSettings { learning_steps: vec![1, 10], ..DeckSettings::default() };
+        let updated = update.handle(UpdateDeckSettingsCommand { deck_id: english, settings }).unwrap();
+        assert_eq!(updated.settings.learning_steps, [1, 10]);
+        let invalid = DeckSettings { reviews_per_day: -5, ..DeckSettings::default() };
+        let rejected = update.handle(UpdateDeckSettingsCommand { deck_id: english, settings: invalid });
+        assert_eq!(rejected.unwrap_err().code(), "VALIDATION");
+
+        let delete = DeleteDeck::new(&repository);
+        let default_deck = delete.handle(DeleteDeckCommand { deck_id: DEFAULT_DECK_ID });
+        assert_eq!(default_deck.unwrap_err().code(), "VALIDATION");
+        delete.handle(DeleteDeckCommand { deck_id: english }).unwrap();
+        assert_eq!(delete.handle(DeleteDeckCommand { deck_id: english }).unwrap_err().code(), "NOT_FOUND");
+
+        let move_cards = MoveCardsToDeck::new(&repository);
+        let empty = move_cards.handle(MoveCardsToDeckCommand { card_ids: Vec::new(), deck_id: DEFAULT_DECK_ID });
+        assert_eq!(empty.unwrap_err().code(), "VALIDATION");
+        let missing = move_cards.handle(MoveCardsToDeckCommand { card_ids: vec![1], deck_id: DEFAULT_DECK_ID });
+        assert_eq!(missing.unwrap_err().code(), "NOT_FOUND");
+    }
+}
@@ -0,0 +1,576 @@
+use log::{error, info, warn};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::validate_id;
+use crate::application::dto::imports::{
+    ImportEntry, ImportItemResult, ImportItemStatus, ImportJobStatus, ImportOptions, ImportProgress, ImportReport,
+    UndoImportCommand, MAX_IMPORT_WORDS,
+};
+use crate::application::errors::AppError;
+use crate::domain::entities::imports::{
+    ImportOutcome, ImportRecord, ImportStatus, ImportUndoSummary, NewCardSource, NewImportRecord,
+};
+use crate::domain::entities::word_cards::{MergePolicy, SaveOutcome, WordCardEntry};
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+use crate::domain::services::dictionary::Dictionary;
+use crate::domain::value_objects::word::{fold_key, Word};
+
+/// 保留最近完成的匯入報告數量
+const MAX_FINISHED_REPORTS: usize = 20;
+
+/// 等待期間檢查是否取消的間隔
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+#[derive(Default)]
+struct JobsState {
+    /// 執行中的工作與其取消旗標
+    running: HashMap<u64, Arc<AtomicBool>>,
+    finished: VecDeque<ImportReport>,
+}
+
+/// 背景匯入工作的登記處，由 Tauri 管理為共用狀態
+///
+/// 工作 ID 與匯入紀錄的 ID 相同。可以複製後移到背景執行緒，複製出的實例共用同一份資料。
+#[derive(Clone, Default)]
+pub struct ImportJobs {
+    state: Arc<Mutex<JobsState>>,
+}
+
+impl ImportJobs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, JobsState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 登記新的工作，回傳取消旗標
+    pub fn register(&self, job_id: u64) -> Arc<AtomicBool> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.state().running.insert(job_id, Arc::clone(&cancelled));
+        cancelled
+    }
+
+    pub fn is_running(&self, job_id: u64) -> bool {
+        self.state().running.contains_key(&job_id)
+    }
+
+    /// 要求取消工作；工作不存在或已結束時回傳 `false`
+    pub fn cancel(&self, job_id: u64) -> bool {
+        match self.state().running.get(&job_id) {
+            Some(cancelled) => {
+                cancelled.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 工作結束：移出執行中清單並保存報告
+    pub fn finish(&self, report: ImportReport) {
+        let mut state = self.state();
+        state.running.remove(&report.job_id);
+        if state.finished.len() >= MAX_FINISHED_REPORTS {
+            state.finished.pop_front();
+        }
+        state.finished.push_back(report);
+    }
+
+    /// 已結束工作的報告；工作仍在執行或報告已被清除時為 `None`
+    pub fn report(&self, job_id: u64) -> Option<ImportReport> {
+        self.state().finished.iter().find(|r| r.job_id == job_id).cloned()
+    }
+}
+
+/// 批次匯入單字：已有單字卡的單字略過，其餘查詢字典後新增為單字卡
+///
+/// 逐一處理並回報每個單字的結果，每個單字之間檢查取消旗標。
+/// 新增或比對到的單字卡都會記錄來源，之後可以復原整個匯入。
+pub struct ImportWords<'a> {
+    repository: &'a dyn WordCardRepository,
+    imports: &'a dyn ImportRepository,
+    dictionary: &'a dyn Dictionary,
+}
+
+impl<'a> ImportWords<'a> {
+    pub fn new(
+        repository: &'a dyn WordCardRepository,
+        imports: &'a dyn ImportRepository,
+        dictionary: &'a dyn Dictionary,
+    ) -> Self {
+        Self { repository, imports, dictionary }
+    }
+
+    /// 建立匯入紀錄；回傳的 ID 即為工作 ID
+    pub fn begin(&self, words: &[ImportEntry], options: &ImportOptions) -> Result<ImportRecord, AppError> {
+        let provided = |value: &Option<String>| value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(String::from);
+        let record = NewImportRecord {
+            file_name: provided(&options.file_name),
+            file_hash: provided(&options.file_hash),
+            total_count: words.len() as i32,
+        };
+
+        let created = self.imports.create_import(&record).map_err(|e| {
+            let error_msg = format!("建立匯入紀錄失敗 - 檔案: {:?}, 錯誤: {}", record.file_name, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+        info!("✅ 建立匯入紀錄: ID {:?}, 檔案: {:?}", created.id, created.file_name);
+        Ok(created)
+    }
+
+    /// 整理匯入清單：去除頭尾空白與空白行，並不分大小寫合併重複的單字，出現次數相加
+    pub fn prepare(entries: Vec<ImportEntry>) -> Result<Vec<ImportEntry>, AppError> {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut words: Vec<ImportEntry> = Vec::new();
+        for entry in entries {
+            let word = entry.word.trim().to_string();
+            if word.is_empty() {
+                continue;
+            }
+            let key = fold_key(&word);
+            match positions.get(&key) {
+                Some(&position) => words[position].count = words[position].count.saturating_add(entry.count),
+                None => {
+                    positions.insert(key, words.len());
+                    words.push(ImportEntry { word, count: entry.count });
+                }
+            }
+        }
+
+        if words.is_empty() {
+            warn!("匯入清單為空");
+            return Err(AppError::Validation("沒有單字需要匯入".to_string()));
+        }
+        if words.len() > MAX_IMPORT_WORDS {
+            warn!("匯入單字數量過多: {}", words.len());
+            return Err(AppError::Validation(format!("一次最多只能匯入 {} 個單字", MAX_IMPORT_WORDS)));
+        }
+        Ok(words)
+    }
+
+    /// 執行 `begin` 建立的匯入，結束時將狀態與數量寫回匯入紀錄
+    pub fn handle(
+        &self,
+        import_id: i32,
+        words: &[ImportEntry],
+        options: &ImportOptions,
+        cancelled: &AtomicBool,
+        on_progress: &mut dyn FnMut(&ImportProgress),
+    ) -> ImportReport {
+        let job_id = import_id as u64;
+        let delay = Duration::from_millis(options.delay_ms());
+        info!("開始匯入工作 {}: {} 個單字", job_id, words.len());
+
+        let mut report = ImportReport::new(job_id, words.len());
+        for (index, entry) in words.iter().enumerate() {
+            if cancelled.load(Ordering::SeqCst) {
+                report.status = ImportJobStatus::Cancelled;
+                break;
+            }
+
+            let (mut result, queried) = self.import_word(&entry.word);
+            self.record_source(&mut result, import_id, entry.count);
+            on_progress(&ImportProgress { job_id, index: index + 1, total: words.len(), result: result.clone() });
+            report.record(result);
+
+            // 只在查詢過字典後等待，避免請求過於頻繁
+            if queried && index + 1 < words.len() {
+                wait(delay, cancelled);
+            }
+        }
+
+        let status = match report.status {
+            ImportJobStatus::Completed => ImportStatus::Completed,
+            ImportJobStatus::Cancelled => ImportStatus::Cancelled,
+        };
+        let outcome = ImportOutcome {
+            status,
+            success_count: report.success_count as i32,
+            exists_count: report.exists_count as i32,
+            failed_count: report.failed_count as i32,
+        };
+        if let Err(e) = self.imports.finish_import(import_id, &outcome) {
+            error!("❌ 更新匯入紀錄失敗 - ID: {}, 錯誤: {}", import_id, e);
+        }
+
+        if report.status == ImportJobStatus::Cancelled {
+            warn!("⚠️ 匯入工作 {} 已取消，處理了 {} / {} 個單字", job_id, report.results.len(), report.total);
+        } else {
+            info!(
+                "✅ 匯入工作 {} 完成: 成功 {}、已存在 {}、失敗 {}",
+                job_id, report.success_count, report.exists_count, report.failed_count
+            );
+        }
+        report
+    }
+
+    /// 匯入單一單字，回傳結果與是否查詢過字典
+    fn import_word(&self, raw: &str) -> (ImportItemResult, bool) {
+        let result = |status: ImportItemStatus, card: Option<WordCardEntry>, error: Option<String>| ImportItemResult {
+            word: raw.to_string(),
+            status,
+            card,
+            error,
+        };
+        let failed = |message: String| {
+            warn!("⚠️ 匯入單字失敗: '{}', 錯誤: {}", raw, message);
+            result(ImportItemStatus::Failed, None, Some(message))
+        };
+
+        let word = match Word::parse(raw) {
+            Ok(word) => word,
+            Err(e) => return (failed(e), false),
+        };
+
+        let existing = self.repository.find_by_key(word.key()).and_then(|found| match found {
+            Some(card) => Ok(self.repository.with_details(vec![card])?.pop()),
+            None => Ok(None),
+        });
+        match existing {
+            Ok(Some(entry)) => return (result(ImportItemStatus::Exists, Some(entry), None), false),
+            Ok(None) => {}
+            Err(e) => {
+                error!("❌ 查詢單字卡失敗 - 單字: '{}', 錯誤: {}", raw, e);
+                return (failed(AppError::from(e).message()), false);
+            }
+        }
+
+        // 先查字典：本身就是詞條的字（如 seed、news）建立自己的單字卡，
+        // 字典沒有的變化形（如 apples）才比對詞元（apple）的單字卡
+        let card = match self.dictionary.lookup(word.as_str()) {
+            Ok(Some(card)) => card,
+            Ok(None) => {
+                let lemma = self.repository.find_by_lemma(word.key(), &[]).and_then(|found| match found {
+                    Some(card) => Ok(self.repository.with_details(vec![card])?.pop()),
+                    None => Ok(None),
+                });
+                return match lemma {
+                    Ok(Some(entry)) => (result(ImportItemStatus::Exists, Some(entry), None), true),
+                    Ok(None) => (failed(format!("找不到 \"{}\" 的定義", word.as_str())), true),
+                    Err(e) => {
+                        error!("❌ 查詢詞元單字卡失敗 - 單字: '{}', 錯誤: {}", raw, e);
+                        (failed(AppError::from(e).message()), true)
+                    }
+                };
+            }
+            Err(e) => return (failed(e), true),
+        };
+        let card = match card.normalized() {
+            Ok(card) => card,
+            Err(e) => return (failed(e), true),
+        };
+
+        match self.repository.save(&card, MergePolicy::KeepExisting) {
+            // 字典回傳的詞條可能是已存在的詞元
+            Ok((SaveOutcome::Inserted, entry)) => (result(ImportItemStatus::Success, Some(entry), None), true),
+            Ok((_, entry)) => (result(ImportItemStatus::Exists, Some(entry), None), true),
+            Err(e) => {
+                error!("❌ 保存單字卡失敗 - 單字: '{}', 錯誤: {}", raw, e);
+                (failed(AppError::from(e).message()), true)
+            }
+        }
+    }
+
+    /// 記錄單字卡來自此匯入，並將出現次數累加到單字卡上
+    ///
+    /// 來源與次數由儲存庫一起寫入；寫入失敗時此單字標示為失敗，因為復原匯入時不會處理沒有來源的單字卡。
+    fn record_source(&self, result: &mut ImportItemResult, import_id: i32, count: i32) {
+        let created_card = result.status == ImportItemStatus::Success;
+        let Some(entry) = result.card.as_mut() else {
+            return;
+        };
+        let Some(card_id) = entry.card.id else {
+            return;
+        };
+
+        let source = NewCardSource::import(card_id, import_id, created_card, count);
+        match self.imports.add_card_source(&source) {
+            Ok(_) => entry.card.occurrence_count = entry.card.occurrence_count.saturating_add(count),
+            Err(e) => {
+                error!("❌ 記錄單字卡來源失敗 - 單字: '{}', 錯誤: {}", result.word, e);
+                result.status = ImportItemStatus::Failed;
+                result.error = Some(AppError::from(e).message());
+            }
+        }
+    }
+}
+
+/// 復原整個匯入：刪除由此匯入建立、且沒有其他來源的單字卡，其餘單字卡扣回累加的出現次數
+pub struct UndoImport<'a> {
+    repository: &'a dyn ImportRepository,
+    jobs: &'a ImportJobs,
+}
+
+impl<'a> UndoImport<'a> {
+    pub fn new(repository: &'a dyn ImportRepository, jobs: &'a ImportJobs) -> Self {
+        Self { repository, jobs }
+    }
+
+    pub fn handle(&self, command: UndoImportCommand) -> Result<ImportUndoSummary, AppError> {
+        let import_id = command.import_id;
+        validate_id(import_id, "匯入紀錄")?;
+
+        let record = self.repository.find_import(import_id).map_err(|e| {
+            error!("❌ 查詢匯入紀錄失敗 - ID: {}, 錯誤: {}", import_id, e);
+            AppError::from(e)
+        })?;
+        let Some(record) = record else {
+            let error_msg = format!("找不到 ID 為 {} 的匯入紀錄", import_id);
+            warn!("⚠️ {}", error_msg);
+            return Err(AppError::NotFound(error_msg));
+        };
+        if record.status() == ImportStatus::Undone {
+            warn!("匯入紀錄已復原過: ID {}", import_id);
+            return Err(AppError::Conflict(format!("匯入紀錄 {} 已經復原", import_id)));
+        }
+        // 應用程式在匯入途中關閉時，狀態會停在 running，這類紀錄仍可復原
+        if self.jobs.is_running(import_id as u64) {
+            warn!("匯入工作仍在執行: ID {}", import_id);
+            return Err(AppError::Conflict("匯入仍在進行中，請先取消或等待完成".to_string()));
+        }
+
+        info!("復原匯入: ID {}", import_id);
+        let summary = self.repository.undo_import(import_id).map_err(|e| {
+            let error_msg = format!("復原匯入失敗 - ID: {}, 錯誤: {}", import_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        info!(
+            "✅ 匯入 {} 已復原: 刪除 {} 張單字卡, 更新 {} 張單字卡",
+            import_id, summary.deleted_count, summary.updated_count
+        );
+        Ok(summary)
+    }
+}
+
+/// 等待 `delay`，取消時提早結束
+fn wait(delay: Duration, cancelled: &AtomicBool) {
+    let deadline = Instant::now() + delay;
+    while !cancelled.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        thread::sleep(CANCEL_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::senses::Sense;
+    use crate::domain::entities::word_cards::NewWordCard;
+    use crate::infrastructure::repositories::{InMemoryImportRepository, InMemoryStore, InMemoryWordCardRepository};
+
+    /// 只認得固定單字的字典
+    struct FakeDictionary;
+
+    impl Dictionary for FakeDictionary {
+        fn lookup(&self, word: &str) -> Result<Option<NewWordCard>, String> {
+            match word {
+                "offline" => Err("無法連線到字典服務".to_string()),
+                "apple" | "cherry" | "run" | "see" | "seed" | "new" | "news" | "the" | "thing" | "be" | "bed" => Ok(Some(NewWordCard {
+                    word: word.to_string(),
+                    pos: None,
+                    definition: None,
+                    verbs: None,
+                    familiarity: Some(0),
+                    seen_count: Some(1),
+                    word_key: None,
+                    senses: vec![Sense {
+                        part_of_speech: "noun".to_string(),
+                        definition: format!("the word {}", word),
+                        examples: Vec::new(),
+                        synonyms: Vec::new(),
+                        antonyms: Vec::new(),
+                    }],
+                    pronunciations: Vec::new(),
+                })),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    fn import(words: &[&str], cancel_after: Option<usize>) -> (ImportReport, Vec<ImportProgress>) {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let words = ImportWords::prepare(words.iter().map(|w| ImportEntry::from(w.to_string())).collect()).unwrap();
+        let cancelled = AtomicBool::new(false);
+        let mut events = Vec::new();
+        let options = ImportOptions { delay_ms: Some(0), ..ImportOptions::default() };
+        let import = ImportWords::new(&repository, &imports, &FakeDictionary);
+        let record = import.begin(&words, &options).unwrap();
+        let report = import.handle(record.id.unwrap(), &words, &options, &cancelled, &mut |p| {
+            events.push(p.clone());
+            if cancel_after == Some(events.len()) {
+                cancelled.store(true, Ordering::SeqCst);
+            }
+        });
+        (report, events)
+    }
+
+    fn entry(word: &str, count: i32) -> ImportEntry {
+        ImportEntry { word: word.to_string(), count }
+    }
+
+    #[test]
+    fn test_prepare_trims_and_merges_counts() {
+        let words = vec![entry(" Apple ", 2), entry("", 1), entry("apple", 3), entry("cherry", 0)];
+        assert_eq!(ImportWords::prepare(words).unwrap(), [entry("Apple", 5), entry("cherry", 0)]);
+        assert_eq!(ImportWords::prepare(vec![entry("  ", 1)]).unwrap_err().code(), "VALIDATION");
+
+        let parsed: Vec<ImportEntry> = serde_json::from_str(r#"["apple", {"word": "bed", "count": 4}, {"word": "cool"}]"#).unwrap();
+        assert_eq!(parsed, [entry("apple", 0), entry("bed", 4), entry("cool", 0)]);
+    }
+
+    #[test]
+    fn test_occurrence_counts_accumulate_on_cards() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let import = ImportWords::new(&repository, &imports, &FakeDictionary);
+        let options = ImportOptions { delay_ms: Some(0), ..ImportOptions::default() };
+        let cancelled = AtomicBool::new(false);
+        let run = |words: Vec<ImportEntry>| {
+            let words = ImportWords::prepare(words).unwrap();
+            let record = import.begin(&words, &options).unwrap();
+            import.handle(record.id.unwrap(), &words, &options, &cancelled, &mut |_| {})
+        };
+
+        let first = run(vec![entry("apple", 3), entry("run", 0), entry("ghostword", 2)]);
+        assert_eq!(first.results[0].card.as_ref().unwrap().card.occurrence_count, 3);
+        assert_eq!(first.results[1].card.as_ref().unwrap().card.occurrence_count, 0);
+
+        let second = run(vec![entry("apples", 2)]);
+        assert_eq!(second.results[0].status, ImportItemStatus::Exists);
+        assert_eq!(second.results[0].card.as_ref().unwrap().card.occurrence_count, 5);
+        let apple_id = second.results[0].card.as_ref().unwrap().card.id.unwrap();
+        assert_eq!(repository.find_by_id(apple_id).unwrap().unwrap().occurrence_count, 5);
+    }
+
+    #[test]
+    fn test_reports_each_word_and_skips_existing_lemmas() {
+        let (report, events) = import(&["apple", "running", "ghostword", "offline", "cherry", "apples"], None);
+
+        let statuses = report.results.iter().map(|r| r.status).collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            [
+                ImportItemStatus::Success,
+                ImportItemStatus::Failed,
+                ImportItemStatus::Failed,
+                ImportItemStatus::Failed,
+                ImportItemStatus::Success,
+                ImportItemStatus::Exists,
+            ]
+        );
+        assert_eq!(report.status, ImportJobStatus::Completed);
+        assert_eq!((report.success_count, report.exists_count, report.failed_count), (2, 1, 3));
+        assert_eq!(report.results[5].card.as_ref().unwrap().card.word, "apple");
+        assert_eq!(report.results[3].error.as_deref(), Some("無法連線到字典服務"));
+        assert_eq!(events.len(), 6);
+        assert_eq!((events[5].index, events[5].total, events[5].job_id), (6, 6, 1));
+    }
+
+    #[test]
+    fn test_headwords_get_their_own_cards_instead_of_lemmas() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let import = ImportWords::new(&repository, &imports, &FakeDictionary);
+        let options = ImportOptions { delay_ms: Some(0), ..ImportOptions::default() };
+        let cancelled = AtomicBool::new(false);
+        let run = |words: Vec<ImportEntry>| {
+            let words = ImportWords::prepare(words).unwrap();
+            let record = import.begin(&words, &options).unwrap();
+            import.handle(record.id.unwrap(), &words, &options, &cancelled, &mut |_| {})
+        };
+
+        run(vec![entry("see", 1), entry("new", 1), entry("the", 1), entry("be", 1)]);
+        let report = run(vec![entry("seed", 2), entry("news", 3), entry("thing", 4), entry("bed", 5)]);
+        assert!(report.results.iter().all(|r| r.status == ImportItemStatus::Success));
+        for (word, lemma, count) in [("seed", "see", 2), ("news", "new", 3), ("thing", "the", 4), ("bed", "be", 5)] {
+            assert_eq!(repository.find_by_key(word).unwrap().unwrap().occurrence_count, count);
+            assert_eq!(repository.find_by_key(lemma).unwrap().unwrap().occurrence_count, 1);
+        }
+    }
+
+    #[test]
+    fn test_cancel_stops_before_next_word() {
+        let (report, events) = import(&["apple", "cherry", "run"], Some(1));
+        assert_eq!(report.status, ImportJobStatus::Cancelled);
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.total, 3);
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn test_jobs_track_cancellation_and_reports() {
+        let jobs = ImportJobs::new();
+        let job_id = 3;
+        let cancelled = jobs.register(job_id);
+        assert!(jobs.is_running(job_id));
+        assert!(jobs.report(job_id).is_none());
+        assert!(jobs.cancel(job_id));
+        assert!(cancelled.load(Ordering::SeqCst));
+
+        jobs.finish(ImportReport::new(job_id, 0));
+        assert!(!jobs.is_running(job_id));
+        assert!(!jobs.cancel(job_id));
+        assert_eq!(jobs.report(job_id).unwrap().job_id, job_id);
+        assert!(!jobs.cancel(99));
+    }
+
+    #[test]
+    fn test_history_records_sources_and_undo_removes_created_cards() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let import = ImportWords::new(&repository, &imports, &FakeDictionary);
+        let options = ImportOptions {
+            delay_ms: Some(0),
+            file_name: Some(" words.txt ".to_string()),
+            file_hash: Some(String::new()),
+        };
+        let cancelled = AtomicBool::new(false);
+        let run = |words: &[ImportEntry]| {
+            let record = import.begin(words, &options).unwrap();
+            import.handle(record.id.unwrap(), words, &options, &cancelled, &mut |_| {});
+            record.id.unwrap()
+        };
+
+        let first = run(&[entry("apple", 2)]);
+        let second = run(&[entry("apple", 3), entry("cherry", 1), entry("ghostword", 1)]);
+        let record = imports.find_import(second).unwrap().unwrap();
+        assert_eq!(record.status(), ImportStatus::Completed);
+        assert_eq!((record.file_name.as_deref(), record.file_hash.as_deref()), (Some("words.txt"), None));
+        assert_eq!((record.total_count, record.success_count, record.exists_count, record.failed_count), (3, 1, 1, 1));
+        assert_eq!(imports.list_import_cards(second).unwrap().len(), 2);
+
+        let jobs = ImportJobs::new();
+        let undo = UndoImport::new(&imports, &jobs);
+        jobs.register(first as u64);
+        assert_eq!(undo.handle(UndoImportCommand { import_id: first }).unwrap_err().code(), "CONFLICT");
+        jobs.finish(ImportReport::new(first as u64, 1));
+
+        let summary = undo.handle(UndoImportCommand { import_id: second }).unwrap();
+        assert_eq!((summary.deleted_count, summary.updated_count), (1, 1));
+        let apple = repository.find_by_key("apple").unwrap().unwrap();
+        assert_eq!(apple.occurrence_count, 2);
+        assert!(repository.find_by_key("cherry").unwrap().is_none());
+        assert_eq!(undo.handle(UndoImportCommand { import_id: second }).unwrap_err().code(), "CONFLICT");
+        assert_eq!(undo.handle(UndoImportCommand { import_id: 99 }).unwrap_err().code(), "NOT_FOUND");
+
+        undo.handle(UndoImportCommand { import_id: first }).unwrap();
+        assert!(repository.find_by_key("apple").unwrap().is_none());
+    }
+}
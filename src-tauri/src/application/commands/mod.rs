@@ -1 +1,35 @@
-// Command handlers will be defined here
+pub mod anki;
+pub mod decks;
+pub mod imports;
+pub mod profiles;
+pub mod review;
+pub mod spreadsheets;
+pub mod tags;
+pub mod word_cards;
+
+use log::warn;
+
+use crate::application::dto::tags::MAX_BULK_IDS;
+use crate::application::errors::AppError;
+
+/// 驗證 ID 必須大於 0
+fn validate_id(value: i32, label: &str) -> Result<(), AppError> {
+    if value <= 0 {
+        warn!("無效的{} ID: {}", label, value);
+        return Err(AppError::Validation(format!("{} ID 必須大於 0", label)));
+    }
+    Ok(())
+}
+
+/// 驗證批次操作的 ID 清單：不能為空、不能超過上限，且每個 ID 都必須大於 0
+fn validate_ids(values: &[i32], label: &str) -> Result<(), AppError> {
+    if values.is_empty() {
+        warn!("{} ID 清單為空", label);
+        return Err(AppError::Validation(format!("至少需要指定一個{}", label)));
+    }
+    if values.len() > MAX_BULK_IDS {
+        warn!("{} ID 數量過多: {}", label, values.len());
+        return Err(AppError::Validation(format!("一次最多只能指定 {} 個{}", MAX_BULK_IDS, label)));
+    }
+    values.iter().try_for_each(|value| validate_id(*value, label))
+}
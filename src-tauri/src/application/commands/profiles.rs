@@ -0,0 +1,71 @@
+use log::{error, info, warn};
+
+use super::validate_id;
+use crate::application::dto::profiles::{Profile, SetProfileSchedulerCommand};
+use crate::application::errors::AppError;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::value_objects::review::SchedulerKind;
+
+/// 設定設定檔使用的排程演算法
+pub struct SetProfileScheduler<'a> {
+    repository: &'a dyn ProfileRepository,
+}
+
+impl<'a> SetProfileScheduler<'a> {
+    pub fn new(repository: &'a dyn ProfileRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: SetProfileSchedulerCommand) -> Result<Profile, AppError> {
+        let SetProfileSchedulerCommand { profile_id, scheduler_name } = command;
+        validate_id(profile_id, "設定檔")?;
+        let kind = scheduler_name.parse::<SchedulerKind>().map_err(|e| {
+            warn!("無效的排程演算法: '{}'", scheduler_name);
+            AppError::Validation(e)
+        })?;
+
+        info!("更新設定檔排程演算法: ID {} -> {}", profile_id, kind.as_str());
+        match self.repository.update_scheduler(profile_id, kind) {
+            Ok(Some(profile)) => {
+                info!("✅ 排程演算法更新成功: ID {} -> {}", profile_id, kind.as_str());
+                Ok(profile)
+            }
+            Ok(None) => {
+                let error_msg = format!("找不到 ID 為 {} 的設定檔", profile_id);
+                warn!("⚠️ {}", error_msg);
+                Err(AppError::NotFound(error_msg))
+            }
+            Err(e) => {
+                let error_msg = format!("更新排程演算法失敗 - ID: {}, 錯誤: {}", profile_id, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::queries::profiles::GetActiveProfile;
+    use crate::infrastructure::repositories::{InMemoryProfileRepository, InMemoryStore};
+
+    #[test]
+    fn test_set_scheduler_validates_and_updates_profile() {
+        let repository = InMemoryProfileRepository::new(InMemoryStore::new());
+        let profile_id = GetActiveProfile::new(&repository).handle().unwrap().id.unwrap();
+        let command = |profile_id: i32, scheduler_name: &str| SetProfileSchedulerCommand {
+            profile_id,
+            scheduler_name: scheduler_name.to_string(),
+        };
+
+        let updated = SetProfileScheduler::new(&repository).handle(command(profile_id, "fsrs")).unwrap();
+        assert_eq!(updated.scheduler_kind(), SchedulerKind::Fsrs);
+        assert_eq!(GetActiveProfile::new(&repository).handle().unwrap().scheduler_kind(), SchedulerKind::Fsrs);
+
+        let set = |profile_id, scheduler_name| SetProfileScheduler::new(&repository).handle(command(profile_id, scheduler_name));
+        assert_eq!(set(profile_id, "anki").unwrap_err().code(), "VALIDATION");
+        assert_eq!(set(0, "sm2").unwrap_err().code(), "VALIDATION");
+        assert_eq!(set(999, "sm2").unwrap_err().code(), "NOT_FOUND");
+    }
+}
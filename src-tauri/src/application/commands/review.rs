@@ -0,0 +1,181 @@
+use chrono::NaiveDateTime;
+use log::{error, info, warn};
+
+use super::validate_id;
+use super::word_cards::card_not_found;
+use crate::application::dto::review_logs::ReviewWordCardCommand;
+use crate::application::errors::AppError;
+use crate::application::queries::profiles::GetActiveProfile;
+use crate::domain::entities::review_logs::NewReviewLog;
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::repositories::deck_repository::DeckRepository;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::repositories::review_repository::ReviewRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+use crate::domain::services::learning_steps::SteppedScheduler;
+use crate::domain::services::scheduler::{scheduler_for, Scheduler};
+use crate::domain::value_objects::review::{format_timestamp, ReviewGrade, ReviewState};
+
+fn state_json(state: &ReviewState) -> Result<String, AppError> {
+    serde_json::to_string(state).map_err(|e| {
+        error!("❌ 序列化排程狀態失敗: {}", e);
+        AppError::QueryFailed(e.to_string())
+    })
+}
+
+/// 複習單字卡：依作答評分以使用中設定檔的排程演算法與卡片所屬牌組的學習步驟計算下次複習時間，
+/// 再由儲存庫在同一交易中更新排程狀態與寫入作答紀錄
+pub struct ReviewWordCard<'a> {
+    cards: &'a dyn WordCardRepository,
+    decks: &'a dyn DeckRepository,
+    profiles: &'a dyn ProfileRepository,
+    reviews: &'a dyn ReviewRepository,
+}
+
+impl<'a> ReviewWordCard<'a> {
+    pub fn new(
+        cards: &'a dyn WordCardRepository,
+        decks: &'a dyn DeckRepository,
+        profiles: &'a dyn ProfileRepository,
+        reviews: &'a dyn ReviewRepository,
+    ) -> Self {
+        Self { cards, decks, profiles, reviews }
+    }
+
+    /// `now` 為作答時間（UTC）
+    pub fn handle(&self, command: ReviewWordCardCommand, now: NaiveDateTime) -> Result<WordCard, AppError> {
+        let ReviewWordCardCommand { card_id, grade, duration_ms } = command;
+        validate_id(card_id, "單字卡")?;
+        let review_grade = ReviewGrade::try_from(grade).map_err(|e| {
+            warn!("無效的評分: {}", grade);
+            AppError::Validation(e)
+        })?;
+
+        info!("複習單字卡: ID {} -> 評分 {:?}", card_id, review_grade);
+        let card = self
+            .cards
+            .find_by_id(card_id)
+            .map_err(|e| {
+                let error_msg = format!("查詢單字卡失敗 - ID: {}, 錯誤: {}", card_id, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?
+            .ok_or_else(|| card_not_found(card_id))?;
+        let settings = self
+            .decks
+            .find_deck(card.deck_id)
+            .map_err(|e| {
+                let error_msg = format!("查詢牌組失敗 - ID: {}, 錯誤: {}", card.deck_id, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?
+            .map(|deck| deck.settings)
+            .unwrap_or_default();
+        let profile = GetActiveProfile::new(self.profiles).handle()?;
+        let scheduler = SteppedScheduler::new(
+            scheduler_for(profile.scheduler_kind()),
+            settings.learning_steps,
+            settings.relearning_steps,
+        );
+
+        let previous = card.review_state();
+        let next = scheduler.schedule(&previous, review_grade, now);
+        let log = NewReviewLog {
+            card_id,
+            reviewed_at: format_timestamp(&now),
+            grade: review_grade.as_i32(),
+            scheduler: scheduler.kind().as_str().to_string(),
+            elapsed_days: previous
+                .last_reviewed_at
+                .map(|last| (now - last).num_days().max(0) as i32)
+                .unwrap_or(0),
+            duration_ms,
+            previous_state: state_json(&previous)?,
+            new_state: state_json(&next)?,
+        };
+
+        match self.reviews.record_review(card_id, &next, &log) {
+            Ok(Some(card)) => {
+                info!(
+                    "✅ 複習完成: ID {} -> 間隔 {} 天，下次複習 {:?}",
+                    card_id, card.interval_days, card.due_at
+                );
+                Ok(card)
+            }
+            Ok(None) => Err(card_not_found(card_id)),
+            Err(e) => {
+                let error_msg = format!("複習單字卡失敗 - ID: {}, 錯誤: {}", card_id, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::decks::CreateDeck;
+    use crate::application::dto::decks::{CreateDeckCommand, DeckSettings};
+    use crate::domain::value_objects::review::parse_timestamp;
+    use crate::infrastructure::repositories::{
+        InMemoryDeckRepository, InMemoryProfileRepository, InMemoryReviewRepository, InMemoryStore,
+        InMemoryWordCardRepository,
+    };
+    use crate::infrastructure::repositories::tests::add_card;
+    use chrono::Duration;
+
+    #[test]
+    fn test_review_updates_state_and_writes_log() {
+        let store = InMemoryStore::new();
+        let cards = InMemoryWordCardRepository::new(store.clone());
+        let decks = InMemoryDeckRepository::new(store.clone());
+        let profiles = InMemoryProfileRepository::new(store.clone());
+        let reviews = InMemoryReviewRepository::new(store);
+        let card_id = add_card(&cards, "apple");
+        let handler = ReviewWordCard::new(&cards, &decks, &profiles, &reviews);
+        let now = parse_timestamp("2025-01-01 08:00:00").unwrap();
+
+        let command = ReviewWordCardCommand { card_id, grade: 3, duration_ms: Some(1500) };
+        let reviewed = handler.handle(command, now).unwrap();
+        assert!(reviewed.due_at.is_some());
+        assert_eq!(reviewed.last_reviewed_at.as_deref(), Some("2025-01-01 08:00:00"));
+        let logs = reviews.list_review_logs(&Default::default(), 10).unwrap();
+        assert_eq!((logs.len(), logs[0].grade, logs[0].duration_ms), (1, 3, Some(1500)));
+        assert_eq!(logs[0].new_review_state(), Some(reviewed.review_state()));
+
+        let review = |card_id, grade| handler.handle(ReviewWordCardCommand { card_id, grade, duration_ms: None }, now);
+        assert_eq!(review(card_id, 4).unwrap_err().code(), "VALIDATION");
+        assert_eq!(review(0, 2).unwrap_err().code(), "VALIDATION");
+        assert_eq!(review(999, 2).unwrap_err().code(), "NOT_FOUND");
+    }
+
+    #[test]
+    fn test_review_uses_learning_steps_of_card_deck() {
+        let store = InMemoryStore::new();
+        let cards = InMemoryWordCardRepository::new(store.clone());
+        let decks = InMemoryDeckRepository::new(store.clone());
+        let profiles = InMemoryProfileRepository::new(store.clone());
+        let reviews = InMemoryReviewRepository::new(store);
+        let settings = DeckSettings { learning_steps: vec![1, 10], ..DeckSettings::default() };
+        let command = CreateDeckCommand { name: "TOEIC".to_string(), parent_id: None, settings: Some(settings) };
+        let toeic_id = CreateDeck::new(&decks).handle(command).unwrap().id.unwrap();
+        let apple = add_card(&cards, "apple");
+        let banana = add_card(&cards, "banana");
+        decks.move_cards(&[apple], toeic_id).unwrap();
+        let handler = ReviewWordCard::new(&cards, &decks, &profiles, &reviews);
+        let review = |card_id, now| handler.handle(ReviewWordCardCommand { card_id, grade: 2, duration_ms: None }, now).unwrap();
+        let now = parse_timestamp("2025-01-01 08:00:00").unwrap();
+
+        // 牌組有學習步驟：Good 前進到第二步，10 分鐘後再複習
+        let learning = review(apple, now).review_state();
+        assert_eq!((learning.learning_step, learning.interval_days), (Some(1), 0));
+        assert_eq!(learning.due_at, Some(now + Duration::minutes(10)));
+        let graduated = review(apple, now + Duration::minutes(10)).review_state();
+        assert_eq!((graduated.learning_step, graduated.interval_days), (None, 1));
+
+        // 預設牌組沒有學習步驟，直接由排程演算法安排
+        let scheduled = review(banana, now).review_state();
+        assert_eq!((scheduled.learning_step, scheduled.interval_days), (None, 1));
+    }
+}
@@ -0,0 +1,191 @@
+use log::{error, info, warn};
+use std::collections::HashMap;
+
+use crate::application::dto::spreadsheets::{ImportSpreadsheetCommand, SpreadsheetImportReport, SpreadsheetRowError};
+use crate::application::errors::AppError;
+use crate::application::queries::spreadsheets::load_spreadsheet;
+use crate::domain::entities::imports::{ImportOutcome, ImportStatus, NewCardSource, NewImportRecord};
+use crate::domain::entities::word_cards::{NewWordCard, SaveOutcome};
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+
+/// 同一個單字的所有列合併而成的單字卡
+struct PendingCard {
+    card: NewWordCard,
+    lines: Vec<usize>,
+}
+
+/// 將 CSV/TSV 試算表匯入為單字卡，不查詢字典
+///
+/// 每一列依欄位對應轉為一個詞義，同一個單字的多列合併為一張單字卡；驗證失敗的列略過並回報原因。
+/// 匯入會記錄在匯入紀錄中，之後可以復原。
+pub struct ImportSpreadsheet<'a> {
+    repository: &'a dyn WordCardRepository,
+    imports: &'a dyn ImportRepository,
+}
+
+impl<'a> ImportSpreadsheet<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository, imports: &'a dyn ImportRepository) -> Self {
+        Self { repository, imports }
+    }
+
+    pub fn handle(&self, command: ImportSpreadsheetCommand) -> Result<SpreadsheetImportReport, AppError> {
+        let policy = command.policy.unwrap_or_default();
+        let (sheet, content_hash) = load_spreadsheet(command.source, &command.options)?;
+        if sheet.rows.is_empty() {
+            warn!("試算表沒有資料列");
+            return Err(AppError::Validation("沒有資料列需要匯入".to_string()));
+        }
+
+        let mut errors = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut pending: Vec<PendingCard> = Vec::new();
+        for row in &sheet.rows {
+            let card = match sheet.card(row) {
+                Ok(card) => card,
+                Err(message) => {
+                    let word = sheet.word(row).map(String::from);
+                    errors.push(SpreadsheetRowError { line: row.line, word, message });
+                    continue;
+                }
+            };
+            let key = card.word_key.clone().unwrap_or_default();
+            match positions.get(&key) {
+                Some(&position) => {
+                    let existing = &mut pending[position];
+                    existing.card.absorb(card);
+                    existing.lines.push(row.line);
+                }
+                None => {
+                    positions.insert(key, pending.len());
+                    pending.push(PendingCard { card, lines: vec![row.line] });
+                }
+            }
+        }
+
+        let file_name = command.file_name.as_deref().map(str::trim).filter(|name| !name.is_empty()).map(String::from);
+        let record = NewImportRecord { file_name, file_hash: Some(content_hash), total_count: sheet.rows.len() as i32 };
+        let record = self.imports.create_import(&record).map_err(|e| {
+            error!("❌ 建立匯入紀錄失敗 - 檔案: {:?}, 錯誤: {}", record.file_name, e);
+            AppError::from(e)
+        })?;
+        let import_id = record.id.unwrap_or_default();
+        info!("開始匯入試算表 {}: {} 列, {} 個單字", import_id, sheet.rows.len(), pending.len());
+
+        let mut report = SpreadsheetImportReport {
+            import_id,
+            total_rows: sheet.rows.len(),
+            inserted_count: 0,
+            exists_count: 0,
+            failed_count: 0,
+            errors: Vec::new(),
+        };
+        for PendingCard { card, lines } in pending {
+            let card = match card.normalized() {
+                Ok(card) => card,
+                Err(message) => {
+                    errors.extend(lines.iter().map(|&line| SpreadsheetRowError { line, word: None, message: message.clone() }));
+                    continue;
+                }
+            };
+            match self.repository.save(&card, policy) {
+                Ok((outcome, entry)) => {
+                    let created_card = outcome == SaveOutcome::Inserted;
+                    let Some(card_id) = entry.card.id else { continue };
+                    // 沒有來源的單字卡不會被復原匯入刪除，因此來源寫入失敗時列為錯誤
+                    if let Err(e) = self.imports.add_card_source(&NewCardSource::import(card_id, import_id, created_card, 0)) {
+                        error!("❌ 記錄單字卡來源失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+                        let message = AppError::from(e).message();
+                        errors.extend(lines.iter().map(|&line| SpreadsheetRowError {
+                            line,
+                            word: Some(card.word.clone()),
+                            message: message.clone(),
+                        }));
+                        continue;
+                    }
+                    if created_card {
+                        report.inserted_count += 1;
+                    } else {
+                        report.exists_count += 1;
+                    }
+                }
+                Err(e) => {
+                    error!("❌ 保存單字卡失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+                    let message = AppError::from(e).message();
+                    errors.extend(lines.iter().map(|&line| SpreadsheetRowError {
+                        line,
+                        word: Some(card.word.clone()),
+                        message: message.clone(),
+                    }));
+                }
+            }
+        }
+
+        errors.sort_by_key(|error| error.line);
+        report.failed_count = errors.len();
+        report.errors = errors;
+
+        let outcome = ImportOutcome {
+            status: ImportStatus::Completed,
+            success_count: report.inserted_count as i32,
+            exists_count: report.exists_count as i32,
+            failed_count: report.failed_count as i32,
+        };
+        if let Err(e) = self.imports.finish_import(import_id, &outcome) {
+            error!("❌ 更新匯入紀錄失敗 - ID: {}, 錯誤: {}", import_id, e);
+        }
+
+        info!(
+            "✅ 試算表匯入 {} 完成: 新增 {}、已存在 {}、失敗 {} 列",
+            import_id, report.inserted_count, report.exists_count, report.failed_count
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::spreadsheets::{SpreadsheetOptions, SpreadsheetSource};
+    use crate::domain::entities::word_cards::MergePolicy;
+    use crate::infrastructure::repositories::{InMemoryImportRepository, InMemoryStore, InMemoryWordCardRepository};
+
+    fn command(content: &str, policy: Option<MergePolicy>) -> ImportSpreadsheetCommand {
+        ImportSpreadsheetCommand {
+            source: SpreadsheetSource { path: None, content: Some(content.as_bytes().to_vec()) },
+            options: SpreadsheetOptions::default(),
+            file_name: Some("words.csv".to_string()),
+            policy,
+        }
+    }
+
+    #[test]
+    fn test_imports_rows_merging_senses_and_reporting_errors() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let import = ImportSpreadsheet::new(&repository, &imports);
+        let content = "word\tpos\tdefinition\texample\n\
+                       light\tn.\t光\tTurn on the light.\n\
+                       light\tadj.\t輕的\t\n\
+                       \tn.\t沒有單字\t\n\
+                       run\tv.\t跑\t\n\
+                       cat\tn.\t\t\n";
+        let report = import.handle(command(content, None)).unwrap();
+        assert_eq!((report.total_rows, report.inserted_count, report.exists_count, report.failed_count), (5, 2, 0, 2));
+        assert_eq!(report.errors.iter().map(|e| e.line).collect::<Vec<_>>(), [4, 6]);
+        assert_eq!(report.errors[1].word.as_deref(), Some("cat"));
+
+        let light = repository.find_by_key("light").unwrap().unwrap();
+        assert!(light.definition.as_deref().unwrap().contains("[adj.] 輕的"));
+        let record = imports.find_import(report.import_id).unwrap().unwrap();
+        assert_eq!((record.file_name.as_deref(), record.success_count, record.failed_count), (Some("words.csv"), 2, 2));
+        assert_eq!(imports.list_import_cards(report.import_id).unwrap().len(), 2);
+
+        let again = import.handle(command("run,v.,經營\n", Some(MergePolicy::MergeDefinitions))).unwrap();
+        assert_eq!((again.inserted_count, again.exists_count), (0, 1));
+        let run = repository.find_by_key("run").unwrap().unwrap();
+        assert!(run.definition.as_deref().unwrap().contains("經營"));
+        assert_eq!(import.handle(command("\n\n", None)).unwrap_err().code(), "VALIDATION");
+    }
+}
@@ -0,0 +1,254 @@
+use log::{error, info, warn};
+
+use super::{validate_id, validate_ids};
+use crate::application::dto::tags::{
+    CreateTagCommand, DeleteTagCommand, MergeTagsCommand, RenameTagCommand, Tag, TagAssignmentCommand, TagCount,
+};
+use crate::application::errors::AppError;
+use crate::domain::entities::tags::NewTag;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::value_objects::tag::TagName;
+
+fn parse_name(raw: &str) -> Result<NewTag, AppError> {
+    TagName::parse(raw).map(|name| NewTag::from(&name)).map_err(|e| {
+        warn!("⚠️ 標籤名稱不正確: '{}', 錯誤: {}", raw, e);
+        AppError::Validation(e)
+    })
+}
+
+fn tag_not_found(tag_id: i32) -> AppError {
+    let error_msg = format!("找不到 ID 為 {} 的標籤", tag_id);
+    warn!("⚠️ {}", error_msg);
+    AppError::NotFound(error_msg)
+}
+
+/// 建立標籤；名稱不分大小寫不能重複
+pub struct CreateTag<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> CreateTag<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: CreateTagCommand) -> Result<Tag, AppError> {
+        let tag = parse_name(&command.name)?;
+        info!("建立標籤: '{}'", tag.name);
+
+        let created = self.repository.create_tag(&tag).map_err(|e| {
+            let error_msg = format!("建立標籤失敗 - 名稱: '{}', 錯誤: {}", tag.name, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        info!("✅ 標籤建立成功: '{}' (ID {:?})", created.name, created.id);
+        Ok(created)
+    }
+}
+
+/// 更改標籤名稱
+pub struct RenameTag<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> RenameTag<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: RenameTagCommand) -> Result<Tag, AppError> {
+        validate_id(command.tag_id, "標籤")?;
+        let tag = parse_name(&command.name)?;
+        info!("更改標籤名稱: ID {} -> '{}'", command.tag_id, tag.name);
+
+        let renamed = self.repository.rename_tag(command.tag_id, &tag).map_err(|e| {
+            let error_msg = format!("更改標籤名稱失敗 - ID: {}, 錯誤: {}", command.tag_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        info!("✅ 標籤名稱更改成功: ID {} -> '{}'", command.tag_id, renamed.name);
+        Ok(renamed)
+    }
+}
+
+/// 合併標籤：來源標籤的單字卡改掛目標標籤，來源標籤刪除
+pub struct MergeTags<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> MergeTags<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: MergeTagsCommand) -> Result<TagCount, AppError> {
+        let MergeTagsCommand { source_ids, target_id } = command;
+        validate_id(target_id, "標籤")?;
+        validate_ids(&source_ids, "標籤")?;
+        if source_ids.iter().all(|source_id| *source_id == target_id) {
+            warn!("合併標籤的來源與目標相同: ID {}", target_id);
+            return Err(AppError::Validation("來源標籤不能只有目標標籤本身".to_string()));
+        }
+
+        info!("合併標籤: {:?} -> ID {}", source_ids, target_id);
+        let merged = self.repository.merge_tags(&source_ids, target_id).map_err(|e| {
+            let error_msg = format!("合併標籤失敗 - 來源: {:?}, 目標: {}, 錯誤: {}", source_ids, target_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        info!("✅ 標籤合併成功: '{}' 共 {} 張單字卡", merged.tag.name, merged.card_count);
+        Ok(merged)
+    }
+}
+
+/// 刪除標籤，單字卡本身不受影響
+pub struct DeleteTag<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> DeleteTag<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: DeleteTagCommand) -> Result<(), AppError> {
+        let tag_id = command.tag_id;
+        validate_id(tag_id, "標籤")?;
+
+        info!("刪除標籤: ID {}", tag_id);
+        let deleted = self.repository.delete_tag(tag_id).map_err(|e| {
+            let error_msg = format!("刪除標籤失敗 - ID: {}, 錯誤: {}", tag_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        if !deleted {
+            return Err(tag_not_found(tag_id));
+        }
+
+        info!("✅ 標籤刪除成功: ID {}", tag_id);
+        Ok(())
+    }
+}
+
+/// 批次為單字卡加上標籤；回傳新增的數量，已有的標籤不重複計算
+pub struct AssignTags<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> AssignTags<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: TagAssignmentCommand) -> Result<usize, AppError> {
+        validate_ids(&command.card_ids, "單字卡")?;
+        validate_ids(&command.tag_ids, "標籤")?;
+
+        info!("加上標籤: {} 張單字卡, 標籤 {:?}", command.card_ids.len(), command.tag_ids);
+        let assigned = self
+            .repository
+            .assign_tags(&command.card_ids, &command.tag_ids)
+            .map_err(|e| {
+                let error_msg = format!("加上標籤失敗 - 標籤: {:?}, 錯誤: {}", command.tag_ids, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?;
+
+        info!("✅ 成功加上 {} 個標籤", assigned);
+        Ok(assigned)
+    }
+}
+
+/// 批次移除單字卡的標籤；回傳移除的數量
+pub struct UnassignTags<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> UnassignTags<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: TagAssignmentCommand) -> Result<usize, AppError> {
+        validate_ids(&command.card_ids, "單字卡")?;
+        validate_ids(&command.tag_ids, "標籤")?;
+
+        info!("移除標籤: {} 張單字卡, 標籤 {:?}", command.card_ids.len(), command.tag_ids);
+        let removed = self
+            .repository
+            .unassign_tags(&command.card_ids, &command.tag_ids)
+            .map_err(|e| {
+                let error_msg = format!("移除標籤失敗 - 標籤: {:?}, 錯誤: {}", command.tag_ids, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?;
+
+        info!("✅ 成功移除 {} 個標籤", removed);
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::tests::add_card;
+    use crate::infrastructure::repositories::{InMemoryStore, InMemoryTagRepository, InMemoryWordCardRepository};
+
+    fn create(repository: &InMemoryTagRepository, name: &str) -> i32 {
+        CreateTag::new(repository)
+            .handle(CreateTagCommand { name: name.to_string() })
+            .unwrap()
+            .id
+            .unwrap()
+    }
+
+    #[test]
+    fn test_create_and_rename_validate_names() {
+        let repository = InMemoryTagRepository::new(InMemoryStore::new());
+        let travel = create(&repository, "  Travel ");
+        create(&repository, "food");
+
+        let duplicate = CreateTag::new(&repository).handle(CreateTagCommand { name: "TRAVEL".to_string() });
+        assert_eq!(duplicate.unwrap_err().code(), "CONFLICT");
+        let empty = CreateTag::new(&repository).handle(CreateTagCommand { name: " ".to_string() });
+        assert_eq!(empty.unwrap_err().code(), "VALIDATION");
+
+        let rename = RenameTag::new(&repository);
+        let renamed = rename.handle(RenameTagCommand { tag_id: travel, name: "Trips".to_string() }).unwrap();
+        assert_eq!(renamed.name_key, "trips");
+        let clash = rename.handle(RenameTagCommand { tag_id: travel, name: "Food".to_string() });
+        assert_eq!(clash.unwrap_err().code(), "CONFLICT");
+        let missing = rename.handle(RenameTagCommand { tag_id: 99, name: "x".to_string() });
+        assert_eq!(missing.unwrap_err().code(), "NOT_FOUND");
+    }
+
+    #[test]
+    fn test_bulk_assignment_and_merge() {
+        let store = InMemoryStore::new();
+        let cards = InMemoryWordCardRepository::new(store.clone());
+        let repository = InMemoryTagRepository::new(store);
+        let card_id = add_card(&cards, "apple");
+        let (fruit, food) = (create(&repository, "fruit"), create(&repository, "food"));
+
+        let assign = AssignTags::new(&repository);
+        let command = |tag_ids: Vec<i32>| TagAssignmentCommand { card_ids: vec![card_id], tag_ids };
+        assert_eq!(assign.handle(command(vec![fruit, food])).unwrap(), 2);
+        assert_eq!(assign.handle(command(Vec::new())).unwrap_err().code(), "VALIDATION");
+        assert_eq!(assign.handle(command(vec![99])).unwrap_err().code(), "NOT_FOUND");
+
+        let merge = MergeTags::new(&repository);
+        let same = merge.handle(MergeTagsCommand { source_ids: vec![food], target_id: food });
+        assert_eq!(same.unwrap_err().code(), "VALIDATION");
+        let merged = merge.handle(MergeTagsCommand { source_ids: vec![fruit], target_id: food }).unwrap();
+        assert_eq!(merged.card_count, 1);
+
+        assert_eq!(UnassignTags::new(&repository).handle(command(vec![food])).unwrap(), 1);
+        let delete = DeleteTag::new(&repository);
+        delete.handle(DeleteTagCommand { tag_id: food }).unwrap();
+        assert_eq!(delete.handle(DeleteTagCommand { tag_id: food }).unwrap_err().code(), "NOT_FOUND");
+    }
+}
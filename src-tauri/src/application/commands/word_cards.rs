@@ -0,0 +1,240 @@
+use log::{error, info, warn};
+
+use crate::application::dto::word_cards::{
+    DeleteWordCardCommand, IncrementSeenCountCommand, SaveWordCardCommand, SaveWordCardResult,
+    UpdateFamiliarityCommand,
+};
+use crate::application::errors::AppError;
+use crate::domain::entities::imports::NewCardSource;
+use crate::domain::entities::word_cards::SaveOutcome;
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+
+/// 驗證單字卡 ID
+fn validate_card_id(card_id: i32) -> Result<(), AppError> {
+    if card_id <= 0 {
+        warn!("無效的單字卡 ID: {}", card_id);
+        return Err(AppError::Validation("單字卡 ID 必須大於 0".to_string()));
+    }
+    Ok(())
+}
+
+pub(super) fn card_not_found(card_id: i32) -> AppError {
+    let error_msg = format!("找不到 ID 為 {} 的單字卡", card_id);
+    warn!("⚠️ {}", error_msg);
+    AppError::NotFound(error_msg)
+}
+
+/// 保存單字卡：正規化單字後交由儲存庫新增或合併，並記錄單字卡來自手動查詢
+pub struct SaveWordCard<'a> {
+    repository: &'a dyn WordCardRepository,
+    sources: &'a dyn ImportRepository,
+}
+
+impl<'a> SaveWordCard<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository, sources: &'a dyn ImportRepository) -> Self {
+        Self { repository, sources }
+    }
+
+    pub fn handle(&self, command: SaveWordCardCommand) -> Result<SaveWordCardResult, AppError> {
+        let policy = command.policy.unwrap_or_default();
+        info!("保存單字卡: '{}', 合併策略: {:?}", command.card.word, policy);
+
+        let raw_word = command.card.word.clone();
+        let card = command.card.normalized().map_err(|e| {
+            warn!("⚠️ 單字格式不正確: '{}', 錯誤: {}", raw_word, e);
+            AppError::Validation(e)
+        })?;
+
+        let (outcome, saved) = self.repository.save(&card, policy).map_err(|e| {
+            let error_msg = format!("保存單字卡失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        if let Some(card_id) = saved.card.id {
+            let source = NewCardSource::lookup(card_id, outcome == SaveOutcome::Inserted);
+            self.sources.add_card_source(&source).map_err(|e| {
+                let error_msg = format!("記錄單字卡來源失敗 - 單字: '{}', 錯誤: {}", card.word, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?;
+        }
+
+        info!("✅ 成功保存單字卡: '{}', 結果: {:?}", card.word, outcome);
+        Ok(SaveWordCardResult { outcome, card: saved })
+    }
+}
+
+/// 設定單字卡熟悉度
+pub struct UpdateFamiliarity<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> UpdateFamiliarity<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: UpdateFamiliarityCommand) -> Result<(), AppError> {
+        let UpdateFamiliarityCommand { card_id, familiarity_level } = command;
+        validate_card_id(card_id)?;
+
+        if !(0..=3).contains(&familiarity_level) {
+            warn!("無效的熟悉度級別: {} (應該在 0-3 之間)", familiarity_level);
+            return Err(AppError::Validation("熟悉度級別必須在 0-3 之間".to_string()));
+        }
+
+        info!("更新單字卡熟悉度: ID {} -> 級別 {}", card_id, familiarity_level);
+        let updated = self
+            .repository
+            .update_familiarity(card_id, familiarity_level)
+            .map_err(|e| {
+                let error_msg = format!("更新熟悉度失敗 - ID: {}, 錯誤: {}", card_id, e);
+                error!("❌ {}", error_msg);
+                AppError::from(e)
+            })?;
+
+        if !updated {
+            return Err(card_not_found(card_id));
+        }
+
+        info!("✅ 熟悉度更新成功: ID {} -> 級別 {}", card_id, familiarity_level);
+        Ok(())
+    }
+}
+
+/// 增加單字卡查看次數
+pub struct IncrementSeenCount<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> IncrementSeenCount<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: IncrementSeenCountCommand) -> Result<(), AppError> {
+        let card_id = command.card_id;
+        validate_card_id(card_id)?;
+
+        info!("增加單字卡查看次數: ID {}", card_id);
+        let updated = self.repository.increment_seen_count(card_id).map_err(|e| {
+            let error_msg = format!("更新查看次數失敗 - ID: {}, 錯誤: {}", card_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        if !updated {
+            return Err(card_not_found(card_id));
+        }
+
+        info!("✅ 查看次數更新成功: ID {}", card_id);
+        Ok(())
+    }
+}
+
+/// 刪除單字卡
+pub struct DeleteWordCard<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> DeleteWordCard<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, command: DeleteWordCardCommand) -> Result<(), AppError> {
+        let card_id = command.card_id;
+        validate_card_id(card_id)?;
+
+        info!("刪除單字卡: ID {}", card_id);
+        let deleted = self.repository.delete(card_id).map_err(|e| {
+            let error_msg = format!("刪除單字卡失敗 - ID: {}, 錯誤: {}", card_id, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        if !deleted {
+            return Err(card_not_found(card_id));
+        }
+
+        info!("✅ 單字卡刪除成功: ID {}", card_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::word_cards::{MergePolicy, SaveOutcome};
+    use crate::infrastructure::repositories::tests::card_input;
+    use crate::infrastructure::repositories::{InMemoryImportRepository, InMemoryStore, InMemoryWordCardRepository};
+
+    fn save_command(word: &str, definition: &str, policy: Option<MergePolicy>) -> SaveWordCardCommand {
+        SaveWordCardCommand { card: card_input(word, definition), policy }
+    }
+
+    #[test]
+    fn test_save_normalizes_and_merges() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let handler = SaveWordCard::new(&repository, &imports);
+
+        let first = handler.handle(save_command("  Apple ", "[noun] a fruit", None)).unwrap();
+        assert_eq!(first.outcome, SaveOutcome::Inserted);
+        assert_eq!(first.card.card.word, "Apple");
+        assert_eq!(first.card.senses.len(), 1);
+
+        let second = handler
+            .handle(save_command("apple", "[noun] a tree", Some(MergePolicy::Overwrite)))
+            .unwrap();
+        assert_eq!(second.outcome, SaveOutcome::Updated);
+        assert_eq!(second.card.card.id, first.card.card.id);
+        assert_eq!(second.card.card.definition.as_deref(), Some("[noun] a tree"));
+        assert_eq!(second.card.senses[0].definition, "a tree");
+
+        let invalid = handler.handle(save_command("   ", "", None)).unwrap_err();
+        assert_eq!(invalid.code(), "VALIDATION");
+
+        // 重複查詢同一個單字只留下一筆來源
+        let sources = imports.list_card_sources(first.card.card.id.unwrap()).unwrap();
+        assert_eq!(sources.len(), 1);
+        assert!(sources[0].created_card);
+        assert_eq!(sources[0].kind, "lookup");
+    }
+
+    #[test]
+    fn test_update_and_delete_validate_and_report_missing_cards() {
+        let store = InMemoryStore::new();
+        let repository = InMemoryWordCardRepository::new(store.clone());
+        let imports = InMemoryImportRepository::new(store);
+        let saved = SaveWordCard::new(&repository, &imports)
+            .handle(save_command("apple", "[noun] a fruit", None))
+            .unwrap();
+        let card_id = saved.card.card.id.unwrap();
+
+        let update = UpdateFamiliarity::new(&repository);
+        assert_eq!(
+            update.handle(UpdateFamiliarityCommand { card_id, familiarity_level: 4 }).unwrap_err().code(),
+            "VALIDATION"
+        );
+        update.handle(UpdateFamiliarityCommand { card_id, familiarity_level: 2 }).unwrap();
+        assert_eq!(repository.find_by_id(card_id).unwrap().unwrap().familiarity, Some(2));
+
+        let seen = IncrementSeenCount::new(&repository);
+        seen.handle(IncrementSeenCountCommand { card_id }).unwrap();
+        assert_eq!(repository.find_by_id(card_id).unwrap().unwrap().seen_count, Some(2));
+
+        let delete = DeleteWordCard::new(&repository);
+        assert_eq!(delete.handle(DeleteWordCardCommand { card_id: 0 }).unwrap_err().code(), "VALIDATION");
+        delete.handle(DeleteWordCardCommand { card_id }).unwrap();
+        assert_eq!(delete.handle(DeleteWordCardCommand { card_id }).unwrap_err().code(), "NOT_FOUND");
+        assert_eq!(
+            update.handle(UpdateFamiliarityCommand { card_id, familiarity_level: 1 }).unwrap_err().code(),
+            "NOT_FOUND"
+        );
+        assert_eq!(seen.handle(IncrementSeenCountCommand { card_id }).unwrap_err().code(), "NOT_FOUND");
+    }
+}
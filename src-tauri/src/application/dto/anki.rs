@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub use crate::domain::services::spreadsheet::ColumnField;
+use crate::domain::entities::word_cards::MergePolicy;
+
+/// 一個套件的筆記數量上限
+pub const MAX_ANKI_NOTES: usize = 50_000;
+
+/// 檢視 Anki 套件的筆記類型與欄位對應，不寫入資料庫
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InspectAnkiPackageQuery {
+    /// .apkg 或 .colpkg 檔案的路徑
+    pub path: String,
+    /// 欄位名稱（不分大小寫）對應到單字卡欄位；未列出的欄位依名稱自動判斷
+    pub field_map: HashMap<String, ColumnField>,
+}
+
+/// 匯入 Anki 套件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportAnkiPackageCommand {
+    pub path: String,
+    pub field_map: HashMap<String, ColumnField>,
+    /// 是否帶入排程狀態與作答紀錄，預設為是；只套用到此次新增的單字卡
+    pub include_history: Option<bool>,
+    /// 單字已有單字卡時的處理方式，未指定時保留原有內容
+    pub policy: Option<MergePolicy>,
+}
+
+impl ImportAnkiPackageCommand {
+    pub fn include_history(&self) -> bool {
+        self.include_history.unwrap_or(true)
+    }
+}
+
+/// 筆記類型中的一個欄位與其對應
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnkiFieldMapping {
+    pub name: String,
+    pub field: ColumnField,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnkiNoteTypeSummary {
+    pub id: i64,
+    pub name: String,
+    pub fields: Vec<AnkiFieldMapping>,
+    pub note_count: usize,
+    /// 欄位對應無法匯入時的原因（如沒有對應到單字的欄位），此類型的筆記會全部略過
+    pub error: Option<String>,
+}
+
+/// `inspect_anki_package` 的結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnkiPackageSummary {
+    pub note_types: Vec<AnkiNoteTypeSummary>,
+    pub note_count: usize,
+    pub card_count: usize,
+    pub review_count: usize,
+    /// 套件檔案的 SHA-256（十六進位小寫）
+    pub content_hash: String,
+}
+
+/// 略過的筆記與原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnkiSkippedNote {
+    pub note_id: i64,
+    pub note_type: String,
+    /// 單字欄位的內容，沒有時為 `None`
+    pub word: Option<String>,
+    pub reason: String,
+}
+
+/// Anki 套件匯入的結果
+///
+/// 同一個單字的多則筆記合併為一張單字卡，因此單字卡數量可能少於筆記數量。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnkiImportReport {
+    pub import_id: i32,
+    pub note_count: usize,
+    /// 新增的單字卡數量
+    pub inserted_count: usize,
+    /// 已有單字卡、依合併策略處理的單字數量
+    pub exists_count: usize,
+    /// 帶入排程狀態的單字卡數量
+    pub history_count: usize,
+    /// 寫入的作答紀錄數量
+    pub review_log_count: usize,
+    pub skipped: Vec<AnkiSkippedNote>,
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use crate::application::errors::AppError;
+
+/// 資料庫目前的狀態，供前端在啟動時判斷是否需要顯示錯誤畫面
+#[derive(Debug, Clone, Serialize)]
+pub struct DatabaseStatus {
+    pub ready: bool,
+    /// 初始化失敗時的錯誤
+    pub error: Option<AppError>,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::domain::aggregates::deck::{Deck, DeckCounts, DeckSettings};
+
+/// 建立牌組；未指定設定時使用預設值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDeckCommand {
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub settings: Option<DeckSettings>,
+}
+
+/// 更改牌組名稱
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameDeckCommand {
+    pub deck_id: i32,
+    pub name: String,
+}
+
+/// 將牌組移到其他牌組之下；`parent_id` 為 `None` 時移到最上層
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MoveDeckCommand {
+    pub deck_id: i32,
+    pub parent_id: Option<i32>,
+}
+
+/// 更新牌組的排程設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDeckSettingsCommand {
+    pub deck_id: i32,
+    pub settings: DeckSettings,
+}
+
+/// 刪除牌組
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DeleteDeckCommand {
+    pub deck_id: i32,
+}
+
+/// 將單字卡移到指定牌組
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveCardsToDeckCommand {
+    pub card_ids: Vec<i32>,
+    pub deck_id: i32,
+}
+
+/// 牌組與其卡片數量
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeckSummary {
+    #[serde(flatten)]
+    pub deck: Deck,
+    /// 牌組本身的卡片數量
+    pub counts: DeckCounts,
+    /// 包含所有子牌組的卡片數量
+    pub total: DeckCounts,
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::domain::entities::imports::{CardSource, ImportRecord, ImportUndoSummary};
+use crate::domain::entities::word_cards::WordCardEntry;
+
+/// 一次匯入的單字數量上限
+pub const MAX_IMPORT_WORDS: usize = 5000;
+
+/// 每次查詢字典後的預設等待時間（毫秒），避免請求過於頻繁
+pub const DEFAULT_IMPORT_DELAY_MS: u64 = 100;
+
+/// 查詢字典後等待時間的上限（毫秒）
+pub const MAX_IMPORT_DELAY_MS: u64 = 5000;
+
+/// 匯入的選項
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportOptions {
+    /// 每次查詢字典後等待的毫秒數，未指定時使用 `DEFAULT_IMPORT_DELAY_MS`
+    pub delay_ms: Option<u64>,
+    /// 來源檔案名稱，記錄在匯入紀錄中
+    pub file_name: Option<String>,
+    /// 來源檔案內容的 SHA-256，由 `extract_vocabulary` 提供
+    pub file_hash: Option<String>,
+}
+
+impl ImportOptions {
+    pub fn delay_ms(&self) -> u64 {
+        self.delay_ms.unwrap_or(DEFAULT_IMPORT_DELAY_MS).min(MAX_IMPORT_DELAY_MS)
+    }
+}
+
+/// 匯入清單中的一筆單字
+///
+/// 可以只傳入單字字串，或以 `{ "word": ..., "count": ... }` 附上在來源檔案中出現的次數；
+/// 次數會累加到新增或已存在的單字卡上。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "ImportEntryInput")]
+pub struct ImportEntry {
+    pub word: String,
+    /// 在來源檔案中出現的次數，0 代表不記錄
+    pub count: i32,
+}
+
+impl From<String> for ImportEntry {
+    fn from(word: String) -> Self {
+        Self { word, count: 0 }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ImportEntryInput {
+    Word(String),
+    Entry {
+        word: String,
+        #[serde(default)]
+        count: i32,
+    },
+}
+
+impl From<ImportEntryInput> for ImportEntry {
+    fn from(input: ImportEntryInput) -> Self {
+        match input {
+            ImportEntryInput::Word(word) => Self::from(word),
+            ImportEntryInput::Entry { word, count } => Self { word, count: count.max(0) },
+        }
+    }
+}
+
+/// 單一單字的匯入結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportItemStatus {
+    /// 查詢字典後新增為單字卡
+    Success,
+    /// 已有此單字的單字卡；或字典沒有此字，但有其詞元的單字卡
+    Exists,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportItemResult {
+    /// 匯入清單中的單字
+    pub word: String,
+    pub status: ImportItemStatus,
+    /// 新增或已存在的單字卡
+    pub card: Option<WordCardEntry>,
+    /// 失敗的原因
+    pub error: Option<String>,
+}
+
+/// 匯入紀錄中的單字卡與此匯入對它的貢獻
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedCardEntry {
+    pub source: CardSource,
+    pub card: WordCardEntry,
+}
+
+/// 復原整個匯入
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UndoImportCommand {
+    pub import_id: i32,
+}
+
+/// `import://progress` 事件的內容，每處理完一個單字送出一次
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProgress {
+    /// 與匯入紀錄的 ID 相同
+    pub job_id: u64,
+    /// 已處理的數量（從 1 開始）
+    pub index: usize,
+    pub total: usize,
+    pub result: ImportItemResult,
+}
+
+/// 匯入工作結束的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportJobStatus {
+    Completed,
+    Cancelled,
+}
+
+/// 匯入工作的最終報告，也是 `import://finished` 事件的內容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub job_id: u64,
+    pub status: ImportJobStatus,
+    /// 匯入清單的單字數（去除空白行與重複後）
+    pub total: usize,
+    pub success_count: usize,
+    pub exists_count: usize,
+    pub failed_count: usize,
+    /// 已處理單字的結果；取消時只包含取消前處理完的單字
+    pub results: Vec<ImportItemResult>,
+}
+
+impl ImportReport {
+    pub fn new(job_id: u64, total: usize) -> Self {
+        Self {
+            job_id,
+            status: ImportJobStatus::Completed,
+            total,
+            success_count: 0,
+            exists_count: 0,
+            failed_count: 0,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, result: ImportItemResult) {
+        match result.status {
+            ImportItemStatus::Success => self.success_count += 1,
+            ImportItemStatus::Exists => self.exists_count += 1,
+            ImportItemStatus::Failed => self.failed_count += 1,
+        }
+        self.results.push(result);
+    }
+}
@@ -1 +1,11 @@
-// Data Transfer Objects will be defined here
+pub mod anki;
+pub mod database;
+pub mod decks;
+pub mod imports;
+pub mod profiles;
+pub mod review_logs;
+pub mod review_queue;
+pub mod spreadsheets;
+pub mod tags;
+pub mod vocabulary;
+pub mod word_cards;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::domain::entities::profiles::Profile;
+
+/// 設定設定檔使用的排程演算法；`scheduler_name` 為 "sm2" 或 "fsrs"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetProfileSchedulerCommand {
+    pub profile_id: i32,
+    pub scheduler_name: String,
+}
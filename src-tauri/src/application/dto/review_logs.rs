@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::review_logs::ReviewLog;
+use crate::domain::value_objects::review::ReviewState;
+
+/// 複習單字卡；`grade` 為 0（Again）到 3（Easy），`duration_ms` 為作答所花的時間（毫秒）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReviewWordCardCommand {
+    pub card_id: i32,
+    pub grade: i32,
+    pub duration_ms: Option<i32>,
+}
+
+/// 查詢作答紀錄的條件，所有欄位皆為選填
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewLogQuery {
+    pub card_id: Option<i32>,
+    /// 起始時間（含），格式同 `created_at`
+    pub since: Option<String>,
+    /// 結束時間（不含）
+    pub until: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// 回傳給前端的作答紀錄，狀態欄位已解析為結構
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewLogDto {
+    pub id: Option<i32>,
+    pub card_id: i32,
+    pub reviewed_at: String,
+    pub grade: i32,
+    pub scheduler: String,
+    pub elapsed_days: i32,
+    pub duration_ms: Option<i32>,
+    pub previous_state: Option<ReviewState>,
+    pub new_state: Option<ReviewState>,
+}
+
+impl From<ReviewLog> for ReviewLogDto {
+    fn from(log: ReviewLog) -> Self {
+        Self {
+            previous_state: log.previous_review_state(),
+            new_state: log.new_review_state(),
+            id: log.id,
+            card_id: log.card_id,
+            reviewed_at: log.reviewed_at,
+            grade: log.grade,
+            scheduler: log.scheduler,
+            elapsed_days: log.elapsed_days,
+            duration_ms: log.duration_ms,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::word_cards::WordCard;
+
+/// 每日新卡片上限預設值
+pub const DEFAULT_NEW_CARDS_PER_DAY: i64 = 20;
+
+/// 每日複習卡片上限預設值
+pub const DEFAULT_REVIEWS_PER_DAY: i64 = 200;
+
+/// 取得複習佇列的選項
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewQueueOptions {
+    pub new_cards_per_day: Option<i64>,
+    pub reviews_per_day: Option<i64>,
+    /// 只複習此牌組及其子牌組；未指定上限時使用該牌組的每日上限
+    #[serde(default)]
+    pub deck_id: Option<i32>,
+}
+
+/// 今日的複習佇列
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewQueue {
+    /// 依序作答的卡片，新卡片已穿插在複習卡片之間
+    pub cards: Vec<WordCard>,
+    pub new_count: i64,
+    pub review_count: i64,
+    /// 今日已學習的新卡片數
+    pub new_done_today: i64,
+    /// 今日已完成的複習數
+    pub reviews_done_today: i64,
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::domain::services::spreadsheet::{ColumnField, TextEncoding};
+use crate::domain::entities::word_cards::{MergePolicy, NewWordCard};
+
+/// 試算表檔案的大小上限（位元組）
+pub const MAX_SPREADSHEET_BYTES: u64 = 20 * 1024 * 1024;
+
+/// 一次匯入的資料列數上限
+pub const MAX_SPREADSHEET_ROWS: usize = 20_000;
+
+/// 預覽的預設列數
+pub const DEFAULT_PREVIEW_ROWS: usize = 20;
+
+/// 預覽列數的上限
+pub const MAX_PREVIEW_ROWS: usize = 200;
+
+/// 試算表的來源；`path` 與 `content` 必須恰好提供一個
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpreadsheetSource {
+    /// CSV/TSV 檔案的路徑
+    pub path: Option<String>,
+    /// 檔案的原始位元組，編碼由 `SpreadsheetOptions.encoding` 指定或自動判斷
+    pub content: Option<Vec<u8>>,
+}
+
+/// 解析試算表的選項，未指定的項目自動判斷
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpreadsheetOptions {
+    pub encoding: Option<TextEncoding>,
+    /// 分隔符號，必須是單一 ASCII 字元（如 `,`、`\t`、`;`）
+    pub delimiter: Option<char>,
+    pub has_header: Option<bool>,
+    /// 依欄位順序對應到單字卡欄位，超出的欄位略過
+    pub mapping: Option<Vec<ColumnField>>,
+}
+
+/// 預覽試算表匯入，不寫入資料庫
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreviewSpreadsheetQuery {
+    pub source: SpreadsheetSource,
+    pub options: SpreadsheetOptions,
+    /// 預覽的列數，未指定時為 `DEFAULT_PREVIEW_ROWS`
+    pub rows: Option<usize>,
+}
+
+impl PreviewSpreadsheetQuery {
+    pub fn rows(&self) -> usize {
+        self.rows.unwrap_or(DEFAULT_PREVIEW_ROWS).min(MAX_PREVIEW_ROWS)
+    }
+}
+
+/// 預覽中的一列
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetRowPreview {
+    /// 在檔案中的行號（從 1 開始）
+    pub line: usize,
+    pub values: Vec<String>,
+    /// 驗證通過時轉換出的單字卡
+    pub card: Option<NewWordCard>,
+    /// 驗證失敗的原因
+    pub error: Option<String>,
+}
+
+/// 試算表匯入的預覽：判斷出的設定、前幾列的轉換結果，以及所有列的驗證統計
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetPreview {
+    pub encoding: TextEncoding,
+    /// 是否有無法解碼、以替代字元取代的位元組；通常代表編碼判斷錯誤
+    pub lossy: bool,
+    pub delimiter: char,
+    pub has_header: bool,
+    /// 標題列，沒有標題列時為空
+    pub headers: Vec<String>,
+    pub mapping: Vec<ColumnField>,
+    pub column_count: usize,
+    /// 資料列數（不含標題列與空白列）
+    pub total_rows: usize,
+    pub valid_count: usize,
+    pub error_count: usize,
+    pub rows: Vec<SpreadsheetRowPreview>,
+    /// 檔案內容的 SHA-256（十六進位小寫）
+    pub content_hash: String,
+}
+
+/// 匯入試算表
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportSpreadsheetCommand {
+    pub source: SpreadsheetSource,
+    pub options: SpreadsheetOptions,
+    /// 來源檔案名稱，記錄在匯入紀錄中
+    pub file_name: Option<String>,
+    /// 單字已有單字卡時的處理方式，未指定時保留原有內容
+    pub policy: Option<MergePolicy>,
+}
+
+/// 無法匯入的一列
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpreadsheetRowError {
+    pub line: usize,
+    /// 單字欄的值，空白時為 `None`
+    pub word: Option<String>,
+    pub message: String,
+}
+
+/// 試算表匯入的結果
+///
+/// 同一個單字的多列合併為一張單字卡的多個詞義，因此數量以單字計算；驗證失敗的列不影響其他列。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetImportReport {
+    pub import_id: i32,
+    pub total_rows: usize,
+    /// 新增的單字卡數量
+    pub inserted_count: usize,
+    /// 已有單字卡、依合併策略處理的單字數量
+    pub exists_count: usize,
+    /// 驗證或保存失敗的列數
+    pub failed_count: usize,
+    pub errors: Vec<SpreadsheetRowError>,
+}
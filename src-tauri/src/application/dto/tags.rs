@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::domain::entities::tags::{Tag, TagCount};
+pub use crate::domain::value_objects::tag::TagExpression;
+
+/// 批次操作一次可指定的 ID 數量上限
+pub const MAX_BULK_IDS: usize = 5000;
+
+/// 建立標籤
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTagCommand {
+    pub name: String,
+}
+
+/// 更改標籤名稱
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameTagCommand {
+    pub tag_id: i32,
+    pub name: String,
+}
+
+/// 將來源標籤併入目標標籤，來源標籤合併後刪除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeTagsCommand {
+    pub source_ids: Vec<i32>,
+    pub target_id: i32,
+}
+
+/// 刪除標籤
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DeleteTagCommand {
+    pub tag_id: i32,
+}
+
+/// 批次為單字卡加上或移除標籤：每張卡片對每個標籤各操作一次
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagAssignmentCommand {
+    pub card_ids: Vec<i32>,
+    pub tag_ids: Vec<i32>,
+}
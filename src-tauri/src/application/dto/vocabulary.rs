@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::services::tokenizer::VocabularyExtraction;
+
+/// 擷取單字時檔案或文字的大小上限（位元組）
+pub const MAX_VOCABULARY_SOURCE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// 擷取單字的來源；`path` 與 `text` 必須恰好提供一個
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExtractVocabularyQuery {
+    /// 文字檔的路徑，內容以 UTF-8 讀取，無法解碼的位元組以替代字元取代
+    pub path: Option<String>,
+    pub text: Option<String>,
+}
+
+/// 擷取單字的結果與來源內容的 SHA-256，匯入時傳給 `ImportOptions.file_hash` 以記錄在匯入紀錄中
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedVocabulary {
+    #[serde(flatten)]
+    pub extraction: VocabularyExtraction,
+    /// 十六進位小寫
+    pub content_hash: String,
+}
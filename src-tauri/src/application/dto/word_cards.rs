@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::word_cards::{MergePolicy, NewWordCard, SaveOutcome, WordCardEntry};
+
+pub use crate::domain::repositories::word_card_repository::{
+    SortDirection, WordCardFilter, WordCardSortKey, WordMatch,
+};
+
+/// 每頁筆數預設值
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// 每頁筆數上限
+pub const MAX_PAGE_SIZE: i64 = 500;
+
+/// 分頁查詢單字卡
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListWordCardsQuery {
+    pub filter: WordCardFilter,
+    pub sort_key: WordCardSortKey,
+    pub sort_direction: SortDirection,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl ListWordCardsQuery {
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0).max(0)
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// 全文檢索筆數預設值
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+/// 全文檢索筆數上限
+pub const MAX_SEARCH_LIMIT: i64 = 200;
+
+/// 拼字建議筆數預設值
+pub const DEFAULT_SUGGESTION_LIMIT: usize = 5;
+
+/// 拼字建議筆數上限
+pub const MAX_SUGGESTION_LIMIT: usize = 50;
+
+/// 單頁查詢結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordCardPage {
+    pub items: Vec<WordCardEntry>,
+    /// 符合篩選條件的總筆數
+    pub total: i64,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+/// 依單字查詢的結果，包含比對到的方式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordCardLookup {
+    #[serde(flatten)]
+    pub entry: WordCardEntry,
+    /// 查詢時輸入的詞形（正規化後），例如以 "running" 查到 "run" 時為 "running"
+    pub surface_form: String,
+    pub matched_by: WordMatch,
+}
+
+/// 全文檢索的單筆結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordCardSearchHit {
+    pub card: WordCardEntry,
+    /// bm25 分數，數值越小越相關
+    pub score: f64,
+    /// 定義中命中段落的摘要，命中詞以 `<mark>` 標示
+    pub snippet: String,
+}
+
+/// `save_word_card` 的回傳結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveWordCardResult {
+    pub outcome: SaveOutcome,
+    /// 保存後的單字卡
+    pub card: WordCardEntry,
+}
+
+/// 保存單字卡；`policy` 未指定時為 `KeepExisting`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveWordCardCommand {
+    pub card: NewWordCard,
+    pub policy: Option<MergePolicy>,
+}
+
+/// 設定單字卡熟悉度（0-3）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UpdateFamiliarityCommand {
+    pub card_id: i32,
+    pub familiarity_level: i32,
+}
+
+/// 刪除單字卡
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DeleteWordCardCommand {
+    pub card_id: i32,
+}
+
+/// 依單字查詢單字卡，比對時會先正規化，找不到時改以詞元查詢
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetCardByWordQuery {
+    pub word: String,
+}
+
+/// 查詢單字的詞形變化
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetWordFormsQuery {
+    pub word: String,
+}
+
+/// 全文檢索單字卡，比對單字、定義與例句
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchWordCardsQuery {
+    pub query: String,
+    pub limit: Option<i64>,
+}
+
+impl SearchWordCardsQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT)
+    }
+}
+
+/// 查詢拼字建議；`include_word_list` 未指定時也比對內建字表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestWordsQuery {
+    pub word: String,
+    pub limit: Option<usize>,
+    pub include_word_list: Option<bool>,
+}
+
+impl SuggestWordsQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT).min(MAX_SUGGESTION_LIMIT)
+    }
+}
+
+/// 增加單字卡查看次數
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IncrementSeenCountCommand {
+    pub card_id: i32,
+}
@@ -0,0 +1,170 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::domain::aggregates::deck::DeckError;
+use crate::domain::repositories::word_card_repository::RepositoryError;
+use crate::infrastructure::persistence::DatabaseError;
+
+/// 指令回傳給前端的錯誤
+///
+/// 序列化為 `{ code, message, details }`：`code` 供前端判斷錯誤種類，
+/// `message` 為可直接顯示的訊息，`details` 為底層錯誤內容（可能為 `null`）。
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    ConnectionFailed(String),
+    InitializationFailed(String),
+    DirectoryCreationFailed(String),
+    MigrationFailed(String),
+    SchemaTooNew(String),
+    /// 資料庫查詢或寫入失敗
+    QueryFailed(String),
+    /// 找不到指定的資料
+    NotFound(String),
+    /// 輸入參數不合法
+    Validation(String),
+    /// 與現有資料衝突（例如違反唯一約束）
+    Conflict(String),
+}
+
+impl AppError {
+    /// 錯誤代碼
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::ConnectionFailed(_) => "CONNECTION_FAILED",
+            AppError::InitializationFailed(_) => "INITIALIZATION_FAILED",
+            AppError::DirectoryCreationFailed(_) => "DIRECTORY_CREATION_FAILED",
+            AppError::MigrationFailed(_) => "MIGRATION_FAILED",
+            AppError::SchemaTooNew(_) => "SCHEMA_TOO_NEW",
+            AppError::QueryFailed(_) => "QUERY_FAILED",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::Validation(_) => "VALIDATION",
+            AppError::Conflict(_) => "CONFLICT",
+        }
+    }
+
+    /// 顯示給使用者的訊息
+    pub fn message(&self) -> String {
+        match self {
+            AppError::ConnectionFailed(_) => "無法連接資料庫".to_string(),
+            AppError::InitializationFailed(_) => "資料庫初始化失敗".to_string(),
+            AppError::DirectoryCreationFailed(_) => "無法建立資料庫目錄，請確認是否有寫入權限".to_string(),
+            AppError::MigrationFailed(_) => "資料庫結構更新失敗".to_string(),
+            AppError::SchemaTooNew(_) => "資料庫由較新版本的應用程式建立，請更新應用程式".to_string(),
+            AppError::QueryFailed(_) => "資料庫操作失敗".to_string(),
+            AppError::NotFound(msg) | AppError::Validation(msg) | AppError::Conflict(msg) => msg.clone(),
+        }
+    }
+
+    /// 底層錯誤內容；訊息本身已完整描述問題時為 `None`
+    pub fn details(&self) -> Option<&str> {
+        match self {
+            AppError::ConnectionFailed(details)
+            | AppError::InitializationFailed(details)
+            | AppError::DirectoryCreationFailed(details)
+            | AppError::MigrationFailed(details)
+            | AppError::SchemaTooNew(details)
+            | AppError::QueryFailed(details) => Some(details),
+            AppError::NotFound(_) | AppError::Validation(_) | AppError::Conflict(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.details() {
+            Some(details) => write!(f, "{}: {}", self.message(), details),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.message())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
+
+impl From<DatabaseError> for AppError {
+    fn from(error: DatabaseError) -> Self {
+        match error {
+            DatabaseError::ConnectionFailed(msg) => AppError::ConnectionFailed(msg),
+            DatabaseError::InitializationFailed(msg) => AppError::InitializationFailed(msg),
+            DatabaseError::DirectoryCreationFailed(msg) => AppError::DirectoryCreationFailed(msg),
+            DatabaseError::MigrationFailed(msg) => AppError::MigrationFailed(msg),
+            DatabaseError::SchemaTooNew(msg) => AppError::SchemaTooNew(msg),
+        }
+    }
+}
+
+impl From<RepositoryError> for AppError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(msg) => AppError::NotFound(msg),
+            RepositoryError::Conflict(msg) => AppError::Conflict(msg),
+            RepositoryError::Storage(msg) => AppError::QueryFailed(msg),
+        }
+    }
+}
+
+impl From<DeckError> for AppError {
+    fn from(error: DeckError) -> Self {
+        match error {
+            DeckError::Invalid(msg) => AppError::Validation(msg),
+            DeckError::ParentNotFound(_) => AppError::NotFound(error.to_string()),
+            DeckError::DuplicateName(_) => AppError::Conflict(error.to_string()),
+        }
+    }
+}
+
+impl From<diesel::result::Error> for AppError {
+    fn from(error: diesel::result::Error) -> Self {
+        use diesel::result::{DatabaseErrorKind, Error};
+
+        match error {
+            Error::NotFound => AppError::NotFound("找不到指定的資料".to_string()),
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+                AppError::Conflict(format!("資料已存在: {}", info.message()))
+            }
+            Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info) => {
+                AppError::Conflict(format!("資料仍被其他紀錄參照: {}", info.message()))
+            }
+            other => AppError::QueryFailed(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_code_message_and_details() {
+        let json = serde_json::to_value(AppError::SchemaTooNew("version 99".to_string())).unwrap();
+        assert_eq!(json["code"], "SCHEMA_TOO_NEW");
+        assert_eq!(json["details"], "version 99");
+        assert!(json["message"].as_str().unwrap().contains("較新版本"));
+
+        let json = serde_json::to_value(AppError::Validation("單字不能為空".to_string())).unwrap();
+        assert_eq!(json["code"], "VALIDATION");
+        assert_eq!(json["message"], "單字不能為空");
+        assert!(json["details"].is_null());
+    }
+
+    #[test]
+    fn test_diesel_errors_are_classified() {
+        assert_eq!(AppError::from(diesel::result::Error::NotFound).code(), "NOT_FOUND");
+        assert_eq!(AppError::from(diesel::result::Error::RollbackTransaction).code(), "QUERY_FAILED");
+    }
+
+    #[test]
+    fn test_repository_not_found_keeps_message() {
+        let error = AppError::from(RepositoryError::NotFound("找不到標籤: 7".to_string()));
+        assert_eq!((error.code(), error.message().as_str()), ("NOT_FOUND", "找不到標籤: 7"));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod commands;
 pub mod queries;
 pub mod dto;
+pub mod errors;
 pub mod services;
@@ -0,0 +1,139 @@
+use log::{error, info, warn};
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::application::dto::anki::{
+    AnkiFieldMapping, AnkiNoteTypeSummary, AnkiPackageSummary, InspectAnkiPackageQuery, MAX_ANKI_NOTES,
+};
+use crate::application::errors::AppError;
+use crate::domain::services::anki::{field_mapping, AnkiCollection, AnkiPackageReader};
+use crate::domain::services::spreadsheet::validate_mapping;
+
+/// 檢視 Anki 套件：列出筆記類型、欄位與套用欄位對應後的結果，不寫入資料庫
+pub struct InspectAnkiPackage<'a> {
+    reader: &'a dyn AnkiPackageReader,
+}
+
+impl<'a> InspectAnkiPackage<'a> {
+    pub fn new(reader: &'a dyn AnkiPackageReader) -> Self {
+        Self { reader }
+    }
+
+    pub fn handle(&self, query: InspectAnkiPackageQuery) -> Result<AnkiPackageSummary, AppError> {
+        let collection = load_package(self.reader, &query.path)?;
+        let note_types = collection
+            .note_types
+            .iter()
+            .map(|note_type| {
+                let mapping = field_mapping(note_type, &query.field_map);
+                AnkiNoteTypeSummary {
+                    id: note_type.id,
+                    name: note_type.name.clone(),
+                    fields: note_type
+                        .fields
+                        .iter()
+                        .zip(&mapping)
+                        .map(|(name, field)| AnkiFieldMapping { name: name.clone(), field: *field })
+                        .collect(),
+                    note_count: collection.notes.iter().filter(|note| note.note_type_id == note_type.id).count(),
+                    error: validate_mapping(&mapping).err(),
+                }
+            })
+            .collect();
+
+        Ok(AnkiPackageSummary {
+            note_types,
+            note_count: collection.notes.len(),
+            card_count: collection.cards.len(),
+            review_count: collection.reviews.len(),
+            content_hash: collection.content_hash,
+        })
+    }
+}
+
+/// 確認檔案存在後讀取 Anki 套件
+pub(crate) fn load_package(reader: &dyn AnkiPackageReader, path: &str) -> Result<AnkiCollection, AppError> {
+    let path = path.trim();
+    if path.is_empty() {
+        warn!("Anki 套件路徑為空");
+        return Err(AppError::Validation("必須提供 Anki 套件的檔案路徑".to_string()));
+    }
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_file() => {}
+        Ok(_) => {
+            warn!("Anki 套件路徑不是檔案: '{}'", path);
+            return Err(AppError::Validation(format!("不是檔案: {}", path)));
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            warn!("⚠️ 找不到 Anki 套件: '{}'", path);
+            return Err(AppError::NotFound(format!("找不到檔案: {}", path)));
+        }
+        Err(e) => {
+            let error_msg = format!("讀取檔案失敗 - 路徑: '{}', 錯誤: {}", path, e);
+            error!("❌ {}", error_msg);
+            return Err(AppError::Validation(error_msg));
+        }
+    }
+
+    info!("讀取 Anki 套件: '{}'", path);
+    let collection = reader.read(path).map_err(|e| {
+        let error_msg = format!("讀取 Anki 套件失敗 - 路徑: '{}', 錯誤: {}", path, e);
+        error!("❌ {}", error_msg);
+        AppError::Validation(e)
+    })?;
+    if collection.notes.len() > MAX_ANKI_NOTES {
+        warn!("Anki 套件筆記數量過多: {}", collection.notes.len());
+        return Err(AppError::Validation(format!("一次最多只能匯入 {} 則筆記", MAX_ANKI_NOTES)));
+    }
+    info!(
+        "✅ 讀取 Anki 套件完成: {} 種筆記類型, {} 則筆記, {} 張卡片, {} 筆複習紀錄",
+        collection.note_types.len(),
+        collection.notes.len(),
+        collection.cards.len(),
+        collection.reviews.len()
+    );
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::anki::ColumnField;
+    use crate::domain::services::anki::{AnkiNote, AnkiNoteType};
+    use std::collections::HashMap;
+
+    struct FakeReader(AnkiCollection);
+
+    impl AnkiPackageReader for FakeReader {
+        fn read(&self, _path: &str) -> Result<AnkiCollection, String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_inspect_applies_field_map() {
+        let note_types = vec![
+            AnkiNoteType { id: 1, name: "Basic".to_string(), fields: vec!["Front".into(), "Back".into()] },
+            AnkiNoteType { id: 2, name: "Cloze".to_string(), fields: vec!["Text".into(), "Extra".into()] },
+        ];
+        let notes = vec![
+            AnkiNote { id: 10, note_type_id: 1, fields: vec!["apple".into(), "蘋果".into()] },
+            AnkiNote { id: 11, note_type_id: 1, fields: vec!["run".into(), "跑".into()] },
+            AnkiNote { id: 12, note_type_id: 2, fields: vec!["{{c1::cat}}".into(), String::new()] },
+        ];
+        let reader = FakeReader(AnkiCollection { note_types, notes, ..AnkiCollection::default() });
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").to_string();
+        let query = InspectAnkiPackageQuery { path: path.clone(), field_map: HashMap::from([("back".to_string(), ColumnField::Example)]) };
+
+        let summary = InspectAnkiPackage::new(&reader).handle(query).unwrap();
+        assert_eq!(summary.note_count, 3);
+        let basic = &summary.note_types[0];
+        assert_eq!((basic.note_count, basic.fields[1].field, basic.error.is_none()), (2, ColumnField::Example, true));
+        assert!(summary.note_types[1].error.is_some());
+
+        let missing = InspectAnkiPackageQuery { path: "/nonexistent/deck.apkg".to_string(), ..InspectAnkiPackageQuery::default() };
+        assert_eq!(InspectAnkiPackage::new(&reader).handle(missing).unwrap_err().code(), "NOT_FOUND");
+        let directory = InspectAnkiPackageQuery { path: env!("CARGO_MANIFEST_DIR").to_string(), ..InspectAnkiPackageQuery::default() };
+        assert_eq!(InspectAnkiPackage::new(&reader).handle(directory).unwrap_err().code(), "VALIDATION");
+    }
+}
@@ -0,0 +1,82 @@
+use log::{error, info};
+
+use crate::application::dto::decks::DeckSummary;
+use crate::application::errors::AppError;
+use crate::domain::aggregates::deck::DeckTree;
+use crate::domain::repositories::deck_repository::DeckRepository;
+
+/// 查詢所有牌組及其新卡片、學習中與到期卡片的數量
+pub struct GetDeckCounts<'a> {
+    repository: &'a dyn DeckRepository,
+}
+
+impl<'a> GetDeckCounts<'a> {
+    pub fn new(repository: &'a dyn DeckRepository) -> Self {
+        Self { repository }
+    }
+
+    /// `due_before` 之前到期的複習卡片算作到期，通常為今日結束的時間
+    pub fn handle(&self, due_before: &str) -> Result<Vec<DeckSummary>, AppError> {
+        info!("查詢牌組卡片數量: 到期時間早於 {}", due_before);
+
+        let result = self.repository.list_decks().and_then(|decks| {
+            let counts = self.repository.count_cards_by_deck(due_before)?;
+            Ok((DeckTree::new(decks), counts))
+        });
+
+        match result {
+            Ok((tree, counts)) => {
+                let totals = tree.totals(&counts);
+                let summaries = tree
+                    .decks()
+                    .iter()
+                    .map(|deck| {
+                        let deck_id = deck.id.unwrap_or_default();
+                        DeckSummary {
+                            deck: deck.clone(),
+                            counts: counts.get(&deck_id).copied().unwrap_or_default(),
+                            total: totals.get(&deck_id).copied().unwrap_or_default(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                info!("✅ 成功查詢牌組卡片數量，共 {} 個牌組", summaries.len());
+                Ok(summaries)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢牌組卡片數量失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::decks::CreateDeck;
+    use crate::application::dto::decks::CreateDeckCommand;
+    use crate::infrastructure::repositories::tests::add_card;
+    use crate::infrastructure::repositories::{InMemoryDeckRepository, InMemoryStore, InMemoryWordCardRepository};
+
+    #[test]
+    fn test_totals_include_sub_decks() {
+        let store = InMemoryStore::new();
+        let cards = InMemoryWordCardRepository::new(store.clone());
+        let repository = InMemoryDeckRepository::new(store);
+        let create = |name: &str, parent_id: Option<i32>| {
+            let command = CreateDeckCommand { name: name.to_string(), parent_id, settings: None };
+            CreateDeck::new(&repository).handle(command).unwrap().id.unwrap()
+        };
+        let english = create("English", None);
+        let toeic = create("TOEIC", Some(english));
+
+        repository.move_cards(&[add_card(&cards, "apple")], toeic).unwrap();
+
+        let summaries = GetDeckCounts::new(&repository).handle("2025-01-02 00:00:00").unwrap();
+        let summary = |deck_id: i32| summaries.iter().find(|s| s.deck.id == Some(deck_id)).unwrap();
+        assert_eq!(summaries.len(), 3);
+        assert_eq!((summary(english).counts.new, summary(english).total.new), (0, 1));
+        assert_eq!((summary(toeic).counts.new, summary(toeic).total.new), (1, 1));
+    }
+}
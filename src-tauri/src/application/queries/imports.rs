@@ -0,0 +1,107 @@
+use log::{error, info, warn};
+
+use crate::application::dto::imports::{CardSource, ImportRecord, ImportedCardEntry};
+use crate::application::errors::AppError;
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+
+/// 查詢所有匯入紀錄，新的在前
+pub struct ListImports<'a> {
+    repository: &'a dyn ImportRepository,
+}
+
+impl<'a> ListImports<'a> {
+    pub fn new(repository: &'a dyn ImportRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self) -> Result<Vec<ImportRecord>, AppError> {
+        info!("查詢匯入紀錄");
+
+        match self.repository.list_imports() {
+            Ok(imports) => {
+                info!("✅ 成功查詢匯入紀錄，共 {} 筆", imports.len());
+                Ok(imports)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢匯入紀錄失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 查詢一次匯入新增或比對到的單字卡；已復原的匯入沒有單字卡
+pub struct GetImportCards<'a> {
+    repository: &'a dyn WordCardRepository,
+    imports: &'a dyn ImportRepository,
+}
+
+impl<'a> GetImportCards<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository, imports: &'a dyn ImportRepository) -> Self {
+        Self { repository, imports }
+    }
+
+    pub fn handle(&self, import_id: i32) -> Result<Vec<ImportedCardEntry>, AppError> {
+        info!("查詢匯入的單字卡: 匯入 ID {}", import_id);
+
+        let result = self.imports.find_import(import_id).and_then(|record| match record {
+            Some(_) => {
+                let (sources, cards): (Vec<_>, Vec<_>) = self.imports.list_import_cards(import_id)?.into_iter().unzip();
+                let entries = self.repository.with_details(cards)?;
+                let imported = sources
+                    .into_iter()
+                    .zip(entries)
+                    .map(|(source, card)| ImportedCardEntry { source, card })
+                    .collect::<Vec<_>>();
+                Ok(Some(imported))
+            }
+            None => Ok(None),
+        });
+
+        match result {
+            Ok(Some(cards)) => {
+                info!("✅ 成功查詢匯入的單字卡，共 {} 張", cards.len());
+                Ok(cards)
+            }
+            Ok(None) => {
+                let error_msg = format!("找不到 ID 為 {} 的匯入紀錄", import_id);
+                warn!("⚠️ {}", error_msg);
+                Err(AppError::NotFound(error_msg))
+            }
+            Err(e) => {
+                let error_msg = format!("查詢匯入的單字卡失敗 - 匯入 ID: {}, 錯誤: {}", import_id, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 查詢單字卡的所有來源（匯入或手動查詢）
+pub struct GetCardSources<'a> {
+    repository: &'a dyn ImportRepository,
+}
+
+impl<'a> GetCardSources<'a> {
+    pub fn new(repository: &'a dyn ImportRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, card_id: i32) -> Result<Vec<CardSource>, AppError> {
+        info!("查詢單字卡來源: ID {}", card_id);
+
+        match self.repository.list_card_sources(card_id) {
+            Ok(sources) => {
+                info!("✅ 成功查詢單字卡來源，共 {} 筆", sources.len());
+                Ok(sources)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢單字卡來源失敗 - ID: {}, 錯誤: {}", card_id, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
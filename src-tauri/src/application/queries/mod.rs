@@ -1 +1,9 @@
-// Query handlers will be defined here
+pub mod anki;
+pub mod decks;
+pub mod imports;
+pub mod profiles;
+pub mod review;
+pub mod spreadsheets;
+pub mod tags;
+pub mod vocabulary;
+pub mod word_cards;
@@ -0,0 +1,34 @@
+use log::{error, info, warn};
+
+use crate::application::dto::profiles::Profile;
+use crate::application::errors::AppError;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+
+/// 取得目前使用中的設定檔
+pub struct GetActiveProfile<'a> {
+    repository: &'a dyn ProfileRepository,
+}
+
+impl<'a> GetActiveProfile<'a> {
+    pub fn new(repository: &'a dyn ProfileRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self) -> Result<Profile, AppError> {
+        info!("查詢使用中的設定檔");
+
+        match self.repository.find_active_profile() {
+            Ok(Some(profile)) => Ok(profile),
+            Ok(None) => {
+                let error_msg = "找不到使用中的設定檔".to_string();
+                warn!("⚠️ {}", error_msg);
+                Err(AppError::NotFound(error_msg))
+            }
+            Err(e) => {
+                let error_msg = format!("查詢設定檔失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::{Duration, NaiveDateTime};
+use log::{error, info, warn};
+
+use crate::application::dto::review_logs::{ReviewLogDto, ReviewLogQuery};
+use crate::application::dto::review_queue::{
+    ReviewQueue, ReviewQueueOptions, DEFAULT_NEW_CARDS_PER_DAY, DEFAULT_REVIEWS_PER_DAY,
+};
+use crate::application::errors::AppError;
+use crate::domain::aggregates::deck::DeckTree;
+use crate::domain::repositories::deck_repository::DeckRepository;
+use crate::domain::repositories::review_repository::{ReviewLogFilter, ReviewRepository};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::services::review_queue::{interleave, remaining_quota};
+use crate::domain::value_objects::review::format_timestamp;
+
+/// 單次查詢作答紀錄的預設筆數上限
+const DEFAULT_REVIEW_LOG_LIMIT: i64 = 500;
+
+/// 查詢作答紀錄，依作答時間由新到舊排序
+pub struct GetReviewLogs<'a> {
+    repository: &'a dyn ReviewRepository,
+}
+
+impl<'a> GetReviewLogs<'a> {
+    pub fn new(repository: &'a dyn ReviewRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, query: ReviewLogQuery) -> Result<Vec<ReviewLogDto>, AppError> {
+        info!("查詢作答紀錄: {:?}", query);
+        let limit = query.limit.unwrap_or(DEFAULT_REVIEW_LOG_LIMIT).max(1);
+        let filter = ReviewLogFilter { card_id: query.card_id, since: query.since, until: query.until };
+
+        match self.repository.list_review_logs(&filter, limit) {
+            Ok(logs) => {
+                info!("✅ 查詢到 {} 筆作答紀錄", logs.len());
+                Ok(logs.into_iter().map(ReviewLogDto::from).collect())
+            }
+            Err(e) => {
+                let error_msg = format!("查詢作答紀錄失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 取得今日的複習佇列
+///
+/// 到期的複習卡依到期時間由早到晚（逾期最久者優先），新卡片依建立順序，
+/// 兩者各自扣除今日已完成的數量後套用每日上限，再將新卡片穿插於複習卡之間。
+///
+/// 指定 `deck_id` 時只包含該牌組及其子牌組的卡片，未指定的每日上限改用該牌組的設定。
+pub struct GetReviewQueue<'a> {
+    decks: &'a dyn DeckRepository,
+    reviews: &'a dyn ReviewRepository,
+}
+
+impl<'a> GetReviewQueue<'a> {
+    pub fn new(decks: &'a dyn DeckRepository, reviews: &'a dyn ReviewRepository) -> Self {
+        Self { decks, reviews }
+    }
+
+    /// `today_start` 為今日零點對應的 UTC 時間
+    pub fn handle(&self, options: ReviewQueueOptions, today_start: NaiveDateTime) -> Result<ReviewQueue, AppError> {
+        if let Some(deck_id) = options.deck_id.filter(|d| *d <= 0) {
+            warn!("無效的牌組 ID: {}", deck_id);
+            return Err(AppError::Validation("牌組 ID 必須大於 0".to_string()));
+        }
+
+        info!("查詢複習佇列: {:?}", options);
+        // 牌組及其子牌組的 ID 與牌組設定的每日上限
+        let (deck_ids, new_default, review_default) = match options.deck_id {
+            Some(deck_id) => {
+                let decks = self.decks.list_decks().map_err(|e| {
+                    error!("❌ 查詢牌組失敗: {}", e);
+                    AppError::from(e)
+                })?;
+                let tree = DeckTree::new(decks);
+                let Some(deck) = tree.get(deck_id) else {
+                    let error_msg = format!("找不到 ID 為 {} 的牌組", deck_id);
+                    warn!("⚠️ {}", error_msg);
+                    return Err(AppError::NotFound(error_msg));
+                };
+                let limits = (i64::from(deck.settings.new_cards_per_day), i64::from(deck.settings.reviews_per_day));
+                (Some(tree.subtree_ids(deck_id)), limits.0, limits.1)
+            }
+            None => (None, DEFAULT_NEW_CARDS_PER_DAY, DEFAULT_REVIEWS_PER_DAY),
+        };
+        let new_limit = options.new_cards_per_day.unwrap_or(new_default).max(0);
+        let review_limit = options.reviews_per_day.unwrap_or(review_default).max(0);
+
+        match self.load_queue(deck_ids.as_deref(), new_limit, review_limit, today_start) {
+            Ok(queue) => {
+                info!(
+                    "✅ 複習佇列: {} 張複習、{} 張新卡（今日已完成 {} / {}）",
+                    queue.review_count, queue.new_count, queue.reviews_done_today, queue.new_done_today
+                );
+                Ok(queue)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢複習佇列失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+
+    fn load_queue(
+        &self,
+        deck_ids: Option<&[i32]>,
+        new_limit: i64,
+        review_limit: i64,
+        today_start: NaiveDateTime,
+    ) -> RepositoryResult<ReviewQueue> {
+        let today_end = format_timestamp(&(today_start + Duration::days(1)));
+        let done = self.reviews.count_reviews_since(&format_timestamp(&today_start), deck_ids)?;
+
+        let due_cards = self
+            .reviews
+            .list_due_cards(&today_end, deck_ids, remaining_quota(review_limit, done.reviews))?;
+        let new_cards = self.reviews.list_new_cards(deck_ids, remaining_quota(new_limit, done.new))?;
+
+        let review_count = due_cards.len() as i64;
+        let new_count = new_cards.len() as i64;
+        Ok(ReviewQueue {
+            cards: interleave(due_cards, new_cards),
+            new_count,
+            review_count,
+            new_done_today: done.new,
+            reviews_done_today: done.reviews,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::decks::CreateDeck;
+    use crate::application::commands::review::ReviewWordCard;
+    use crate::application::dto::decks::{CreateDeckCommand, DeckSettings};
+    use crate::application::dto::review_logs::ReviewWordCardCommand;
+    use crate::domain::value_objects::review::parse_timestamp;
+    use crate::infrastructure::repositories::{
+        InMemoryDeckRepository, InMemoryProfileRepository, InMemoryReviewRepository, InMemoryStore,
+        InMemoryWordCardRepository,
+    };
+    use crate::infrastructure::repositories::tests::add_card;
+
+    #[test]
+    fn test_review_queue_validates_deck() {
+        let store = InMemoryStore::new();
+        let decks = InMemoryDeckRepository::new(store.clone());
+        let reviews = InMemoryReviewRepository::new(store);
+        let handler = GetReviewQueue::new(&decks, &reviews);
+        let today_start = parse_timestamp("2025-01-01 00:00:00").unwrap();
+
+        let options = |deck_id| ReviewQueueOptions { deck_id: Some(deck_id), ..ReviewQueueOptions::default() };
+        assert_eq!(handler.handle(options(0), today_start).unwrap_err().code(), "VALIDATION");
+        assert_eq!(handler.handle(options(999), today_start).unwrap_err().code(), "NOT_FOUND");
+        assert!(handler.handle(ReviewQueueOptions::default(), today_start).unwrap().cards.is_empty());
+    }
+
+    #[test]
+    fn test_review_queue_uses_deck_limits_and_sub_decks() {
+        let store = InMemoryStore::new();
+        let cards = InMemoryWordCardRepository::new(store.clone());
+        let decks = InMemoryDeckRepository::new(store.clone());
+        let profiles = InMemoryProfileRepository::new(store.clone());
+        let reviews = InMemoryReviewRepository::new(store);
+        let create = |name: &str, parent_id: Option<i32>, new_cards_per_day: i32| {
+            let settings = DeckSettings { new_cards_per_day, ..DeckSettings::default() };
+            let command = CreateDeckCommand { name: name.to_string(), parent_id, settings: Some(settings) };
+            CreateDeck::new(&decks).handle(command).unwrap().id.unwrap()
+        };
+        let english = create("English", None, 2);
+        let toeic = create("TOEIC", Some(english), 20);
+        let [apple, banana, cherry, _] = ["apple", "banana", "cherry", "date"].map(|word| add_card(&cards, word));
+        decks.move_cards(&[apple, banana], english).unwrap();
+        decks.move_cards(&[cherry], toeic).unwrap();
+        let today_start = parse_timestamp("2025-01-01 00:00:00").unwrap();
+        let queue = |deck_id: Option<i32>, new_cards_per_day: Option<i64>| {
+            let options = ReviewQueueOptions { deck_id, new_cards_per_day, ..ReviewQueueOptions::default() };
+            GetReviewQueue::new(&decks, &reviews).handle(options, today_start).unwrap()
+        };
+        let ids = |queue: &ReviewQueue| queue.cards.iter().map(|c| c.id.unwrap()).collect::<Vec<_>>();
+
+        // 牌組的每日上限套用於整個子樹，明確指定的上限優先
+        assert_eq!(ids(&queue(Some(english), None)), [apple, banana]);
+        assert_eq!(ids(&queue(Some(english), Some(5))), [apple, banana, cherry]);
+        assert_eq!(ids(&queue(Some(toeic), None)), [cherry]);
+
+        // 今日在子牌組學過的新卡片也計入上層牌組的上限
+        let handler = ReviewWordCard::new(&cards, &decks, &profiles, &reviews);
+        let command = ReviewWordCardCommand { card_id: cherry, grade: 2, duration_ms: None };
+        handler.handle(command, today_start + Duration::hours(8)).unwrap();
+        let english_queue = queue(Some(english), None);
+        assert_eq!((ids(&english_queue), english_queue.new_done_today), (vec![apple], 1));
+        assert_eq!(queue(Some(toeic), None).new_done_today, 1);
+        assert_eq!(queue(None, None).new_count, 3);
+    }
+}
@@ -0,0 +1,160 @@
+use log::{error, info, warn};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::application::dto::spreadsheets::{
+    PreviewSpreadsheetQuery, SpreadsheetOptions, SpreadsheetPreview, SpreadsheetRowPreview, SpreadsheetSource,
+    MAX_SPREADSHEET_BYTES, MAX_SPREADSHEET_ROWS,
+};
+use crate::application::errors::AppError;
+use crate::domain::services::spreadsheet::{Spreadsheet, SpreadsheetSettings};
+
+/// 預覽試算表匯入：判斷編碼、分隔符號與標題列，轉換前幾列並驗證所有列，不寫入資料庫
+pub struct PreviewSpreadsheet;
+
+impl PreviewSpreadsheet {
+    pub fn handle(&self, query: PreviewSpreadsheetQuery) -> Result<SpreadsheetPreview, AppError> {
+        let limit = query.rows();
+        let (sheet, content_hash) = load_spreadsheet(query.source, &query.options)?;
+
+        let mut rows = Vec::new();
+        let mut error_count = 0;
+        for row in &sheet.rows {
+            let card = sheet.card(row);
+            error_count += usize::from(card.is_err());
+            if rows.len() < limit {
+                let (card, error) = match card {
+                    Ok(card) => (Some(card), None),
+                    Err(e) => (None, Some(e)),
+                };
+                rows.push(SpreadsheetRowPreview { line: row.line, values: row.values.clone(), card, error });
+            }
+        }
+
+        info!("✅ 預覽試算表: {} 列, {} 列有錯誤", sheet.rows.len(), error_count);
+        Ok(SpreadsheetPreview {
+            encoding: sheet.encoding,
+            lossy: sheet.lossy,
+            delimiter: char::from(sheet.delimiter),
+            has_header: sheet.headers.is_some(),
+            headers: sheet.headers.unwrap_or_default(),
+            mapping: sheet.mapping,
+            column_count: sheet.column_count,
+            total_rows: sheet.rows.len(),
+            valid_count: sheet.rows.len() - error_count,
+            error_count,
+            rows,
+            content_hash,
+        })
+    }
+}
+
+/// 讀取並解析試算表，回傳解析結果與檔案內容的 SHA-256
+pub(crate) fn load_spreadsheet(
+    source: SpreadsheetSource,
+    options: &SpreadsheetOptions,
+) -> Result<(Spreadsheet, String), AppError> {
+    let delimiter = match options.delimiter {
+        Some(delimiter) if delimiter.is_ascii() && delimiter != '"' && !matches!(delimiter, '\n' | '\r') => {
+            Some(delimiter as u8)
+        }
+        Some(delimiter) => {
+            warn!("無效的分隔符號: {:?}", delimiter);
+            return Err(AppError::Validation(format!("分隔符號必須是引號與換行以外的 ASCII 字元: {:?}", delimiter)));
+        }
+        None => None,
+    };
+
+    let bytes = match (source.path, source.content) {
+        (Some(path), None) => read_source(&path)?,
+        (None, Some(content)) => {
+            if content.len() as u64 > MAX_SPREADSHEET_BYTES {
+                warn!("試算表內容過大: {} bytes", content.len());
+                return Err(too_large());
+            }
+            content
+        }
+        _ => {
+            warn!("試算表的來源不正確");
+            return Err(AppError::Validation("必須提供檔案路徑或檔案內容其中一個".to_string()));
+        }
+    };
+
+    info!("解析試算表: {} bytes", bytes.len());
+    let settings = SpreadsheetSettings {
+        encoding: options.encoding,
+        delimiter,
+        has_header: options.has_header,
+        mapping: options.mapping.clone(),
+    };
+    let sheet = Spreadsheet::parse(&bytes, &settings).map_err(|e| {
+        warn!("解析試算表失敗: {}", e);
+        AppError::Validation(e)
+    })?;
+    if sheet.rows.len() > MAX_SPREADSHEET_ROWS {
+        warn!("試算表列數過多: {}", sheet.rows.len());
+        return Err(AppError::Validation(format!("一次最多只能匯入 {} 列", MAX_SPREADSHEET_ROWS)));
+    }
+    if sheet.lossy {
+        warn!("⚠️ 試算表有無法以 {:?} 解碼的內容", sheet.encoding);
+    }
+
+    let content_hash = format!("{:x}", Sha256::digest(&bytes));
+    Ok((sheet, content_hash))
+}
+
+fn too_large() -> AppError {
+    AppError::Validation(format!("檔案大小不能超過 {} MB", MAX_SPREADSHEET_BYTES / 1024 / 1024))
+}
+
+fn read_source(path: &str) -> Result<Vec<u8>, AppError> {
+    let read_failed = |e: std::io::Error| {
+        let error_msg = format!("讀取檔案失敗 - 路徑: '{}', 錯誤: {}", path, e);
+        error!("❌ {}", error_msg);
+        match e.kind() {
+            ErrorKind::NotFound => AppError::NotFound(format!("找不到檔案: {}", path)),
+            _ => AppError::Validation(error_msg),
+        }
+    };
+
+    let metadata = fs::metadata(path).map_err(read_failed)?;
+    if !metadata.is_file() {
+        warn!("試算表的路徑不是檔案: '{}'", path);
+        return Err(AppError::Validation(format!("不是檔案: {}", path)));
+    }
+    if metadata.len() > MAX_SPREADSHEET_BYTES {
+        warn!("試算表檔案過大: '{}', {} bytes", path, metadata.len());
+        return Err(too_large());
+    }
+    fs::read(path).map_err(read_failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview(content: &str, options: SpreadsheetOptions, rows: Option<usize>) -> Result<SpreadsheetPreview, AppError> {
+        let source = SpreadsheetSource { path: None, content: Some(content.as_bytes().to_vec()) };
+        PreviewSpreadsheet.handle(PreviewSpreadsheetQuery { source, options, rows })
+    }
+
+    #[test]
+    fn test_preview_limits_rows_and_counts_errors() {
+        let content = "word,pos,definition\napple,n.,蘋果\n,n.,沒有單字\ncat,n.,\nrun,v.,跑\n";
+        let result = preview(content, SpreadsheetOptions::default(), Some(2)).unwrap();
+        assert!(result.has_header);
+        assert_eq!(result.delimiter, ',');
+        assert_eq!((result.total_rows, result.valid_count, result.error_count), (4, 2, 2));
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0].card.as_ref().unwrap().word, "apple");
+        assert_eq!((result.rows[1].line, result.rows[1].error.as_deref()), (3, Some("缺少單字")));
+        assert_eq!(result.content_hash.len(), 64);
+
+        let options = SpreadsheetOptions { delimiter: Some('\n'), ..SpreadsheetOptions::default() };
+        assert_eq!(preview(content, options, None).unwrap_err().code(), "VALIDATION");
+        let source = SpreadsheetSource { path: Some("/nonexistent/words.csv".to_string()), content: None };
+        let missing = PreviewSpreadsheet.handle(PreviewSpreadsheetQuery { source, ..PreviewSpreadsheetQuery::default() });
+        assert_eq!(missing.unwrap_err().code(), "NOT_FOUND");
+    }
+}
@@ -0,0 +1,32 @@
+use log::{error, info};
+
+use crate::application::dto::tags::TagCount;
+use crate::application::errors::AppError;
+use crate::domain::repositories::tag_repository::TagRepository;
+
+/// 查詢所有標籤及其單字卡數量，供側邊欄顯示
+pub struct ListTags<'a> {
+    repository: &'a dyn TagRepository,
+}
+
+impl<'a> ListTags<'a> {
+    pub fn new(repository: &'a dyn TagRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self) -> Result<Vec<TagCount>, AppError> {
+        info!("查詢所有標籤");
+
+        match self.repository.list_tags() {
+            Ok(tags) => {
+                info!("✅ 成功查詢所有標籤，共 {} 個", tags.len());
+                Ok(tags)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢所有標籤失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
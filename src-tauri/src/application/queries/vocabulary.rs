@@ -0,0 +1,102 @@
+use log::{error, info, warn};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::application::dto::vocabulary::{ExtractVocabularyQuery, ExtractedVocabulary, MAX_VOCABULARY_SOURCE_BYTES};
+use crate::application::errors::AppError;
+use crate::domain::services::tokenizer::extract_vocabulary;
+
+/// 從文字檔或文字中擷取不重複的單字與片語，並計算出現次數
+pub struct ExtractVocabulary;
+
+impl ExtractVocabulary {
+    pub fn handle(&self, query: ExtractVocabularyQuery) -> Result<ExtractedVocabulary, AppError> {
+        let text = match (query.path, query.text) {
+            (Some(path), None) => read_source(&path)?,
+            (None, Some(text)) => {
+                if text.len() as u64 > MAX_VOCABULARY_SOURCE_BYTES {
+                    warn!("擷取單字的文字過大: {} bytes", text.len());
+                    return Err(too_large());
+                }
+                text
+            }
+            _ => {
+                warn!("擷取單字的來源不正確");
+                return Err(AppError::Validation("必須提供檔案路徑或文字其中一個".to_string()));
+            }
+        };
+
+        info!("擷取單字: {} bytes", text.len());
+        let content_hash = format!("{:x}", Sha256::digest(text.as_bytes()));
+        let extraction = extract_vocabulary(&text);
+        info!(
+            "✅ 擷取單字完成: {} 行, {} 個單字, 不重複 {} 個",
+            extraction.line_count,
+            extraction.token_count,
+            extraction.candidates.len()
+        );
+        Ok(ExtractedVocabulary { extraction, content_hash })
+    }
+}
+
+fn too_large() -> AppError {
+    AppError::Validation(format!("檔案大小不能超過 {} MB", MAX_VOCABULARY_SOURCE_BYTES / 1024 / 1024))
+}
+
+/// 讀取文字檔；無法以 UTF-8 解碼的位元組以替代字元取代
+fn read_source(path: &str) -> Result<String, AppError> {
+    let read_failed = |e: std::io::Error| {
+        let error_msg = format!("讀取檔案失敗 - 路徑: '{}', 錯誤: {}", path, e);
+        error!("❌ {}", error_msg);
+        match e.kind() {
+            ErrorKind::NotFound => AppError::NotFound(format!("找不到檔案: {}", path)),
+            _ => AppError::Validation(error_msg),
+        }
+    };
+
+    let metadata = fs::metadata(path).map_err(read_failed)?;
+    if !metadata.is_file() {
+        warn!("擷取單字的路徑不是檔案: '{}'", path);
+        return Err(AppError::Validation(format!("不是檔案: {}", path)));
+    }
+    if metadata.len() > MAX_VOCABULARY_SOURCE_BYTES {
+        warn!("擷取單字的檔案過大: '{}', {} bytes", path, metadata.len());
+        return Err(too_large());
+    }
+
+    let bytes = fs::read(path).map_err(read_failed)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_file_or_text_but_not_both() {
+        let path = std::env::temp_dir().join(format!("vocabulary-{}.txt", std::process::id()));
+        fs::write(&path, "apple\nbed\napple\n").unwrap();
+        let from_file = ExtractVocabulary.handle(ExtractVocabularyQuery {
+            path: Some(path.to_string_lossy().into_owned()),
+            text: None,
+        });
+        fs::remove_file(&path).unwrap();
+        let from_file = from_file.unwrap();
+        assert_eq!(from_file.extraction.candidates[0].count, 2);
+
+        let text = Some("apple\nbed\napple\n".to_string());
+        let from_text = ExtractVocabulary.handle(ExtractVocabularyQuery { path: None, text }).unwrap();
+        assert_eq!(from_text.extraction, from_file.extraction);
+        assert_eq!(from_text.content_hash, from_file.content_hash);
+        assert_eq!(from_text.content_hash.len(), 64);
+
+        let both = ExtractVocabulary.handle(ExtractVocabularyQuery { path: Some("a".to_string()), text: Some("b".to_string()) });
+        assert_eq!(both.unwrap_err().code(), "VALIDATION");
+        let missing = ExtractVocabulary.handle(ExtractVocabularyQuery {
+            path: Some("/nonexistent/words.txt".to_string()),
+            text: None,
+        });
+        assert_eq!(missing.unwrap_err().code(), "NOT_FOUND");
+    }
+}
@@ -0,0 +1,454 @@
+use log::{error, info, warn};
+
+use crate::application::dto::word_cards::{
+    GetCardByWordQuery, GetWordFormsQuery, ListWordCardsQuery, SearchWordCardsQuery, SortDirection,
+    SuggestWordsQuery, WordCardFilter, WordCardLookup, WordCardPage, WordCardSearchHit,
+    WordCardSortKey, WordMatch,
+};
+use crate::application::errors::AppError;
+use crate::domain::entities::word_cards::{WordCard, WordCardEntry};
+use crate::domain::repositories::word_card_repository::{search_terms, RepositoryResult, WordCardRepository};
+use crate::domain::services::inflection::inflect;
+use crate::domain::services::spelling::{self, Candidate, SuggestionSource, WordSuggestion};
+use crate::domain::value_objects::word::Word;
+use crate::domain::value_objects::word_forms::WordForms;
+
+/// 依單字查詢單字卡；單字格式不正確時視為查無資料
+///
+/// 沒有完全相同的單字卡時改以詞元查詢，結果中標示輸入的詞形與比對方式；
+/// 查詢的字在 `headwords`（內建字表）中時本身就是詞條，不會改以詞元查詢。
+pub struct GetCardByWord<'a> {
+    repository: &'a dyn WordCardRepository,
+    headwords: &'a [&'a str],
+}
+
+impl<'a> GetCardByWord<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository, headwords: &'a [&'a str]) -> Self {
+        Self { repository, headwords }
+    }
+
+    pub fn handle(&self, query: GetCardByWordQuery) -> Result<Option<WordCardLookup>, AppError> {
+        let word_query = query.word;
+        if word_query.trim().is_empty() {
+            warn!("查詢參數為空字串");
+            return Err(AppError::Validation("查詢單字不能為空".to_string()));
+        }
+
+        info!("查詢單字卡: '{}'", word_query);
+        let Ok(word) = Word::parse(&word_query) else {
+            warn!("⚠️ 查詢單字格式不正確: '{}'", word_query);
+            return Ok(None);
+        };
+
+        let result = self.repository.find_by_key_or_lemma(word.key(), self.headwords).and_then(|found| match found {
+            Some((card, matched_by)) => Ok(self
+                .repository
+                .with_details(vec![card])?
+                .pop()
+                .map(|entry| (entry, matched_by))),
+            None => Ok(None),
+        });
+
+        match result {
+            Ok(Some((entry, matched_by))) => {
+                if matched_by == WordMatch::Lemma {
+                    info!("✅ 以詞元找到單字卡: '{}' -> '{}'", word_query, entry.card.word);
+                } else {
+                    info!("✅ 找到單字卡: '{}'", word_query);
+                }
+                Ok(Some(WordCardLookup { entry, surface_form: word.as_str().to_string(), matched_by }))
+            }
+            Ok(None) => {
+                info!("未找到單字卡: '{}'", word_query);
+                Ok(None)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢單字卡失敗 - 單字: '{}', 錯誤: {}", word_query, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 分頁查詢單字卡，回傳單頁內容與符合條件的總筆數
+pub struct ListCards<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> ListCards<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, query: ListWordCardsQuery) -> Result<WordCardPage, AppError> {
+        info!("分頁查詢單字卡: {:?}", query);
+        if let Some(expression) = &query.filter.tags {
+            expression.validate().map_err(|e| {
+                warn!("⚠️ 標籤篩選條件不正確: {}", e);
+                AppError::Validation(e)
+            })?;
+        }
+        let offset = query.offset();
+        let limit = query.limit();
+
+        let total = self.repository.count(&query.filter).map_err(|e| {
+            let error_msg = format!("計算單字卡總數失敗: {}", e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        let result = self
+            .repository
+            .list(&query.filter, query.sort_key, query.sort_direction, offset, limit)
+            .and_then(|cards| self.repository.with_details(cards));
+
+        match result {
+            Ok(items) => {
+                info!("✅ 分頁查詢成功: {} / {} 筆", items.len(), total);
+                Ok(WordCardPage { items, total, offset, limit })
+            }
+            Err(e) => {
+                let error_msg = format!("分頁查詢單字卡失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 查詢所有單字卡，依建立時間排序
+pub struct GetAllCards<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> GetAllCards<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    /// 查詢所有單字卡及其詞義、發音與標籤
+    pub fn handle(&self) -> Result<Vec<WordCardEntry>, AppError> {
+        info!("查詢所有單字卡");
+        let result = self.list().and_then(|cards| self.repository.with_details(cards));
+
+        match result {
+            Ok(cards) => {
+                info!("✅ 成功查詢所有單字卡，共 {} 筆記錄", cards.len());
+                Ok(cards)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢所有單字卡失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+
+    /// 只查詢單字卡本身，用於排查問題
+    pub fn handle_simple(&self) -> Result<Vec<WordCard>, AppError> {
+        info!("執行簡化版查詢所有單字卡");
+        match self.list() {
+            Ok(cards) => {
+                info!("✅ 查詢成功，找到 {} 筆記錄", cards.len());
+                Ok(cards)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+
+    fn list(&self) -> RepositoryResult<Vec<WordCard>> {
+        self.repository
+            .list(&WordCardFilter::default(), WordCardSortKey::CreatedAt, SortDirection::Asc, 0, i64::MAX)
+    }
+}
+
+/// 全文檢索單字卡：比對單字、定義與例句，支援前綴比對並依相關程度排序
+pub struct SearchCards<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> SearchCards<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, query: SearchWordCardsQuery) -> Result<Vec<WordCardSearchHit>, AppError> {
+        let terms = search_terms(&query.query);
+        if terms.is_empty() {
+            warn!("全文檢索參數沒有可檢索的詞: '{}'", query.query);
+            return Err(AppError::Validation("檢索內容不能為空".to_string()));
+        }
+
+        info!("全文檢索單字卡: '{}' -> {:?}", query.query, terms);
+        let result = self.repository.search(&terms, query.limit()).and_then(|matches| {
+            let (cards, ranks): (Vec<_>, Vec<_>) = matches.into_iter().map(|m| (m.card, (m.score, m.snippet))).unzip();
+            let entries = self.repository.with_details(cards)?;
+            Ok(entries
+                .into_iter()
+                .zip(ranks)
+                .map(|(card, (score, snippet))| WordCardSearchHit { card, score, snippet })
+                .collect::<Vec<_>>())
+        });
+
+        match result {
+            Ok(hits) => {
+                info!("✅ 全文檢索完成，找到 {} 筆結果", hits.len());
+                Ok(hits)
+            }
+            Err(e) => {
+                let error_msg = format!("全文檢索失敗 - 查詢: '{}', 錯誤: {}", query.query, e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 拼字建議：以編輯距離與發音碼從既有單字卡（及 `dictionary`，即內建字表）中找出相近的單字
+pub struct SuggestWords<'a> {
+    repository: &'a dyn WordCardRepository,
+    dictionary: &'a [&'a str],
+}
+
+impl<'a> SuggestWords<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository, dictionary: &'a [&'a str]) -> Self {
+        Self { repository, dictionary }
+    }
+
+    pub fn handle(&self, query: SuggestWordsQuery) -> Result<Vec<WordSuggestion>, AppError> {
+        if query.word.trim().is_empty() {
+            warn!("查詢參數為空字串");
+            return Err(AppError::Validation("查詢單字不能為空".to_string()));
+        }
+
+        info!("查詢拼字建議: '{}'", query.word);
+        let words = self.repository.list_words().map_err(|e| {
+            let error_msg = format!("查詢單字列表失敗: {}", e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        let cards = words.iter().map(|(card_id, word)| Candidate {
+            word,
+            source: SuggestionSource::Card,
+            card_id: Some(*card_id),
+        });
+        let dictionary = if query.include_word_list.unwrap_or(true) { self.dictionary } else { &[] };
+        let dictionary = dictionary.iter().map(|w| Candidate {
+            word: w,
+            source: SuggestionSource::Dictionary,
+            card_id: None,
+        });
+
+        let suggestions = spelling::suggest(&query.word, cards.chain(dictionary), query.limit());
+        info!("✅ 找到 {} 個拼字建議: '{}'", suggestions.len(), query.word);
+        Ok(suggestions)
+    }
+}
+
+/// 測試資料庫連接：以單字卡總數確認可以查詢
+pub struct CheckConnection<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> CheckConnection<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self) -> Result<String, AppError> {
+        info!("測試資料庫連接");
+        match self.repository.count(&WordCardFilter::default()) {
+            Ok(count) => {
+                let msg = format!("資料庫連接正常，共有 {} 筆單字卡記錄", count);
+                info!("✅ {}", msg);
+                Ok(msg)
+            }
+            Err(e) => {
+                let error_msg = format!("查詢計數失敗: {}", e);
+                error!("❌ {}", error_msg);
+                Err(AppError::from(e))
+            }
+        }
+    }
+}
+
+/// 查詢單字的詞形變化
+///
+/// 已有單字卡時回傳保存時依詞性產生的變化，否則由變化規則產生所有可能的變化。
+pub struct GetWordForms<'a> {
+    repository: &'a dyn WordCardRepository,
+}
+
+impl<'a> GetWordForms<'a> {
+    pub fn new(repository: &'a dyn WordCardRepository) -> Self {
+        Self { repository }
+    }
+
+    pub fn handle(&self, query: GetWordFormsQuery) -> Result<WordForms, AppError> {
+        let word = Word::parse(&query.word).map_err(|e| {
+            warn!("⚠️ 單字格式不正確: '{}', 錯誤: {}", query.word, e);
+            AppError::Validation(e)
+        })?;
+
+        info!("查詢詞形變化: '{}'", word);
+        let card = self.repository.find_by_key(word.key()).map_err(|e| {
+            let error_msg = format!("查詢詞形變化失敗 - 單字: '{}', 錯誤: {}", word, e);
+            error!("❌ {}", error_msg);
+            AppError::from(e)
+        })?;
+
+        let forms = match card {
+            Some(card) => card.verbs.unwrap_or_default(),
+            None => inflect(word.as_str()),
+        };
+        info!("✅ 詞形變化查詢完成: '{}'", word);
+        Ok(forms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::tag::TagExpression;
+    use crate::domain::entities::word_cards::MergePolicy;
+    use crate::infrastructure::repositories::tests::new_card;
+    use crate::infrastructure::repositories::{InMemoryStore, InMemoryWordCardRepository};
+
+    fn repository_with(words: &[&str]) -> InMemoryWordCardRepository {
+        let repository = InMemoryWordCardRepository::new(InMemoryStore::new());
+        for w in words {
+            repository.save(&new_card(w, ""), MergePolicy::KeepExisting).unwrap();
+        }
+        repository
+    }
+
+    const HEADWORDS: &[&str] = &["bed", "news", "run", "seed", "thing"];
+
+    #[test]
+    fn test_get_card_by_word_normalizes_query() {
+        let repository = repository_with(&["Apple"]);
+        let handler = GetCardByWord::new(&repository, HEADWORDS);
+
+        let found = handler.handle(GetCardByWordQuery { word: " APPLE ".to_string() }).unwrap().unwrap();
+        assert_eq!(found.entry.card.word, "Apple");
+        assert_eq!(found.matched_by, WordMatch::Exact);
+        assert!(handler.handle(GetCardByWordQuery { word: "pear".to_string() }).unwrap().is_none());
+        assert_eq!(
+            handler.handle(GetCardByWordQuery { word: " ".to_string() }).unwrap_err().code(),
+            "VALIDATION"
+        );
+    }
+
+    #[test]
+    fn test_get_card_by_word_falls_back_to_lemma() {
+        let repository = repository_with(&["run", "mouse"]);
+        let handler = GetCardByWord::new(&repository, HEADWORDS);
+
+        let running = handler.handle(GetCardByWordQuery { word: "Running".to_string() }).unwrap().unwrap();
+        assert_eq!(running.entry.card.word, "run");
+        assert_eq!(running.surface_form, "Running");
+        assert_eq!(running.matched_by, WordMatch::Lemma);
+
+        let mice = handler.handle(GetCardByWordQuery { word: "mice".to_string() }).unwrap().unwrap();
+        assert_eq!(mice.entry.card.word, "mouse");
+        assert!(handler.handle(GetCardByWordQuery { word: "mouth".to_string() }).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_get_card_by_word_keeps_headwords() {
+        let repository = repository_with(&["see", "new", "the", "be"]);
+        let handler = GetCardByWord::new(&repository, HEADWORDS);
+
+        for word in ["seed", "news", "thing", "bed"] {
+            assert!(handler.handle(GetCardByWordQuery { word: word.to_string() }).unwrap().is_none(), "{}", word);
+        }
+        // 不在字表中的變化形仍會還原
+        let seen = handler.handle(GetCardByWordQuery { word: "seeing".to_string() }).unwrap().unwrap();
+        assert_eq!((seen.entry.card.word.as_str(), seen.matched_by), ("see", WordMatch::Lemma));
+    }
+
+    #[test]
+    fn test_list_cards_pages_and_clamps_limit() {
+        let repository = repository_with(&["cherry", "apple", "banana"]);
+        let page = ListCards::new(&repository)
+            .handle(ListWordCardsQuery {
+                sort_key: WordCardSortKey::Word,
+                sort_direction: SortDirection::Desc,
+                offset: Some(1),
+                limit: Some(0),
+                ..ListWordCardsQuery::default()
+            })
+            .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.limit, 1);
+        assert_eq!(page.items.iter().map(|c| c.card.word.as_str()).collect::<Vec<_>>(), ["banana"]);
+
+        let invalid_tags = ListWordCardsQuery {
+            filter: WordCardFilter { tags: Some(TagExpression::Tag(0)), ..WordCardFilter::default() },
+            ..ListWordCardsQuery::default()
+        };
+        assert_eq!(ListCards::new(&repository).handle(invalid_tags).unwrap_err().code(), "VALIDATION");
+    }
+
+    #[test]
+    fn test_word_forms_prefer_saved_card() {
+        let repository = InMemoryWordCardRepository::new(InMemoryStore::new());
+        repository.save(&new_card("run", "[verb] to move quickly"), MergePolicy::KeepExisting).unwrap();
+        let handler = GetWordForms::new(&repository);
+
+        // 保存的卡片只有動詞詞義
+        let saved = handler.handle(GetWordFormsQuery { word: "Run".to_string() }).unwrap();
+        assert_eq!(saved.past.as_deref(), Some("ran"));
+        assert_eq!(saved.plural, None);
+
+        let unsaved = handler.handle(GetWordFormsQuery { word: "box".to_string() }).unwrap();
+        assert_eq!(unsaved.plural.as_deref(), Some("boxes"));
+        assert_eq!(unsaved.past.as_deref(), Some("boxed"));
+        assert_eq!(
+            handler.handle(GetWordFormsQuery { word: " ".to_string() }).unwrap_err().code(),
+            "VALIDATION"
+        );
+    }
+
+    #[test]
+    fn test_search_cards_validates_terms_and_keeps_rank_order() {
+        let repository = repository_with(&["harvest", "harbor", "sow"]);
+        let handler = SearchCards::new(&repository);
+
+        let hits = handler.handle(SearchWordCardsQuery { query: "har".to_string(), limit: Some(1) }).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].card.card.word, "harvest");
+        assert_eq!(
+            handler.handle(SearchWordCardsQuery { query: " ?! ".to_string(), limit: None }).unwrap_err().code(),
+            "VALIDATION"
+        );
+    }
+
+    #[test]
+    fn test_suggest_words_marks_card_and_dictionary_sources() {
+        let repository = repository_with(&["receive"]);
+        let handler = SuggestWords::new(&repository, &["recede", "receive"]);
+        let query = |include_word_list| SuggestWordsQuery {
+            word: "recieve".to_string(),
+            limit: None,
+            include_word_list,
+        };
+
+        let suggestions = handler.handle(query(None)).unwrap();
+        assert_eq!(suggestions[0].word, "receive");
+        assert_eq!(suggestions[0].source, SuggestionSource::Card);
+        assert!(suggestions[0].card_id.is_some());
+        assert!(handler
+            .handle(query(Some(false)))
+            .unwrap()
+            .iter()
+            .all(|s| s.source == SuggestionSource::Card));
+    }
+}
@@ -0,0 +1,360 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+use crate::domain::value_objects::word::normalize_text;
+
+/// 預設牌組的 ID；未指定牌組的單字卡都放在這裡，此牌組不能刪除
+pub const DEFAULT_DECK_ID: i32 = 1;
+
+/// 牌組名稱的最大長度（字元數）
+pub const MAX_DECK_NAME_LENGTH: usize = 100;
+
+/// 牌組的最大巢狀層數（最上層為第 1 層）
+pub const MAX_DECK_DEPTH: usize = 5;
+
+/// 每日上限的最大值
+pub const MAX_DAILY_LIMIT: i32 = 9999;
+
+/// 學習步驟的最大數量
+pub const MAX_LEARNING_STEPS: usize = 10;
+
+/// 單一學習步驟的最大分鐘數（一天）
+pub const MAX_STEP_MINUTES: i32 = 1440;
+
+/// 牌組操作違反規則的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeckError {
+    /// 名稱或設定不合法
+    Invalid(String),
+    /// 指定的上層牌組不存在
+    ParentNotFound(i32),
+    /// 同一層已有相同名稱的牌組
+    DuplicateName(String),
+}
+
+impl std::fmt::Display for DeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeckError::Invalid(msg) => write!(f, "{}", msg),
+            DeckError::ParentNotFound(parent_id) => write!(f, "找不到 ID 為 {} 的上層牌組", parent_id),
+            DeckError::DuplicateName(name) => write!(f, "同一層已有名稱為 '{}' 的牌組", name),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+/// 牌組的複習設定
+///
+/// 學習步驟以分鐘為單位：新卡片依 `learning_steps`、遺忘的卡片依 `relearning_steps`
+/// 在當天重複出現，走完所有步驟後才交由排程演算法安排；空陣列代表不使用學習步驟。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeckSettings {
+    pub new_cards_per_day: i32,
+    pub reviews_per_day: i32,
+    pub learning_steps: Vec<i32>,
+    pub relearning_steps: Vec<i32>,
+}
+
+impl Default for DeckSettings {
+    fn default() -> Self {
+        Self {
+            new_cards_per_day: 20,
+            reviews_per_day: 200,
+            learning_steps: Vec::new(),
+            relearning_steps: Vec::new(),
+        }
+    }
+}
+
+impl DeckSettings {
+    pub fn validate(&self) -> Result<(), DeckError> {
+        for (label, limit) in [("每日新卡片上限", self.new_cards_per_day), ("每日複習上限", self.reviews_per_day)] {
+            if !(0..=MAX_DAILY_LIMIT).contains(&limit) {
+                return Err(DeckError::Invalid(format!("{}必須在 0-{} 之間", label, MAX_DAILY_LIMIT)));
+            }
+        }
+        for steps in [&self.learning_steps, &self.relearning_steps] {
+            if steps.len() > MAX_LEARNING_STEPS {
+                return Err(DeckError::Invalid(format!("學習步驟不能超過 {} 個", MAX_LEARNING_STEPS)));
+            }
+            if steps.iter().any(|minutes| !(1..=MAX_STEP_MINUTES).contains(minutes)) {
+                return Err(DeckError::Invalid(format!("學習步驟必須在 1-{} 分鐘之間", MAX_STEP_MINUTES)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 牌組聚合根：名稱、上層牌組與複習設定
+///
+/// 單一牌組的規則（名稱、設定）由此型別保證；與其他牌組相關的規則
+/// （上層牌組存在、不形成循環、層數、同層名稱不重複）由 `DeckTree::check_placement` 檢查。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deck {
+    pub id: Option<i32>,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub settings: DeckSettings,
+    pub created_at: Option<String>,
+}
+
+impl Deck {
+    /// 建立尚未保存的牌組
+    pub fn new(name: &str, parent_id: Option<i32>, settings: DeckSettings) -> Result<Self, DeckError> {
+        settings.validate()?;
+        Ok(Self {
+            id: None,
+            name: Self::parse_name(name)?,
+            parent_id,
+            settings,
+            created_at: None,
+        })
+    }
+
+    /// 整理名稱：與單字相同的空白與引號正規化
+    fn parse_name(raw: &str) -> Result<String, DeckError> {
+        let name = normalize_text(raw);
+        if name.is_empty() {
+            return Err(DeckError::Invalid("牌組名稱不能為空".to_string()));
+        }
+        if name.chars().count() > MAX_DECK_NAME_LENGTH {
+            return Err(DeckError::Invalid(format!("牌組名稱長度不能超過 {} 個字元", MAX_DECK_NAME_LENGTH)));
+        }
+        if name.chars().any(char::is_control) {
+            return Err(DeckError::Invalid("牌組名稱不能包含控制字元".to_string()));
+        }
+        Ok(name)
+    }
+
+    pub fn rename(&mut self, name: &str) -> Result<(), DeckError> {
+        self.name = Self::parse_name(name)?;
+        Ok(())
+    }
+
+    pub fn move_to(&mut self, parent_id: Option<i32>) {
+        self.parent_id = parent_id;
+    }
+
+    pub fn update_settings(&mut self, settings: DeckSettings) -> Result<(), DeckError> {
+        settings.validate()?;
+        self.settings = settings;
+        Ok(())
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.id == Some(DEFAULT_DECK_ID)
+    }
+}
+
+/// 牌組內單字卡的數量
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckCounts {
+    /// 尚未學習的新卡片
+    pub new: i64,
+    /// 正在學習或重新學習步驟中的卡片
+    pub learning: i64,
+    /// 今日到期的複習卡片（不含學習中的卡片）
+    pub due: i64,
+}
+
+impl AddAssign for DeckCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.new += other.new;
+        self.learning += other.learning;
+        self.due += other.due;
+    }
+}
+
+/// 所有牌組組成的樹，用於檢查跨牌組的規則與彙總子牌組
+#[derive(Debug, Clone, Default)]
+pub struct DeckTree {
+    decks: Vec<Deck>,
+}
+
+impl DeckTree {
+    pub fn new(decks: Vec<Deck>) -> Self {
+        Self { decks }
+    }
+
+    pub fn decks(&self) -> &[Deck] {
+        &self.decks
+    }
+
+    pub fn get(&self, deck_id: i32) -> Option<&Deck> {
+        self.decks.iter().find(|d| d.id == Some(deck_id))
+    }
+
+    fn children(&self, deck_id: i32) -> impl Iterator<Item = &Deck> {
+        self.decks.iter().filter(move |d| d.parent_id == Some(deck_id))
+    }
+
+    /// 牌組本身及其所有子孫牌組的 ID，牌組本身排在第一個
+    pub fn subtree_ids(&self, deck_id: i32) -> Vec<i32> {
+        let mut ids = vec![deck_id];
+        let mut index = 0;
+        while index < ids.len() {
+            let current = ids[index];
+            for child in self.children(current).filter_map(|d| d.id) {
+                if !ids.contains(&child) {
+                    ids.push(child);
+                }
+            }
+            index += 1;
+        }
+        ids
+    }
+
+    /// 牌組所在的層數（最上層為 1）
+    fn depth(&self, deck_id: i32) -> usize {
+        let mut depth = 1;
+        let mut current = self.get(deck_id).and_then(|d| d.parent_id);
+        while let Some(parent_id) = current {
+            depth += 1;
+            if depth > self.decks.len() {
+                break;
+            }
+            current = self.get(parent_id).and_then(|d| d.parent_id);
+        }
+        depth
+    }
+
+    /// 牌組以下的層數（沒有子牌組時為 1）
+    fn height(&self, deck_id: i32) -> usize {
+        let subtree = self.subtree_ids(deck_id);
+        subtree
+            .iter()
+            .map(|id| self.depth(*id))
+            .max()
+            .map_or(1, |deepest| deepest + 1 - self.depth(deck_id))
+    }
+
+    /// 檢查牌組放在 `deck.parent_id` 之下是否合法：上層牌組存在、不會形成循環、
+    /// 不超過最大層數，且同一層沒有相同名稱（不分大小寫）的其他牌組
+    pub fn check_placement(&self, deck: &Deck) -> Result<(), DeckError> {
+        if let Some(parent_id) = deck.parent_id {
+            if self.get(parent_id).is_none() {
+                return Err(DeckError::ParentNotFound(parent_id));
+            }
+            if let Some(deck_id) = deck.id {
+                if self.subtree_ids(deck_id).contains(&parent_id) {
+                    return Err(DeckError::Invalid("不能將牌組移到自己或自己的子牌組之下".to_string()));
+                }
+            }
+            let height = deck.id.map_or(1, |deck_id| self.height(deck_id));
+            if self.depth(parent_id) + height > MAX_DECK_DEPTH {
+                return Err(DeckError::Invalid(format!("牌組不能超過 {} 層", MAX_DECK_DEPTH)));
+            }
+        }
+
+        let name_key = deck.name.to_lowercase();
+        let duplicate = self
+            .decks
+            .iter()
+            .any(|d| d.id != deck.id && d.parent_id == deck.parent_id && d.name.to_lowercase() == name_key);
+        if duplicate {
+            return Err(DeckError::DuplicateName(deck.name.clone()));
+        }
+        Ok(())
+    }
+
+    /// 將每個牌組本身的數量加總為包含子孫牌組的數量
+    pub fn totals(&self, counts: &HashMap<i32, DeckCounts>) -> HashMap<i32, DeckCounts> {
+        self.decks
+            .iter()
+            .filter_map(|d| d.id)
+            .map(|deck_id| {
+                let mut total = DeckCounts::default();
+                for id in self.subtree_ids(deck_id) {
+                    total += counts.get(&id).copied().unwrap_or_default();
+                }
+                (deck_id, total)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck(id: i32, name: &str, parent_id: Option<i32>) -> Deck {
+        Deck {
+            id: Some(id),
+            ..Deck::new(name, parent_id, DeckSettings::default()).unwrap()
+        }
+    }
+
+    fn tree() -> DeckTree {
+        DeckTree::new(vec![
+            deck(1, "Default", None),
+            deck(2, "English", None),
+            deck(3, "TOEIC", Some(2)),
+            deck(4, "Listening", Some(3)),
+        ])
+    }
+
+    #[test]
+    fn test_names_and_settings_are_validated() {
+        assert_eq!(Deck::new("  GRE   words ", None, DeckSettings::default()).unwrap().name, "GRE words");
+        assert!(Deck::new(" ", None, DeckSettings::default()).is_err());
+
+        let negative = DeckSettings { new_cards_per_day: -1, ..DeckSettings::default() };
+        assert!(Deck::new("GRE", None, negative).is_err());
+        let zero_step = DeckSettings { learning_steps: vec![1, 0], ..DeckSettings::default() };
+        assert!(Deck::new("GRE", None, zero_step).is_err());
+        let steps = DeckSettings { learning_steps: vec![1, 10], relearning_steps: vec![10], ..DeckSettings::default() };
+        assert!(Deck::new("GRE", None, steps).is_ok());
+    }
+
+    #[test]
+    fn test_placement_rejects_cycles_duplicates_and_missing_parents() {
+        let tree = tree();
+        assert_eq!(tree.subtree_ids(2), [2, 3, 4]);
+
+        let mut english = tree.get(2).unwrap().clone();
+        english.move_to(Some(4));
+        assert!(matches!(tree.check_placement(&english), Err(DeckError::Invalid(_))));
+
+        let duplicate = Deck::new("toeic", Some(2), DeckSettings::default()).unwrap();
+        assert_eq!(tree.check_placement(&duplicate), Err(DeckError::DuplicateName("toeic".to_string())));
+        // 不同層可以同名
+        let other_level = Deck::new("toeic", None, DeckSettings::default()).unwrap();
+        assert!(tree.check_placement(&other_level).is_ok());
+
+        let orphan = Deck::new("Reading", Some(99), DeckSettings::default()).unwrap();
+        assert_eq!(tree.check_placement(&orphan), Err(DeckError::ParentNotFound(99)));
+    }
+
+    #[test]
+    fn test_depth_limit_counts_moved_subtree() {
+        let mut decks = tree().decks().to_vec();
+        decks.push(deck(5, "Part 1", Some(4)));
+        decks.push(deck(6, "Grammar", Some(1)));
+        let tree = DeckTree::new(decks);
+
+        // Part 1 在第 4 層，再往下一層剛好到上限
+        assert!(tree.check_placement(&Deck::new("Q1", Some(5), DeckSettings::default()).unwrap()).is_ok());
+        // English 連同子牌組共 4 層：移到第 1 層之下剛好到上限，移到第 2 層之下則超過
+        let mut english = tree.get(2).unwrap().clone();
+        english.move_to(Some(1));
+        assert!(tree.check_placement(&english).is_ok());
+        english.move_to(Some(6));
+        assert!(matches!(tree.check_placement(&english), Err(DeckError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_totals_include_subdecks() {
+        let counts = HashMap::from([
+            (2, DeckCounts { new: 1, learning: 0, due: 2 }),
+            (4, DeckCounts { new: 3, learning: 1, due: 0 }),
+        ]);
+        let totals = tree().totals(&counts);
+
+        assert_eq!(totals[&2], DeckCounts { new: 4, learning: 1, due: 2 });
+        assert_eq!(totals[&3], DeckCounts { new: 3, learning: 1, due: 0 });
+        assert_eq!(totals[&1], DeckCounts::default());
+    }
+}
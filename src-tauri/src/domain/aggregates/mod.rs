@@ -1 +1 @@
-// Aggregates will be defined here
+pub mod deck;
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use crate::infrastructure::persistence::schema::{card_sources, imports};
+use diesel::prelude::*;
+use diesel::sqlite::Sqlite;
+use super::word_cards::WordCard;
+
+/// 匯入紀錄的狀態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    Running,
+    Completed,
+    Cancelled,
+    /// 已復原：由此匯入建立的單字卡已刪除，累加的出現次數已扣回
+    Undone,
+}
+
+impl ImportStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ImportStatus::Running => "running",
+            ImportStatus::Completed => "completed",
+            ImportStatus::Cancelled => "cancelled",
+            ImportStatus::Undone => "undone",
+        }
+    }
+}
+
+impl std::str::FromStr for ImportStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "running" => Ok(ImportStatus::Running),
+            "completed" => Ok(ImportStatus::Completed),
+            "cancelled" => Ok(ImportStatus::Cancelled),
+            "undone" => Ok(ImportStatus::Undone),
+            _ => Err(format!("未知的匯入狀態: {}", value)),
+        }
+    }
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = imports)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewImportRecord {
+    pub file_name: Option<String>,
+    pub file_hash: Option<String>,
+    pub total_count: i32,
+}
+
+/// 一次匯入的紀錄
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[diesel(table_name = imports)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct ImportRecord {
+    pub id: Option<i32>,
+    pub file_name: Option<String>,
+    /// 檔案內容的 SHA-256（十六進位小寫）
+    pub file_hash: Option<String>,
+    /// `ImportStatus` 的字串形式
+    pub status: String,
+    pub total_count: i32,
+    pub success_count: i32,
+    pub exists_count: i32,
+    pub failed_count: i32,
+    pub created_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+impl ImportRecord {
+    /// 無法解析的狀態視為已完成
+    pub fn status(&self) -> ImportStatus {
+        self.status.parse().unwrap_or(ImportStatus::Completed)
+    }
+}
+
+/// 匯入結束時寫回紀錄的狀態與數量
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportOutcome {
+    pub status: ImportStatus,
+    pub success_count: i32,
+    pub exists_count: i32,
+    pub failed_count: i32,
+}
+
+/// 單字卡來源的種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardSourceKind {
+    /// 批次匯入，`import_id` 為匯入紀錄
+    Import,
+    /// 手動查詢後保存
+    Lookup,
+}
+
+impl CardSourceKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CardSourceKind::Import => "import",
+            CardSourceKind::Lookup => "lookup",
+        }
+    }
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = card_sources)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewCardSource {
+    pub card_id: i32,
+    pub kind: String,
+    pub import_id: Option<i32>,
+    pub created_card: bool,
+    pub occurrence_count: i32,
+}
+
+impl NewCardSource {
+    /// 匯入建立或比對到的單字卡
+    pub fn import(card_id: i32, import_id: i32, created_card: bool, occurrence_count: i32) -> Self {
+        Self {
+            card_id,
+            kind: CardSourceKind::Import.as_str().to_string(),
+            import_id: Some(import_id),
+            created_card,
+            occurrence_count,
+        }
+    }
+
+    /// 手動查詢後保存的單字卡
+    pub fn lookup(card_id: i32, created_card: bool) -> Self {
+        Self {
+            card_id,
+            kind: CardSourceKind::Lookup.as_str().to_string(),
+            import_id: None,
+            created_card,
+            occurrence_count: 0,
+        }
+    }
+}
+
+/// 單字卡的來源；同一張單字卡與同一個來源只有一筆，重複記錄時累加出現次數
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[diesel(table_name = card_sources)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct CardSource {
+    pub id: Option<i32>,
+    pub card_id: i32,
+    /// `CardSourceKind` 的字串形式
+    pub kind: String,
+    pub import_id: Option<i32>,
+    /// 單字卡是否由此來源建立
+    pub created_card: bool,
+    pub occurrence_count: i32,
+    pub created_at: Option<String>,
+}
+
+/// 復原匯入的結果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportUndoSummary {
+    /// 由此匯入建立、且沒有其他來源而刪除的單字卡數量
+    pub deleted_count: usize,
+    /// 保留下來、扣回出現次數的單字卡數量
+    pub updated_count: usize,
+}
+
+/// 匯入紀錄中的單字卡與其來源
+pub type ImportedCard = (CardSource, WordCard);
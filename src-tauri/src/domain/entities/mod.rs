@@ -1 +1,6 @@
+pub mod imports;
+pub mod profiles;
+pub mod review_logs;
+pub mod senses;
+pub mod tags;
 pub mod word_cards;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use crate::infrastructure::persistence::schema::profiles;
+use diesel::prelude::*;
+use diesel::sqlite::Sqlite;
+use crate::domain::value_objects::review::SchedulerKind;
+
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = profiles)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct Profile {
+    pub id: Option<i32>,
+    pub name: String,
+    pub scheduler: String,
+    pub is_active: i32,
+    pub created_at: Option<String>,
+}
+
+impl Profile {
+    /// 取得此設定檔使用的排程演算法；無法辨識時退回 SM-2
+    pub fn scheduler_kind(&self) -> SchedulerKind {
+        self.scheduler.parse().unwrap_or(SchedulerKind::Sm2)
+    }
+}
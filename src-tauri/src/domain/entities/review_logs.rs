@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use crate::infrastructure::persistence::schema::review_logs;
+use diesel::prelude::*;
+use diesel::sqlite::Sqlite;
+use crate::domain::value_objects::review::ReviewState;
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = review_logs)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewReviewLog {
+    pub card_id: i32,
+    pub reviewed_at: String,
+    pub grade: i32,
+    pub scheduler: String,
+    pub elapsed_days: i32,
+    pub duration_ms: Option<i32>,
+    pub previous_state: String,
+    pub new_state: String,
+}
+
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+#[diesel(table_name = review_logs)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct ReviewLog {
+    pub id: Option<i32>,
+    pub card_id: i32,
+    pub reviewed_at: String,
+    pub grade: i32,
+    pub scheduler: String,
+    pub elapsed_days: i32,
+    pub duration_ms: Option<i32>,
+    pub previous_state: String,
+    pub new_state: String,
+}
+
+impl ReviewLog {
+    /// 作答前的排程狀態
+    pub fn previous_review_state(&self) -> Option<ReviewState> {
+        serde_json::from_str(&self.previous_state).ok()
+    }
+
+    /// 作答後的排程狀態
+    pub fn new_review_state(&self) -> Option<ReviewState> {
+        serde_json::from_str(&self.new_state).ok()
+    }
+}
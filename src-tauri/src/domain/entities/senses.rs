@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+
+use super::word_cards::MergePolicy;
+
+/// 舊格式定義文字中各行的前綴
+const EXAMPLE_PREFIX: &str = "例句: ";
+const SYNONYMS_PREFIX: &str = "同義詞: ";
+const ANTONYMS_PREFIX: &str = "反義詞: ";
+
+/// 單字的一個詞義，連同其例句、同義詞與反義詞
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sense {
+    pub part_of_speech: String,
+    pub definition: String,
+    pub examples: Vec<String>,
+    pub synonyms: Vec<String>,
+    pub antonyms: Vec<String>,
+}
+
+impl Sense {
+    /// 去除頭尾空白並移除空白的例句與同義詞；定義為空時回傳 `None`
+    fn cleaned(self) -> Option<Self> {
+        fn clean_list(items: Vec<String>) -> Vec<String> {
+            items
+                .into_iter()
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        }
+
+        let definition = self.definition.trim().to_string();
+        if definition.is_empty() {
+            return None;
+        }
+        Some(Self {
+            part_of_speech: self.part_of_speech.trim().to_string(),
+            definition,
+            examples: clean_list(self.examples),
+            synonyms: clean_list(self.synonyms),
+            antonyms: clean_list(self.antonyms),
+        })
+    }
+}
+
+/// 整理詞義列表，移除定義為空的詞義
+pub fn clean_senses(senses: Vec<Sense>) -> Vec<Sense> {
+    senses.into_iter().filter_map(Sense::cleaned).collect()
+}
+
+/// 由詞義產生 `word_cards.definition` 的檢索用文字
+///
+/// 格式與舊版前端組出的文字相同，讓全文檢索與摘要維持一致；此文字只供檢索與顯示摘要，不再被解析。
+pub fn render_definition(senses: &[Sense]) -> String {
+    senses
+        .iter()
+        .map(|sense| {
+            let mut block = if sense.part_of_speech.is_empty() {
+                sense.definition.clone()
+            } else {
+                format!("[{}] {}", sense.part_of_speech, sense.definition)
+            };
+            for example in &sense.examples {
+                block.push('\n');
+                block.push_str(EXAMPLE_PREFIX);
+                block.push_str(example);
+            }
+            if !sense.synonyms.is_empty() {
+                block.push('\n');
+                block.push_str(SYNONYMS_PREFIX);
+                block.push_str(&sense.synonyms.join(", "));
+            }
+            if !sense.antonyms.is_empty() {
+                block.push('\n');
+                block.push_str(ANTONYMS_PREFIX);
+                block.push_str(&sense.antonyms.join(", "));
+            }
+            block
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// 解析舊版以文字儲存的定義
+///
+/// 舊格式為 `[詞性] 定義`，其後可接 `例句: `、`同義詞: `、`反義詞: ` 開頭的行，詞義之間以空行分隔。
+/// 不以 `[詞性]` 開頭的段落視為前一個詞義定義的延續（定義本身含有空行），
+/// 其他無法辨識的行也併入定義。
+pub fn parse_legacy_definition(text: &str) -> Vec<Sense> {
+    fn split_list(value: &str) -> Vec<String> {
+        value.split(", ").map(str::to_string).collect()
+    }
+
+    let mut senses: Vec<Sense> = Vec::new();
+
+    for block in text.split("\n\n").map(str::trim).filter(|b| !b.is_empty()) {
+        let mut lines = block.lines();
+        let first_line = lines.next().unwrap_or_default();
+
+        let tagged = first_line
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .filter(|(pos, _)| !pos.is_empty());
+
+        match (tagged, senses.last_mut()) {
+            (Some((pos, definition)), _) => senses.push(Sense {
+                part_of_speech: pos.trim().to_string(),
+                definition: definition.trim().to_string(),
+                ..Sense::default()
+            }),
+            (None, Some(previous)) => {
+                previous.definition.push_str("\n\n");
+                previous.definition.push_str(first_line);
+            }
+            (None, None) => senses.push(Sense {
+                definition: first_line.to_string(),
+                ..Sense::default()
+            }),
+        }
+        let Some(sense) = senses.last_mut() else { continue };
+
+        for line in lines {
+            if let Some(example) = line.strip_prefix(EXAMPLE_PREFIX) {
+                sense.examples.push(example.to_string());
+            } else if let Some(synonyms) = line.strip_prefix(SYNONYMS_PREFIX) {
+                sense.synonyms.extend(split_list(synonyms));
+            } else if let Some(antonyms) = line.strip_prefix(ANTONYMS_PREFIX) {
+                sense.antonyms.extend(split_list(antonyms));
+            } else {
+                sense.definition.push('\n');
+                sense.definition.push_str(line);
+            }
+        }
+    }
+
+    clean_senses(senses)
+}
+
+/// 依合併策略合併已存在與新傳入的詞義
+///
+/// 規則與 `WordCard::apply_incoming` 對定義的處理相同：`Overwrite` 在有新詞義時整批取代，
+/// `MergeDefinitions` 在原有詞義之後附加尚未出現過的詞義。
+pub fn merge_senses(existing: &[Sense], incoming: &[Sense], policy: MergePolicy) -> Vec<Sense> {
+    match policy {
+        MergePolicy::KeepExisting => existing.to_vec(),
+        MergePolicy::Overwrite if incoming.is_empty() => existing.to_vec(),
+        MergePolicy::Overwrite => incoming.to_vec(),
+        MergePolicy::MergeDefinitions => {
+            let mut merged = existing.to_vec();
+            for sense in incoming {
+                if !merged.contains(sense) {
+                    merged.push(sense.clone());
+                }
+            }
+            merged
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_definition_round_trips() {
+        let text = "[noun] a round fruit\n例句: An apple a day.\n同義詞: pome, fruit\n\n[verb] to apple\n反義詞: pear";
+        let senses = parse_legacy_definition(text);
+
+        assert_eq!(senses.len(), 2);
+        assert_eq!(senses[0].part_of_speech, "noun");
+        assert_eq!(senses[0].examples, ["An apple a day."]);
+        assert_eq!(senses[0].synonyms, ["pome", "fruit"]);
+        assert_eq!(senses[1].antonyms, ["pear"]);
+        assert_eq!(render_definition(&senses), text);
+    }
+
+    #[test]
+    fn test_legacy_definition_with_brackets_and_blank_lines() {
+        let senses = parse_legacy_definition(
+            "[noun] an array [a-z] of letters\n\nsecond paragraph\n例句: see [1]\n\nplain text",
+        );
+
+        assert_eq!(senses.len(), 1);
+        assert_eq!(
+            senses[0].definition,
+            "an array [a-z] of letters\n\nsecond paragraph\n\nplain text"
+        );
+        assert_eq!(senses[0].examples, ["see [1]"]);
+
+        let untagged = parse_legacy_definition("just a definition");
+        assert_eq!(untagged[0].part_of_speech, "");
+        assert_eq!(untagged[0].definition, "just a definition");
+        assert!(parse_legacy_definition("  \n\n ").is_empty());
+    }
+
+    #[test]
+    fn test_merge_senses_follows_policy() {
+        let fruit = Sense {
+            part_of_speech: "noun".to_string(),
+            definition: "a round fruit".to_string(),
+            ..Sense::default()
+        };
+        let company = Sense {
+            part_of_speech: "noun".to_string(),
+            definition: "a tech company".to_string(),
+            ..Sense::default()
+        };
+        let existing = vec![fruit.clone()];
+        let incoming = vec![fruit.clone(), company.clone()];
+
+        assert_eq!(merge_senses(&existing, &incoming, MergePolicy::KeepExisting), existing);
+        assert_eq!(merge_senses(&existing, &[], MergePolicy::Overwrite), existing);
+        let replacement = std::slice::from_ref(&company);
+        assert_eq!(merge_senses(&existing, replacement, MergePolicy::Overwrite), replacement);
+        assert_eq!(merge_senses(&existing, &incoming, MergePolicy::MergeDefinitions), [fruit, company]);
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use crate::infrastructure::persistence::schema::tags;
+use diesel::prelude::*;
+use diesel::sqlite::Sqlite;
+use crate::domain::value_objects::tag::TagName;
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewTag {
+    pub name: String,
+    pub name_key: String,
+}
+
+impl From<&TagName> for NewTag {
+    fn from(name: &TagName) -> Self {
+        Self {
+            name: name.as_str().to_string(),
+            name_key: name.key().to_string(),
+        }
+    }
+}
+
+#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct Tag {
+    pub id: Option<i32>,
+    pub name: String,
+    /// 名稱正規化後的 key，不分大小寫唯一
+    pub name_key: String,
+    pub created_at: Option<String>,
+}
+
+/// 標籤連同使用中的單字卡數量，供側邊欄顯示
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TagCount {
+    #[serde(flatten)]
+    pub tag: Tag,
+    pub card_count: i64,
+}
@@ -2,31 +2,455 @@ use serde::{Deserialize, Serialize};
 use crate::infrastructure::persistence::schema::word_cards;
 use diesel::prelude::*; // ✅ 匯入 Queryable 等 Diesel 的 derive macro
 use diesel::sqlite::Sqlite;
+use crate::domain::value_objects::review::{parse_timestamp, ReviewState};
+use crate::domain::services::inflection::inflect_for;
+use crate::domain::value_objects::pronunciation::{validate_pronunciations, Pronunciation};
+use crate::domain::value_objects::word::Word;
+use crate::domain::value_objects::word_forms::WordForms;
+use super::tags::Tag;
+use super::senses::{clean_senses, merge_senses, parse_legacy_definition, render_definition, Sense};
 
-#[derive(Insertable, Serialize, Deserialize)]
+#[derive(Insertable, Serialize, Deserialize, Debug, Clone)]
 #[diesel(table_name = word_cards)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct NewWordCard {
     pub word: String,
     pub pos: Option<String>,
     pub definition: Option<String>,
-    pub pronunciation: Option<String>,
-    pub verbs: Option<String>,
+    /// 由單字與詞義的詞性產生，不接受前端傳入
+    #[serde(skip_deserializing)]
+    pub verbs: Option<WordForms>,
     pub familiarity: Option<i32>,
     pub seen_count: Option<i32>,
+    /// 由 `word` 正規化而來，不接受前端傳入
+    #[serde(skip)]
+    pub word_key: Option<String>,
+    /// 結構化的詞義；未提供時由 `definition` 的舊格式文字解析
+    #[diesel(skip_insertion)]
+    #[serde(default)]
+    pub senses: Vec<Sense>,
+    /// 發音，保存前逐一驗證
+    #[diesel(skip_insertion)]
+    #[serde(default)]
+    pub pronunciations: Vec<Pronunciation>,
 }
 
-#[derive(Queryable, Selectable, Serialize, Deserialize, Debug, Clone)]
+/// 保存已存在的單字時的合併策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergePolicy {
+    /// 保留原有內容，只增加查看次數
+    #[default]
+    KeepExisting,
+    /// 以新傳入的內容覆蓋原有內容（未提供的欄位保留原值）
+    Overwrite,
+    /// 合併定義：原有定義之後附加新的、尚未出現過的詞義段落，缺少的欄位以新內容補齊
+    MergeDefinitions,
+}
+
+/// 保存單字卡的結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SaveOutcome {
+    /// 新增了一張單字卡
+    Inserted,
+    /// 單字已存在，依 `KeepExisting` 或 `Overwrite` 更新
+    Updated,
+    /// 單字已存在，依 `MergeDefinitions` 合併內容
+    Merged,
+}
+
+impl NewWordCard {
+    /// 正規化單字並填入 `word_key`
+    ///
+    /// 同時整理詞義：只傳入舊格式的 `definition` 時解析為詞義，
+    /// 有詞義時 `definition` 一律改為由詞義產生的檢索用文字；發音不正確時回傳錯誤。
+    /// 詞形變化由單字與詞義的詞性產生。
+    pub fn normalized(mut self) -> Result<Self, String> {
+        let normalized = Word::parse(&self.word)?;
+        self.word = normalized.as_str().to_string();
+        self.word_key = Some(normalized.key().to_string());
+
+        self.senses = clean_senses(std::mem::take(&mut self.senses));
+        if self.senses.is_empty() {
+            self.senses = parse_legacy_definition(self.definition.as_deref().unwrap_or_default());
+        }
+        if !self.senses.is_empty() {
+            self.definition = Some(render_definition(&self.senses));
+        }
+        self.pronunciations = validate_pronunciations(std::mem::take(&mut self.pronunciations))?;
+        self.verbs = word_forms(&self.word, &self.senses);
+        Ok(self)
+    }
+
+    /// 併入同一個單字的另一筆資料（如試算表中的另一列）：加入不重複的詞義與發音，缺少的詞性以新資料補齊
+    ///
+    /// 之後需要再呼叫 `normalized` 重新產生檢索用文字與詞形變化。
+    pub fn absorb(&mut self, other: NewWordCard) {
+        for sense in other.senses {
+            if !self.senses.contains(&sense) {
+                self.senses.push(sense);
+            }
+        }
+        for pronunciation in other.pronunciations {
+            if !self.pronunciations.contains(&pronunciation) {
+                self.pronunciations.push(pronunciation);
+            }
+        }
+        self.pos = self.pos.take().or(other.pos);
+    }
+}
+
+#[derive(Queryable, Selectable, AsChangeset, Serialize, Deserialize, Debug, Clone)]
 #[diesel(table_name = word_cards)]
 #[diesel(check_for_backend(Sqlite))]
+#[diesel(treat_none_as_null = true)]
 pub struct WordCard {
     pub id: Option<i32>, // ✅ Nullable<Integer>
     pub word: String,
     pub pos: Option<String>,
     pub definition: Option<String>,
-    pub pronunciation: Option<String>,
-    pub verbs: Option<String>,
+    pub verbs: Option<WordForms>,
     pub familiarity: Option<i32>,
     pub seen_count: Option<i32>,
     pub created_at: Option<String>, // ✅ Nullable<Text>
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub due_at: Option<String>,
+    pub stability: Option<f64>,
+    pub difficulty: Option<f64>,
+    pub last_reviewed_at: Option<String>,
+    pub word_key: Option<String>,
+    /// 所屬牌組，預設為 `DEFAULT_DECK_ID`
+    pub deck_id: i32,
+    pub learning_step: Option<i32>,
+    /// 在匯入的檔案與文字中累計出現的次數
+    pub occurrence_count: i32,
+}
+
+impl WordCard {
+    /// 取得目前的排程狀態
+    pub fn review_state(&self) -> ReviewState {
+        ReviewState {
+            ease_factor: self.ease_factor,
+            interval_days: self.interval_days,
+            repetitions: self.repetitions,
+            due_at: self.due_at.as_deref().and_then(parse_timestamp),
+            stability: self.stability,
+            difficulty: self.difficulty,
+            last_reviewed_at: self.last_reviewed_at.as_deref().and_then(parse_timestamp),
+            familiarity: self.familiarity.unwrap_or(0),
+            learning_step: self.learning_step,
+        }
+    }
+
+    /// 依合併策略將新傳入的內容套用到已存在的單字卡上
+    ///
+    /// 熟悉度與排程狀態屬於複習進度，不受任何策略影響；查看次數由呼叫端累加。
+    pub fn apply_incoming(&mut self, incoming: &NewWordCard, policy: MergePolicy) -> SaveOutcome {
+        fn provided(value: &Option<String>) -> Option<&String> {
+            value.as_ref().filter(|v| !v.trim().is_empty())
+        }
+
+        match policy {
+            MergePolicy::KeepExisting => SaveOutcome::Updated,
+            MergePolicy::Overwrite => {
+                self.word.clone_from(&incoming.word);
+                for (target, source) in [
+                    (&mut self.pos, &incoming.pos),
+                    (&mut self.definition, &incoming.definition),
+                ] {
+                    if let Some(value) = provided(source) {
+                        *target = Some(value.clone());
+                    }
+                }
+                SaveOutcome::Updated
+            }
+            MergePolicy::MergeDefinitions => {
+                let merged = match (provided(&self.definition), provided(&incoming.definition)) {
+                    (Some(existing), Some(new)) => Some(merge_definitions(existing, new)),
+                    (None, Some(new)) => Some(new.clone()),
+                    _ => None,
+                };
+                if merged.is_some() {
+                    self.definition = merged;
+                }
+                if provided(&self.pos).is_none() {
+                    self.pos.clone_from(&incoming.pos);
+                }
+                SaveOutcome::Merged
+            }
+        }
+    }
+
+    /// 將另一張重複的單字卡併入此卡
+    ///
+    /// 缺少的內容以對方補齊、查看次數相加、熟悉度取較高者、建立時間取較早者；
+    /// 若對方的複習紀錄較新，則採用對方的排程狀態。
+    pub fn absorb(&mut self, other: &WordCard) {
+        fn fill(target: &mut Option<String>, source: &Option<String>) {
+            let missing = match target.as_deref() {
+                Some(t) => t.trim().is_empty(),
+                None => true,
+            };
+            if missing {
+                target.clone_from(source);
+            }
+        }
+
+        fill(&mut self.pos, &other.pos);
+        fill(&mut self.definition, &other.definition);
+        if self.verbs.is_none() {
+            self.verbs.clone_from(&other.verbs);
+        }
+
+        self.seen_count = Some(self.seen_count.unwrap_or(0) + other.seen_count.unwrap_or(0));
+        self.familiarity = self.familiarity.max(other.familiarity);
+        let earlier = match (&self.created_at, &other.created_at) {
+            (Some(mine), Some(theirs)) => theirs < mine,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if earlier {
+            self.created_at.clone_from(&other.created_at);
+        }
+
+        if other.last_reviewed_at > self.last_reviewed_at {
+            self.ease_factor = other.ease_factor;
+            self.interval_days = other.interval_days;
+            self.repetitions = other.repetitions;
+            self.due_at.clone_from(&other.due_at);
+            self.stability = other.stability;
+            self.difficulty = other.difficulty;
+            self.learning_step = other.learning_step;
+            self.last_reviewed_at.clone_from(&other.last_reviewed_at);
+        }
+    }
+}
+
+/// 單字卡連同其結構化詞義、發音與標籤，為指令回傳給前端的完整內容
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WordCardEntry {
+    #[serde(flatten)]
+    pub card: WordCard,
+    pub senses: Vec<Sense>,
+    #[serde(default)]
+    pub pronunciations: Vec<Pronunciation>,
+    /// 標籤不受保存時的合併策略影響
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+impl WordCardEntry {
+    /// 依合併策略將新傳入的內容、詞義與發音套用到已存在的單字卡上
+    ///
+    /// 詞義的合併規則與定義相同；有詞義時 `definition` 改為由合併後的詞義產生。
+    /// 發音比照其他欄位：`Overwrite` 在有新發音時整批取代，`MergeDefinitions` 只補齊缺少的發音。
+    /// 詞形變化依合併後的單字與詞義重新產生。
+    pub fn apply_incoming(&mut self, incoming: &NewWordCard, policy: MergePolicy) -> SaveOutcome {
+        let outcome = self.card.apply_incoming(incoming, policy);
+        self.senses = merge_senses(&self.senses, &incoming.senses, policy);
+        if !self.senses.is_empty() {
+            self.card.definition = Some(render_definition(&self.senses));
+        }
+        let replace_pronunciations = match policy {
+            MergePolicy::KeepExisting => false,
+            MergePolicy::Overwrite => !incoming.pronunciations.is_empty(),
+            MergePolicy::MergeDefinitions => self.pronunciations.is_empty(),
+        };
+        if replace_pronunciations {
+            self.pronunciations.clone_from(&incoming.pronunciations);
+        }
+        self.card.verbs = word_forms(&self.card.word, &self.senses);
+        outcome
+    }
+}
+
+/// 依單字與詞義的詞性產生詞形變化；沒有任何變化時回傳 `None`
+pub fn word_forms(word: &str, senses: &[Sense]) -> Option<WordForms> {
+    let parts_of_speech = senses.iter().map(|s| s.part_of_speech.as_str()).collect::<Vec<_>>();
+    Some(inflect_for(word, &parts_of_speech)).filter(|forms| !forms.is_empty())
+}
+
+/// 在原有定義之後附加新定義中尚未出現過的段落
+///
+/// 定義以空行分隔成段落（一個詞義連同其例句、同義詞為一段），比較時忽略頭尾空白。
+fn merge_definitions(existing: &str, incoming: &str) -> String {
+    fn blocks(text: &str) -> impl Iterator<Item = &str> {
+        text.split("\n\n").map(str::trim).filter(|b| !b.is_empty())
+    }
+
+    let mut merged = blocks(existing).collect::<Vec<_>>();
+    for block in blocks(incoming) {
+        if !merged.contains(&block) {
+            merged.push(block);
+        }
+    }
+    merged.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing() -> WordCard {
+        WordCard {
+            id: Some(1),
+            word: "apple".to_string(),
+            pos: Some("[\"noun\"]".to_string()),
+            definition: Some("[noun] a round fruit".to_string()),
+            verbs: None,
+            familiarity: Some(2),
+            seen_count: Some(3),
+            created_at: None,
+            ease_factor: 2.5,
+            interval_days: 6,
+            repetitions: 2,
+            due_at: None,
+            stability: None,
+            difficulty: None,
+            last_reviewed_at: None,
+            word_key: Some("apple".to_string()),
+            deck_id: 1,
+            learning_step: None,
+            occurrence_count: 0,
+        }
+    }
+
+    fn incoming(definition: &str) -> NewWordCard {
+        NewWordCard {
+            word: "Apple".to_string(),
+            pos: None,
+            definition: Some(definition.to_string()),
+            verbs: None,
+            familiarity: Some(0),
+            seen_count: Some(1),
+            word_key: Some("apple".to_string()),
+            senses: Vec::new(),
+            pronunciations: vec![Pronunciation {
+                ipa: Some("/ˈæp.əl/".to_string()),
+                ..Pronunciation::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_keep_existing_leaves_content() {
+        let mut card = existing();
+        let outcome = card.apply_incoming(&incoming("[noun] a tech company"), MergePolicy::KeepExisting);
+
+        assert_eq!(outcome, SaveOutcome::Updated);
+        assert_eq!(card.definition.as_deref(), Some("[noun] a round fruit"));
+    }
+
+    #[test]
+    fn test_overwrite_replaces_provided_fields() {
+        let mut card = existing();
+        let outcome = card.apply_incoming(&incoming("[noun] a tech company"), MergePolicy::Overwrite);
+
+        assert_eq!(outcome, SaveOutcome::Updated);
+        assert_eq!(card.word, "Apple");
+        assert_eq!(card.definition.as_deref(), Some("[noun] a tech company"));
+        // 未提供的欄位與複習進度保留原值
+        assert_eq!(card.pos.as_deref(), Some("[\"noun\"]"));
+        assert_eq!(card.familiarity, Some(2));
+        assert_eq!(card.interval_days, 6);
+    }
+
+    #[test]
+    fn test_merge_appends_new_definition_blocks() {
+        let mut card = existing();
+        let outcome = card.apply_incoming(
+            &incoming("[noun] a round fruit\n\n[noun] a tech company\n例句: Apple makes phones."),
+            MergePolicy::MergeDefinitions,
+        );
+
+        assert_eq!(outcome, SaveOutcome::Merged);
+        assert_eq!(
+            card.definition.as_deref(),
+            Some("[noun] a round fruit\n\n[noun] a tech company\n例句: Apple makes phones.")
+        );
+        assert_eq!(card.word, "apple");
+    }
+
+    #[test]
+    fn test_normalized_parses_legacy_definition_into_senses() {
+        let mut legacy = incoming("[noun] a round fruit\n例句: An apple a day.");
+        legacy.word_key = None;
+        let card = legacy.normalized().unwrap();
+
+        assert_eq!(card.senses.len(), 1);
+        assert_eq!(card.senses[0].part_of_speech, "noun");
+        assert_eq!(card.senses[0].examples, ["An apple a day."]);
+        // 只有名詞詞義時只產生複數
+        let forms = card.verbs.unwrap();
+        assert_eq!(forms.plural.as_deref(), Some("Apples"));
+        assert_eq!(forms.past, None);
+    }
+
+    #[test]
+    fn test_entry_merge_renders_definition_from_senses() {
+        let mut entry = WordCardEntry {
+            card: existing(),
+            senses: parse_legacy_definition("[noun] a round fruit"),
+            pronunciations: Vec::new(),
+            tags: Vec::new(),
+        };
+        let incoming = incoming("[noun] a tech company\n\ncontinued [1]").normalized().unwrap();
+        let outcome = entry.apply_incoming(&incoming, MergePolicy::MergeDefinitions);
+
+        assert_eq!(outcome, SaveOutcome::Merged);
+        assert_eq!(entry.senses.len(), 2);
+        assert_eq!(entry.senses[1].definition, "a tech company\n\ncontinued [1]");
+        assert_eq!(entry.card.definition.as_deref(), Some(render_definition(&entry.senses).as_str()));
+    }
+
+    #[test]
+    fn test_entry_merge_follows_policy_for_pronunciations() {
+        let incoming = incoming("[noun] a tech company").normalized().unwrap();
+        assert_eq!(incoming.pronunciations[0].ipa.as_deref(), Some("ˈæp.əl"));
+
+        let mut kept = WordCardEntry {
+            card: existing(),
+            senses: Vec::new(),
+            pronunciations: Vec::new(),
+            tags: Vec::new(),
+        };
+        kept.apply_incoming(&incoming, MergePolicy::KeepExisting);
+        assert!(kept.pronunciations.is_empty());
+
+        let mut merged = kept.clone();
+        merged.apply_incoming(&incoming, MergePolicy::MergeDefinitions);
+        assert_eq!(merged.pronunciations, incoming.pronunciations);
+
+        let mut invalid = incoming.clone();
+        invalid.pronunciations = vec![Pronunciation::default()];
+        assert!(invalid.normalized().is_err());
+    }
+
+    #[test]
+    fn test_absorb_takes_newer_review_state_including_learning_step() {
+        let mut card = existing();
+        card.learning_step = Some(0);
+        card.last_reviewed_at = Some("2025-01-01 08:00:00".to_string());
+        let mut duplicate = existing();
+        duplicate.id = Some(2);
+        duplicate.interval_days = 0;
+        duplicate.due_at = Some("2025-01-02 09:10:00".to_string());
+        duplicate.learning_step = Some(1);
+        duplicate.last_reviewed_at = Some("2025-01-02 09:00:00".to_string());
+
+        // 較舊的紀錄不會覆蓋目前的排程狀態
+        let mut older = card.clone();
+        older.absorb(&existing());
+        assert_eq!(older.learning_step, Some(0));
+
+        card.absorb(&duplicate);
+        assert_eq!(card.learning_step, Some(1));
+        assert_eq!(card.interval_days, 0);
+        assert_eq!(card.due_at, duplicate.due_at);
+        assert_eq!(card.last_reviewed_at, duplicate.last_reviewed_at);
+        assert_eq!(card.seen_count, Some(6));
+    }
 }
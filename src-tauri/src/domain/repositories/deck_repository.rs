@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use crate::domain::aggregates::deck::{Deck, DeckCounts};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+
+/// 牌組的儲存介面
+///
+/// 只負責保存；跨牌組的規則（上層牌組、循環、同層名稱）由呼叫端以 `DeckTree` 檢查。
+pub trait DeckRepository: Send + Sync {
+    /// 所有牌組，依 ID 排序
+    fn list_decks(&self) -> RepositoryResult<Vec<Deck>>;
+
+    fn find_deck(&self, deck_id: i32) -> RepositoryResult<Option<Deck>>;
+
+    /// 新增（`id` 為 `None`）或更新牌組，回傳保存後的牌組
+    fn save_deck(&self, deck: &Deck) -> RepositoryResult<Deck>;
+
+    /// 刪除牌組：子牌組改掛到其上層，單字卡移到上層牌組（最上層的牌組則移到預設牌組）。
+    /// 預設牌組不能刪除。回傳是否有牌組被刪除
+    fn delete_deck(&self, deck_id: i32) -> RepositoryResult<bool>;
+
+    /// 將單字卡移到指定牌組；單字卡或牌組不存在時回傳 `NotFound`。回傳實際改變牌組的數量
+    fn move_cards(&self, card_ids: &[i32], deck_id: i32) -> RepositoryResult<usize>;
+
+    /// 每個牌組本身（不含子牌組）的卡片數量；`due_before` 之前到期的複習卡片算作到期
+    fn count_cards_by_deck(&self, due_before: &str) -> RepositoryResult<HashMap<i32, DeckCounts>>;
+}
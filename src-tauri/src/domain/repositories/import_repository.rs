@@ -0,0 +1,35 @@
+use crate::domain::entities::imports::{
+    CardSource, ImportOutcome, ImportRecord, ImportUndoSummary, ImportedCard, NewCardSource, NewImportRecord,
+};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+
+/// 匯入紀錄與單字卡來源的儲存介面
+///
+/// 指定的匯入紀錄或單字卡不存在時回傳 `RepositoryError::NotFound`。
+pub trait ImportRepository: Send + Sync {
+    /// 建立狀態為 `running` 的匯入紀錄
+    fn create_import(&self, record: &NewImportRecord) -> RepositoryResult<ImportRecord>;
+
+    /// 寫入匯入結束時的狀態與數量，並記錄結束時間
+    fn finish_import(&self, import_id: i32, outcome: &ImportOutcome) -> RepositoryResult<ImportRecord>;
+
+    /// 所有匯入紀錄，新的在前
+    fn list_imports(&self) -> RepositoryResult<Vec<ImportRecord>>;
+
+    fn find_import(&self, import_id: i32) -> RepositoryResult<Option<ImportRecord>>;
+
+    /// 記錄單字卡的來源；同一張單字卡已有相同來源時累加出現次數，並保留「由此來源建立」的標記
+    ///
+    /// 來源的出現次數會在同一個原子操作中累加到單字卡上，復原匯入時扣回的次數因此與實際累加的一致。
+    fn add_card_source(&self, source: &NewCardSource) -> RepositoryResult<CardSource>;
+
+    /// 單字卡的所有來源，依建立順序排列
+    fn list_card_sources(&self, card_id: i32) -> RepositoryResult<Vec<CardSource>>;
+
+    /// 匯入紀錄中的單字卡，依單字排序（不分大小寫）
+    fn list_import_cards(&self, import_id: i32) -> RepositoryResult<Vec<ImportedCard>>;
+
+    /// 復原匯入：刪除由此匯入建立、且沒有其他來源的單字卡，其餘單字卡扣回累加的出現次數，
+    /// 移除此匯入的來源紀錄並將狀態改為 `undone`。整個操作為原子操作。
+    fn undo_import(&self, import_id: i32) -> RepositoryResult<ImportUndoSummary>;
+}
@@ -1 +1,6 @@
-// Repository interfaces will be defined here
+pub mod deck_repository;
+pub mod import_repository;
+pub mod profile_repository;
+pub mod review_repository;
+pub mod tag_repository;
+pub mod word_card_repository;
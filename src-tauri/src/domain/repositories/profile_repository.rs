@@ -0,0 +1,12 @@
+use crate::domain::entities::profiles::Profile;
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::SchedulerKind;
+
+/// 設定檔的儲存介面
+pub trait ProfileRepository: Send + Sync {
+    /// 使用中的設定檔；有多個時取 ID 最小者
+    fn find_active_profile(&self) -> RepositoryResult<Option<Profile>>;
+
+    /// 更新設定檔的排程演算法，回傳更新後的設定檔；設定檔不存在時回傳 `None`
+    fn update_scheduler(&self, profile_id: i32, kind: SchedulerKind) -> RepositoryResult<Option<Profile>>;
+}
@@ -0,0 +1,44 @@
+use crate::domain::entities::review_logs::{NewReviewLog, ReviewLog};
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::ReviewState;
+
+/// 作答紀錄的篩選條件，所有欄位皆為選填
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReviewLogFilter {
+    pub card_id: Option<i32>,
+    /// 起始時間（含）
+    pub since: Option<String>,
+    /// 結束時間（不含）
+    pub until: Option<String>,
+}
+
+/// 一段時間內的作答次數
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReviewCounts {
+    /// 新卡片的第一次學習（作答前尚未排程）
+    pub new: i64,
+    /// 其餘的作答
+    pub reviews: i64,
+}
+
+/// 作答與複習佇列的儲存介面
+///
+/// `deck_ids` 為 `None` 時不限牌組；指定時只包含這些牌組本身的卡片，子牌組由呼叫端展開。
+pub trait ReviewRepository: Send + Sync {
+    /// 在同一交易中寫入作答後的排程狀態、查看次數加一並新增作答紀錄。
+    /// 回傳更新後的單字卡；單字卡不存在時回傳 `None`
+    fn record_review(&self, card_id: i32, state: &ReviewState, log: &NewReviewLog) -> RepositoryResult<Option<WordCard>>;
+
+    /// 符合條件的作答紀錄，依作答時間由新到舊排序，最多 `limit` 筆
+    fn list_review_logs(&self, filter: &ReviewLogFilter, limit: i64) -> RepositoryResult<Vec<ReviewLog>>;
+
+    /// `since` 之後（含）的作答次數
+    fn count_reviews_since(&self, since: &str, deck_ids: Option<&[i32]>) -> RepositoryResult<ReviewCounts>;
+
+    /// `due_before` 之前到期的卡片，依到期時間排序（逾期最久者優先），最多 `limit` 張
+    fn list_due_cards(&self, due_before: &str, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>>;
+
+    /// 尚未排程的新卡片，依建立順序排序，最多 `limit` 張
+    fn list_new_cards(&self, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>>;
+}
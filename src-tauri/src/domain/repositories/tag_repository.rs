@@ -0,0 +1,30 @@
+use crate::domain::entities::tags::{NewTag, Tag, TagCount};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+
+/// 標籤與單字卡標籤的儲存介面
+///
+/// 標籤名稱以 `name_key` 判斷是否重複，重複時回傳 `RepositoryError::Conflict`；
+/// 指定的標籤或單字卡不存在時回傳 `RepositoryError::NotFound`。
+pub trait TagRepository: Send + Sync {
+    /// 所有標籤及其單字卡數量，依名稱排序（不分大小寫）
+    fn list_tags(&self) -> RepositoryResult<Vec<TagCount>>;
+
+    fn find_tag(&self, tag_id: i32) -> RepositoryResult<Option<Tag>>;
+
+    fn create_tag(&self, tag: &NewTag) -> RepositoryResult<Tag>;
+
+    /// 更改標籤名稱；新名稱只與自己相同（例如只改大小寫）時不視為衝突
+    fn rename_tag(&self, tag_id: i32, tag: &NewTag) -> RepositoryResult<Tag>;
+
+    /// 將 `source_ids` 的標籤併入 `target_id`：單字卡改掛目標標籤，來源標籤刪除。整個操作為原子操作。
+    fn merge_tags(&self, source_ids: &[i32], target_id: i32) -> RepositoryResult<TagCount>;
+
+    /// 刪除標籤及其所有單字卡標籤；回傳是否有標籤被刪除
+    fn delete_tag(&self, tag_id: i32) -> RepositoryResult<bool>;
+
+    /// 為每張單字卡加上每個標籤；已有的組合略過，回傳新增的數量。整個操作為原子操作。
+    fn assign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize>;
+
+    /// 移除每張單字卡的每個標籤；回傳移除的數量
+    fn unassign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize>;
+}
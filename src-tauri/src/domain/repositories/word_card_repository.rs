@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::entities::review_logs::NewReviewLog;
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::tags::Tag;
+use crate::domain::entities::word_cards::{MergePolicy, NewWordCard, SaveOutcome, WordCard, WordCardEntry};
+use crate::domain::services::lemmatizer::lemma_candidates;
+use crate::domain::value_objects::pronunciation::Pronunciation;
+use crate::domain::value_objects::review::ReviewState;
+use crate::domain::value_objects::tag::TagExpression;
+
+/// 儲存庫操作失敗的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepositoryError {
+    /// 找不到指定的資料
+    NotFound(String),
+    /// 與現有資料衝突（例如違反唯一約束）
+    Conflict(String),
+    /// 底層儲存失敗
+    Storage(String),
+}
+
+impl std::fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            RepositoryError::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            RepositoryError::Storage(msg) => write!(f, "Storage error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+pub type RepositoryResult<T> = Result<T, RepositoryError>;
+
+/// 單字卡篩選條件，所有欄位皆為選填
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WordCardFilter {
+    /// 比對單字或定義（不分大小寫的部分比對）
+    pub text: Option<String>,
+    /// 熟悉度集合，符合其中任一即可
+    pub familiarity: Option<Vec<i32>>,
+    /// 建立時間起點（含），例如 "2025-01-01"
+    pub created_from: Option<String>,
+    /// 建立時間終點（不含）
+    pub created_to: Option<String>,
+    /// 牌組集合，符合其中任一即可（不自動包含子牌組）
+    pub deck_ids: Option<Vec<i32>>,
+    /// 標籤條件，例如「有 A 且沒有 B」
+    pub tags: Option<TagExpression>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordCardSortKey {
+    #[default]
+    Word,
+    Familiarity,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// 全文檢索摘要中標示命中詞的標籤
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// 全文檢索的單筆結果
+#[derive(Debug, Clone)]
+pub struct FullTextMatch {
+    pub card: WordCard,
+    /// 相關程度，數值越小越相關
+    pub score: f64,
+    /// 定義中命中段落的摘要，命中詞以 `HIGHLIGHT_START`、`HIGHLIGHT_END` 標示
+    pub snippet: String,
+}
+
+/// 將檢索輸入拆成詞
+///
+/// 字母與數字以外的字元都是分隔符號，但詞中的 `'` 與 `-` 會保留（如 "don't"、"well-known"）。
+pub fn search_terms(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '-'))
+        .map(|term| term.trim_matches(|c| c == '\'' || c == '-'))
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 單字與單字卡的比對方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordMatch {
+    /// 正規化後完全相同
+    Exact,
+    /// 查詢的是變化形，比對到其詞元（原形）的單字卡
+    Lemma,
+}
+
+/// 單字卡的儲存介面
+///
+/// 指令與應用層只依賴此介面；正式環境使用 SQLite 實作，測試可改用記憶體實作。
+/// 查詢單字時使用的 key 為 `Word` 正規化後的 `word_key`。
+pub trait WordCardRepository: Send + Sync {
+    fn find_by_id(&self, card_id: i32) -> RepositoryResult<Option<WordCard>>;
+
+    fn find_by_key(&self, key: &str) -> RepositoryResult<Option<WordCard>>;
+
+    /// 依 key 查詢單字卡，找不到時改以詞元查詢，例如 "running" -> "run"、"mice" -> "mouse"
+    fn find_by_key_or_lemma(&self, key: &str, headwords: &[&str]) -> RepositoryResult<Option<(WordCard, WordMatch)>> {
+        if let Some(card) = self.find_by_key(key)? {
+            return Ok(Some((card, WordMatch::Exact)));
+        }
+        Ok(self.find_by_lemma(key, headwords)?.map(|card| (card, WordMatch::Lemma)))
+    }
+
+    /// 以詞元查詢單字卡，不比對 key 本身
+    ///
+    /// `headwords` 中的字本身就是詞條，不會還原成詞元；
+    /// 否則字尾規則會把 "seed"、"news" 比對到 "see"、"new" 的單字卡。
+    fn find_by_lemma(&self, key: &str, headwords: &[&str]) -> RepositoryResult<Option<WordCard>> {
+        if headwords.contains(&key) {
+            return Ok(None);
+        }
+        for lemma in lemma_candidates(key) {
+            if let Some(card) = self.find_by_key(&lemma)? {
+                return Ok(Some(card));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 依 ID 取得多張單字卡，回傳順序不保證與輸入相同
+    fn find_by_ids(&self, card_ids: &[i32]) -> RepositoryResult<Vec<WordCard>>;
+
+    /// 依篩選條件分頁查詢；排序相同時以 ID 遞增排列，單字排序不分大小寫
+    fn list(
+        &self,
+        filter: &WordCardFilter,
+        sort_key: WordCardSortKey,
+        sort_direction: SortDirection,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<WordCard>>;
+
+    /// 符合篩選條件的總筆數
+    fn count(&self, filter: &WordCardFilter) -> RepositoryResult<i64>;
+
+    /// 全文檢索單字與定義（含例句）：每個詞皆以前綴比對且必須全部命中，
+    /// 依相關程度排序，單字命中的權重高於定義。`terms` 為空時回傳空結果
+    fn search(&self, terms: &[String], limit: i64) -> RepositoryResult<Vec<FullTextMatch>>;
+
+    /// 所有單字卡的 ID 與單字，依 ID 排序；只取比對拼字所需的欄位
+    fn list_words(&self) -> RepositoryResult<Vec<(i32, String)>>;
+
+    /// 取得多張單字卡的詞義，依順序排列；沒有詞義的卡片不會出現在結果中
+    fn find_senses(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Sense>>>;
+
+    /// 取得多張單字卡的發音，依順序排列；沒有發音的卡片不會出現在結果中
+    fn find_pronunciations(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Pronunciation>>>;
+
+    /// 取得多張單字卡的標籤，依名稱排序；沒有標籤的卡片不會出現在結果中
+    fn find_tags(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Tag>>>;
+
+    /// 為單字卡附上詞義、發音與標籤
+    fn with_details(&self, cards: Vec<WordCard>) -> RepositoryResult<Vec<WordCardEntry>> {
+        let card_ids = cards.iter().filter_map(|c| c.id).collect::<Vec<_>>();
+        let mut senses = self.find_senses(&card_ids)?;
+        let mut pronunciations = self.find_pronunciations(&card_ids)?;
+        let mut tags = self.find_tags(&card_ids)?;
+        Ok(cards
+            .into_iter()
+            .map(|card| {
+                let card_senses = card.id.and_then(|i| senses.remove(&i)).unwrap_or_default();
+                let card_pronunciations = card.id.and_then(|i| pronunciations.remove(&i)).unwrap_or_default();
+                let card_tags = card.id.and_then(|i| tags.remove(&i)).unwrap_or_default();
+                WordCardEntry { card, senses: card_senses, pronunciations: card_pronunciations, tags: card_tags }
+            })
+            .collect())
+    }
+
+    /// 新增單字卡；`word_key` 已存在時依 `policy` 合併內容、詞義與發音並增加查看次數
+    ///
+    /// `card` 必須已經過 `NewWordCard::normalized` 正規化。整個操作為原子操作。
+    fn save(&self, card: &NewWordCard, policy: MergePolicy) -> RepositoryResult<(SaveOutcome, WordCardEntry)>;
+
+    /// 以 `card` 的內容覆寫同 ID 的單字卡
+    fn update(&self, card: &WordCard) -> RepositoryResult<()>;
+
+    /// 設定熟悉度；回傳是否有卡片被更新
+    fn update_familiarity(&self, card_id: i32, level: i32) -> RepositoryResult<bool>;
+
+    /// 查看次數加一；回傳是否有卡片被更新
+    fn increment_seen_count(&self, card_id: i32) -> RepositoryResult<bool>;
+
+    /// 寫入從其他程式（如 Anki）帶入的排程狀態與作答紀錄；回傳是否有卡片被更新
+    ///
+    /// 熟悉度一併改為 `state.familiarity`。整個操作為原子操作。
+    fn restore_review_history(&self, card_id: i32, state: &ReviewState, logs: &[NewReviewLog]) -> RepositoryResult<bool>;
+
+    /// 刪除單字卡及其詞義、發音與標籤；回傳是否有卡片被刪除
+    fn delete(&self, card_id: i32) -> RepositoryResult<bool>;
+}
@@ -0,0 +1,369 @@
+use chrono::{DateTime, Duration, NaiveDateTime};
+use std::collections::HashMap;
+
+use crate::domain::entities::review_logs::NewReviewLog;
+use crate::domain::services::spreadsheet::ColumnField;
+use crate::domain::value_objects::review::{format_timestamp, ReviewGrade, ReviewState, SchedulerKind, DEFAULT_EASE_FACTOR};
+
+/// Anki 卡片種類（`cards.type`）
+pub const CARD_TYPE_NEW: i32 = 0;
+pub const CARD_TYPE_LEARNING: i32 = 1;
+pub const CARD_TYPE_REVIEW: i32 = 2;
+pub const CARD_TYPE_RELEARNING: i32 = 3;
+
+/// 手動調整排程留下的複習紀錄（`revlog.type`），不是作答
+const REVIEW_KIND_MANUAL: i32 = 4;
+
+/// SM-2 難易度係數的下限
+const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// 間隔達到此天數視為已熟記（Anki 的 mature）
+const MATURE_INTERVAL_DAYS: i32 = 21;
+
+/// 學習中卡片的 `due` 是 Unix 時間（秒），複習卡片則是從建立收藏算起的天數；以此區分兩者
+const EPOCH_DUE_THRESHOLD: i64 = 1_000_000_000;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// 筆記類型與其欄位名稱（依欄位順序）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnkiNoteType {
+    pub id: i64,
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnkiNote {
+    pub id: i64,
+    pub note_type_id: i64,
+    /// 各欄位的原始 HTML，順序與筆記類型的欄位相同
+    pub fields: Vec<String>,
+}
+
+/// 一張 Anki 卡片的排程狀態；一則筆記可以有多張卡片（如正反兩面）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnkiCard {
+    pub id: i64,
+    pub note_id: i64,
+    pub ord: i32,
+    pub card_type: i32,
+    pub queue: i32,
+    pub due: i64,
+    /// 天數；負數為學習中卡片的秒數
+    pub interval: i32,
+    /// 難易度係數的千分比，例如 2500
+    pub factor: i32,
+    pub reps: i32,
+    pub lapses: i32,
+}
+
+/// 一筆複習紀錄（`revlog`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnkiReview {
+    /// 複習時間（Unix 毫秒）
+    pub id: i64,
+    pub card_id: i64,
+    /// 1 Again、2 Hard、3 Good、4 Easy；0 為手動調整
+    pub ease: i32,
+    /// 作答後的間隔；負數為秒數
+    pub interval: i32,
+    pub factor: i32,
+    pub duration_ms: i32,
+    pub kind: i32,
+}
+
+/// 從 Anki 套件讀出的收藏內容
+#[derive(Debug, Clone, Default)]
+pub struct AnkiCollection {
+    /// 收藏建立時間（Unix 秒），複習卡片的 `due` 由此起算
+    pub created_at: i64,
+    pub note_types: Vec<AnkiNoteType>,
+    pub notes: Vec<AnkiNote>,
+    pub cards: Vec<AnkiCard>,
+    /// 依時間排序
+    pub reviews: Vec<AnkiReview>,
+    /// 套件檔案的 SHA-256（十六進位小寫）
+    pub content_hash: String,
+}
+
+impl AnkiCollection {
+    /// 每則筆記的卡片，依 `ord` 排序
+    pub fn cards_by_note(&self) -> HashMap<i64, Vec<&AnkiCard>> {
+        let mut cards: HashMap<i64, Vec<&AnkiCard>> = HashMap::new();
+        for card in &self.cards {
+            cards.entry(card.note_id).or_default().push(card);
+        }
+        cards.values_mut().for_each(|cards| cards.sort_by_key(|card| card.ord));
+        cards
+    }
+
+    /// 每張卡片的複習紀錄，依時間排序
+    pub fn reviews_by_card(&self) -> HashMap<i64, Vec<&AnkiReview>> {
+        let mut reviews: HashMap<i64, Vec<&AnkiReview>> = HashMap::new();
+        for review in &self.reviews {
+            reviews.entry(review.card_id).or_default().push(review);
+        }
+        reviews.values_mut().for_each(|reviews| reviews.sort_by_key(|review| review.id));
+        reviews
+    }
+}
+
+/// 讀取 Anki 匯出的套件（.apkg / .colpkg）
+pub trait AnkiPackageReader: Send + Sync {
+    /// 檔案不是 Anki 套件或格式不支援時回傳錯誤訊息
+    fn read(&self, path: &str) -> Result<AnkiCollection, String>;
+}
+
+/// 欄位未指定對應時的預設：可辨識的欄位名稱依試算表標題的規則，`Front` 為單字、`Back` 為定義，其餘略過
+pub fn default_field(name: &str) -> ColumnField {
+    if let Some(field) = ColumnField::from_header(name) {
+        return field;
+    }
+    match name.trim().to_lowercase().as_str() {
+        "front" | "question" | "正面" => ColumnField::Word,
+        "back" | "answer" | "背面" => ColumnField::Definition,
+        _ => ColumnField::Ignore,
+    }
+}
+
+/// 筆記類型各欄位的對應：`field_map` 以欄位名稱（不分大小寫）指定，未指定的欄位使用 `default_field`
+pub fn field_mapping(note_type: &AnkiNoteType, field_map: &HashMap<String, ColumnField>) -> Vec<ColumnField> {
+    let field_map = field_map
+        .iter()
+        .map(|(name, field)| (name.trim().to_lowercase(), *field))
+        .collect::<HashMap<_, _>>();
+    note_type
+        .fields
+        .iter()
+        .map(|name| field_map.get(&name.trim().to_lowercase()).copied().unwrap_or_else(|| default_field(name)))
+        .collect()
+}
+
+/// 將欄位的 HTML 轉為純文字：區塊與換行標籤轉為換行，移除其他標籤與 `[sound:…]`，
+/// 克漏字 `{{c1::答案::提示}}` 只保留答案，並解碼常見的 HTML 實體
+pub fn plain_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut chars = html.chars();
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            text.push(c);
+            continue;
+        }
+        let tag = chars.by_ref().take_while(|c| *c != '>').collect::<String>().to_lowercase();
+        let name = tag.trim_start_matches('/').split([' ', '/']).next().unwrap_or_default();
+        if matches!(name, "br" | "div" | "p" | "li" | "tr") {
+            text.push('\n');
+        }
+    }
+
+    let text = remove_sound_tags(&text);
+    let text = reveal_clozes(&text);
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    text.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>().join("\n")
+}
+
+fn remove_sound_tags(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[sound:") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find(']') {
+            Some(end) => rest = &rest[start + end + 1..],
+            None => rest = "",
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn reveal_clozes(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{c") {
+        result.push_str(&rest[..start]);
+        let inner = &rest[start + 2..];
+        match (inner.find("::"), inner.find("}}")) {
+            (Some(separator), Some(end)) if separator < end => {
+                let answer = &inner[separator + 2..end];
+                result.push_str(answer.split("::").next().unwrap_or_default());
+                rest = &inner[end + 2..];
+            }
+            _ => {
+                result.push_str("{{");
+                rest = inner;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 由 Anki 卡片與其複習紀錄推算排程狀態；新卡片回傳預設狀態
+pub fn review_state(card: &AnkiCard, reviews: &[&AnkiReview], collection_created: i64) -> ReviewState {
+    if card.card_type == CARD_TYPE_NEW {
+        return ReviewState::default();
+    }
+
+    let answers = reviews.iter().filter(|review| is_answer(review)).collect::<Vec<_>>();
+    let last_reviewed_at = answers.last().and_then(|review| timestamp_millis(review.id));
+    let due = if card.due >= EPOCH_DUE_THRESHOLD {
+        card.due
+    } else {
+        collection_created + card.due * SECONDS_PER_DAY
+    };
+    let learning = matches!(card.card_type, CARD_TYPE_LEARNING | CARD_TYPE_RELEARNING);
+    let interval_days = card.interval.max(0);
+    let repetitions = if answers.is_empty() {
+        (card.reps - card.lapses).max(0)
+    } else {
+        answers.iter().rev().take_while(|review| review.ease > 1).count() as i32
+    };
+
+    ReviewState {
+        ease_factor: ease_factor(card.factor).unwrap_or(DEFAULT_EASE_FACTOR),
+        interval_days,
+        repetitions,
+        due_at: DateTime::from_timestamp(due, 0).map(|time| time.naive_utc()).or(last_reviewed_at),
+        stability: None,
+        difficulty: None,
+        last_reviewed_at,
+        familiarity: familiarity(learning, interval_days),
+        learning_step: learning.then_some(0),
+    }
+}
+
+/// 將 Anki 的複習紀錄轉為作答紀錄，前後的排程狀態由每次作答後的間隔與係數推算
+///
+/// 手動調整排程的紀錄與無法辨識的評分會略過。
+pub fn review_logs(card_id: i32, reviews: &[&AnkiReview]) -> Result<Vec<NewReviewLog>, String> {
+    let mut logs = Vec::new();
+    let mut previous = ReviewState::default();
+    for review in reviews.iter().filter(|review| is_answer(review)) {
+        let Some(reviewed_at) = timestamp_millis(review.id) else { continue };
+        let Ok(grade) = ReviewGrade::try_from(review.ease - 1) else { continue };
+
+        let learning = review.interval < 0 || matches!(review.kind, 0 | 2);
+        let interval_days = review.interval.max(0);
+        let due_at = if review.interval < 0 {
+            reviewed_at + Duration::seconds(-(review.interval as i64))
+        } else {
+            reviewed_at + Duration::days(interval_days as i64)
+        };
+        let repetitions = if grade == ReviewGrade::Again { 0 } else { previous.repetitions + 1 };
+        let next = ReviewState {
+            ease_factor: ease_factor(review.factor).unwrap_or(previous.ease_factor),
+            interval_days,
+            repetitions,
+            due_at: Some(due_at),
+            stability: None,
+            difficulty: None,
+            last_reviewed_at: Some(reviewed_at),
+            familiarity: familiarity(learning, interval_days),
+            learning_step: learning.then_some(0),
+        };
+
+        let serialize = |state: &ReviewState| serde_json::to_string(state).map_err(|e| format!("無法序列化排程狀態: {}", e));
+        logs.push(NewReviewLog {
+            card_id,
+            reviewed_at: format_timestamp(&reviewed_at),
+            grade: grade.as_i32(),
+            scheduler: SchedulerKind::Sm2.as_str().to_string(),
+            elapsed_days: previous
+                .last_reviewed_at
+                .map(|last| (reviewed_at - last).num_days().max(0) as i32)
+                .unwrap_or(0),
+            duration_ms: Some(review.duration_ms).filter(|duration| *duration > 0),
+            previous_state: serialize(&previous)?,
+            new_state: serialize(&next)?,
+        });
+        previous = next;
+    }
+    Ok(logs)
+}
+
+fn is_answer(review: &AnkiReview) -> bool {
+    review.kind != REVIEW_KIND_MANUAL && (1..=4).contains(&review.ease)
+}
+
+fn ease_factor(factor: i32) -> Option<f64> {
+    (factor > 0).then(|| (factor as f64 / 1000.0).max(MIN_EASE_FACTOR))
+}
+
+fn timestamp_millis(millis: i64) -> Option<NaiveDateTime> {
+    DateTime::from_timestamp_millis(millis).map(|time| time.naive_utc())
+}
+
+/// 學習中為 1，間隔未達 `MATURE_INTERVAL_DAYS` 為 2，之後為 3
+fn familiarity(learning: bool, interval_days: i32) -> i32 {
+    match (learning, interval_days) {
+        (true, _) => 1,
+        (false, days) if days < MATURE_INTERVAL_DAYS => 2,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(card_type: i32, due: i64, interval: i32) -> AnkiCard {
+        AnkiCard { id: 1, note_id: 1, ord: 0, card_type, queue: card_type, due, interval, factor: 2300, reps: 3, lapses: 1 }
+    }
+
+    fn review(id: i64, ease: i32, interval: i32, kind: i32) -> AnkiReview {
+        AnkiReview { id, card_id: 1, ease, interval, factor: 2500, duration_ms: 4000, kind }
+    }
+
+    #[test]
+    fn test_plain_text_strips_markup() {
+        let html = "<div>to <b>run</b>&nbsp;fast</div><div>[sound:run.mp3]&lt;v.&gt;<br/>{{c1::跑::動詞}} &amp; 經營</div>";
+        assert_eq!(plain_text(html), "to run fast\n<v.>\n跑 & 經營");
+        assert_eq!(default_field("Front"), ColumnField::Word);
+        assert_eq!(default_field("Meaning"), ColumnField::Definition);
+        assert_eq!(default_field("Audio"), ColumnField::Ignore);
+
+        let note_type = AnkiNoteType { id: 1, name: "Vocab".to_string(), fields: vec!["Front".into(), "Notes".into()] };
+        let field_map = HashMap::from([(" notes ".to_string(), ColumnField::Example)]);
+        assert_eq!(field_mapping(&note_type, &field_map), [ColumnField::Word, ColumnField::Example]);
+    }
+
+    #[test]
+    fn test_review_state_and_logs_follow_history() {
+        // 2024-01-01 00:00:00 UTC 起，三次作答：Good（學習）、Good、Again
+        let start = 1_704_067_200_000;
+        let day = SECONDS_PER_DAY * 1000;
+        let reviews = [
+            review(start, 3, -600, 0),
+            review(start + day, 3, 3, 1),
+            review(start + 2 * day, 0, 10, REVIEW_KIND_MANUAL),
+            review(start + 4 * day, 1, -600, 1),
+        ];
+        let refs = reviews.iter().collect::<Vec<_>>();
+
+        let logs = review_logs(7, &refs).unwrap();
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs.iter().map(|log| log.grade).collect::<Vec<_>>(), [2, 2, 0]);
+        assert_eq!(logs[1].reviewed_at, "2024-01-02 00:00:00");
+        assert_eq!((logs[1].elapsed_days, logs[2].elapsed_days), (1, 3));
+        let after: ReviewState = serde_json::from_str(&logs[1].new_state).unwrap();
+        assert_eq!((after.interval_days, after.repetitions, after.ease_factor), (3, 2, 2.5));
+
+        let created = start / 1000 - 10 * SECONDS_PER_DAY;
+        let state = review_state(&card(CARD_TYPE_REVIEW, 30, 25), &refs, created);
+        assert_eq!(state.due_at.map(|due| format_timestamp(&due)).as_deref(), Some("2024-01-21 00:00:00"));
+        assert_eq!((state.interval_days, state.repetitions, state.ease_factor, state.familiarity), (25, 0, 2.3, 3));
+        assert_eq!(state.last_reviewed_at.map(|at| format_timestamp(&at)).as_deref(), Some("2024-01-05 00:00:00"));
+
+        let learning = review_state(&card(CARD_TYPE_RELEARNING, start / 1000, -600), &[], created);
+        assert_eq!((learning.learning_step, learning.repetitions, learning.familiarity), (Some(0), 2, 1));
+        assert!(review_state(&card(CARD_TYPE_NEW, 5, 0), &refs, created).is_new());
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::entities::word_cards::NewWordCard;
+
+/// 外部字典服務，以單字查詢詞義與發音
+///
+/// 查詢結果為尚未正規化的 `NewWordCard`，熟悉度與出現次數使用新卡片的預設值，由呼叫端決定如何保存。
+pub trait Dictionary: Send + Sync {
+    /// 字典中沒有此單字時回傳 `Ok(None)`；連線失敗或回應格式不正確時回傳錯誤訊息
+    fn lookup(&self, word: &str) -> Result<Option<NewWordCard>, String>;
+}
@@ -0,0 +1,335 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::domain::services::scheduler::Scheduler;
+use crate::domain::value_objects::review::{ReviewGrade, ReviewState, SchedulerKind};
+
+/// FSRS-5 預設權重
+pub const DEFAULT_WEIGHTS: [f64; 19] = [
+    0.4072, 1.1829, 3.1262, 15.4722, 7.2102, 0.5316, 1.0651, 0.0234, 1.616, 0.1544, 1.0824, 1.9813,
+    0.0953, 0.2975, 2.2042, 0.2407, 2.9466, 0.5034, 0.6567,
+];
+
+/// 遺忘曲線的衰減指數
+const DECAY: f64 = -0.5;
+
+/// 使 R(S, S) = 0.9 的係數：0.9^(1 / DECAY) - 1
+const FACTOR: f64 = 19.0 / 81.0;
+
+/// FSRS 參數
+#[derive(Debug, Clone)]
+pub struct FsrsParameters {
+    pub weights: [f64; 19],
+    /// 期望的回想機率
+    pub request_retention: f64,
+    pub maximum_interval: i32,
+}
+
+impl Default for FsrsParameters {
+    fn default() -> Self {
+        Self {
+            weights: DEFAULT_WEIGHTS,
+            request_retention: 0.9,
+            maximum_interval: 36500,
+        }
+    }
+}
+
+/// FSRS 排程器（stability / difficulty / retrievability 模型）
+///
+/// 間隔以天為單位，當日的學習步驟由 `SteppedScheduler` 處理；同一天內再次作答時
+/// 依 FSRS-5 的短期公式（w[17]、w[18]）更新 stability。
+/// 尚未有記憶狀態但已有熟悉度（0～3）的舊卡片，會以熟悉度對應的評分
+/// （0→Again、1→Hard、2→Good、3→Easy）推估初始 stability 與 difficulty。
+#[derive(Debug, Clone, Default)]
+pub struct FsrsScheduler {
+    parameters: FsrsParameters,
+}
+
+/// 單次複習後的記憶狀態
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Memory {
+    stability: f64,
+    difficulty: f64,
+}
+
+/// 作答前的記憶狀態與距離上次作答的回想機率
+#[derive(Debug, Clone, Copy)]
+struct Review {
+    memory: Memory,
+    retrievability: f64,
+    /// 與上次作答相隔不到一天
+    same_day: bool,
+}
+
+impl FsrsScheduler {
+    pub fn new(parameters: FsrsParameters) -> Self {
+        Self { parameters }
+    }
+
+    /// 經過 `elapsed_days` 天後的回想機率
+    pub fn retrievability(elapsed_days: f64, stability: f64) -> f64 {
+        (1.0 + FACTOR * elapsed_days / stability).powf(DECAY)
+    }
+
+    /// 將評分轉為 FSRS 的 1～4 分
+    fn rating(grade: ReviewGrade) -> f64 {
+        (grade.as_i32() + 1) as f64
+    }
+
+    fn init_stability(&self, grade: ReviewGrade) -> f64 {
+        self.parameters.weights[grade.as_i32() as usize].max(0.1)
+    }
+
+    fn init_difficulty(&self, grade: ReviewGrade) -> f64 {
+        let w = &self.parameters.weights;
+        (w[4] - (w[5] * (Self::rating(grade) - 1.0)).exp() + 1.0).clamp(1.0, 10.0)
+    }
+
+    fn next_difficulty(&self, difficulty: f64, grade: ReviewGrade) -> f64 {
+        let w = &self.parameters.weights;
+        let next = difficulty - w[6] * (Self::rating(grade) - 3.0);
+        let reverted = w[7] * self.init_difficulty(ReviewGrade::Easy) + (1.0 - w[7]) * next;
+        reverted.clamp(1.0, 10.0)
+    }
+
+    fn next_recall_stability(&self, memory: Memory, retrievability: f64, grade: ReviewGrade) -> f64 {
+        let w = &self.parameters.weights;
+        let modifier = match grade {
+            ReviewGrade::Hard => w[15],
+            ReviewGrade::Easy => w[16],
+            _ => 1.0,
+        };
+        memory.stability
+            * (1.0
+                + w[8].exp()
+                    * (11.0 - memory.difficulty)
+                    * memory.stability.powf(-w[9])
+                    * (((1.0 - retrievability) * w[10]).exp() - 1.0)
+                    * modifier)
+    }
+
+    /// 同一天內再次作答時的 stability，不經過遺忘曲線
+    fn short_term_stability(&self, stability: f64, grade: ReviewGrade) -> f64 {
+        let w = &self.parameters.weights;
+        stability * (w[17] * (Self::rating(grade) - 3.0 + w[18])).exp()
+    }
+
+    fn next_forget_stability(&self, memory: Memory, retrievability: f64) -> f64 {
+        let w = &self.parameters.weights;
+        w[11]
+            * memory.difficulty.powf(-w[12])
+            * ((memory.stability + 1.0).powf(w[13]) - 1.0)
+            * ((1.0 - retrievability) * w[14]).exp()
+    }
+
+    fn next_interval(&self, stability: f64) -> f64 {
+        let interval = stability / FACTOR * (self.parameters.request_retention.powf(1.0 / DECAY) - 1.0);
+        interval.round().clamp(1.0, self.parameters.maximum_interval as f64)
+    }
+
+    /// 計算四種評分各自的記憶狀態；同一天內的再次作答改用短期 stability
+    fn next_memories(&self, previous: Option<Review>) -> [Memory; 4] {
+        let grades = [ReviewGrade::Again, ReviewGrade::Hard, ReviewGrade::Good, ReviewGrade::Easy];
+        grades.map(|grade| match previous {
+            None => Memory {
+                stability: self.init_stability(grade),
+                difficulty: self.init_difficulty(grade),
+            },
+            Some(Review { memory, retrievability, same_day }) => Memory {
+                stability: match grade {
+                    _ if same_day => self.short_term_stability(memory.stability, grade),
+                    ReviewGrade::Again => self.next_forget_stability(memory, retrievability),
+                    _ => self.next_recall_stability(memory, retrievability, grade),
+                },
+                difficulty: self.next_difficulty(memory.difficulty, grade),
+            },
+        })
+    }
+
+    /// 依各評分的 stability 計算間隔，並確保 Again < Hard < Good < Easy
+    fn intervals(&self, memories: &[Memory; 4]) -> [i64; 4] {
+        let mut again = self.next_interval(memories[0].stability);
+        let mut hard = self.next_interval(memories[1].stability);
+        let mut good = self.next_interval(memories[2].stability);
+        let mut easy = self.next_interval(memories[3].stability);
+
+        again = again.min(hard);
+        hard = hard.max(again + 1.0);
+        good = good.max(hard + 1.0);
+        easy = easy.max(good + 1.0);
+
+        [again as i64, hard as i64, good as i64, easy as i64]
+    }
+
+    /// 取得複習前的記憶狀態；新卡片回傳 `None`
+    fn previous_memory(&self, state: &ReviewState, now: NaiveDateTime) -> Option<Review> {
+        let memory = match (state.stability, state.difficulty) {
+            (Some(stability), Some(difficulty)) => Memory { stability, difficulty },
+            _ if state.is_new() && state.familiarity <= 0 => return None,
+            _ => {
+                // 舊資料：以熟悉度推估曾經給過的評分
+                let grade = ReviewGrade::try_from(state.familiarity.clamp(0, 3)).unwrap_or(ReviewGrade::Again);
+                Memory {
+                    stability: self.init_stability(grade),
+                    difficulty: self.init_difficulty(grade),
+                }
+            }
+        };
+
+        let elapsed_days = state.last_reviewed_at.map(|last| (now - last).num_days().max(0));
+        let retrievability = Self::retrievability(elapsed_days.unwrap_or(0) as f64, memory.stability);
+        Some(Review { memory, retrievability, same_day: elapsed_days == Some(0) })
+    }
+}
+
+impl Scheduler for FsrsScheduler {
+    fn kind(&self) -> SchedulerKind {
+        SchedulerKind::Fsrs
+    }
+
+    fn schedule(&self, state: &ReviewState, grade: ReviewGrade, now: NaiveDateTime) -> ReviewState {
+        let memories = self.next_memories(self.previous_memory(state, now));
+        let intervals = self.intervals(&memories);
+
+        let index = grade.as_i32() as usize;
+        let memory = memories[index];
+        let interval_days = intervals[index];
+
+        ReviewState {
+            interval_days: interval_days as i32,
+            repetitions: match grade {
+                ReviewGrade::Again => 0,
+                _ => state.repetitions + 1,
+            },
+            due_at: Some(now + Duration::days(interval_days)),
+            stability: Some(memory.stability),
+            difficulty: Some(memory.difficulty),
+            last_reviewed_at: Some(now),
+            familiarity: grade.as_i32(),
+            ..state.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    /// open-spaced-repetition 參考實作 rs-fsrs 1.2.1 測試所用的權重（`tests.rs` 的 `WEIGHTS`）
+    const REFERENCE_WEIGHTS: [f64; 19] = [
+        0.4197, 1.1869, 3.0412, 15.2441, 7.1434, 0.6477, 1.0007, 0.0674, 1.6597, 0.1712, 1.1178,
+        2.0225, 0.0904, 0.3025, 2.1214, 0.2498, 2.9466, 0.4891, 0.6468,
+    ];
+
+    const REFERENCE_GRADES: [ReviewGrade; 13] = [
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Again,
+        ReviewGrade::Again,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+        ReviewGrade::Good,
+    ];
+
+    fn start() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 11, 29).unwrap().and_hms_opt(12, 30, 0).unwrap()
+    }
+
+    fn round4(value: f64) -> f64 {
+        (value * 10_000.0).round() / 10_000.0
+    }
+
+    #[test]
+    fn test_reference_long_term_intervals() {
+        let scheduler = FsrsScheduler::new(FsrsParameters {
+            weights: REFERENCE_WEIGHTS,
+            ..FsrsParameters::default()
+        });
+
+        let mut state = ReviewState::default();
+        let mut now = start();
+        let mut intervals = vec![];
+        let mut stabilities = vec![];
+        let mut difficulties = vec![];
+
+        for grade in REFERENCE_GRADES {
+            state = scheduler.schedule(&state, grade, now);
+            intervals.push(state.interval_days);
+            stabilities.push(round4(state.stability.unwrap()));
+            difficulties.push(round4(state.difficulty.unwrap()));
+            now = state.due_at.unwrap();
+        }
+
+        // rs-fsrs 1.2.1 `test_long_term_scheduler` 的預期值
+        assert_eq!(intervals, [3, 13, 48, 155, 445, 1158, 17, 3, 9, 27, 74, 190, 457]);
+        assert_eq!(
+            stabilities,
+            [
+                3.0412, 13.0913, 48.1585, 154.9373, 445.0556, 1158.0778, 16.6306, 2.9888, 9.4633,
+                26.9474, 73.9723, 189.7037, 457.4379,
+            ]
+        );
+        assert_eq!(
+            difficulties,
+            [
+                4.4909, 4.2666, 4.0575, 3.8624, 3.6804, 3.5108, 5.219, 6.8122, 6.4314, 6.0763,
+                5.7452, 5.4363, 5.1483,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_same_day_review_uses_short_term_stability() {
+        let scheduler = FsrsScheduler::new(FsrsParameters {
+            weights: REFERENCE_WEIGHTS,
+            ..FsrsParameters::default()
+        });
+        let first = scheduler.schedule(&ReviewState::default(), ReviewGrade::Again, start());
+
+        let later = start() + Duration::minutes(1);
+        let grades = [ReviewGrade::Again, ReviewGrade::Hard, ReviewGrade::Good, ReviewGrade::Easy];
+        let next = grades.map(|grade| scheduler.schedule(&first, grade, later));
+
+        // 與 rs-fsrs 1.2.1 在學習階段（Again 後一分鐘）算出的記憶狀態相同
+        assert_eq!(next.clone().map(|s| round4(s.stability.unwrap())), [0.2165, 0.3531, 0.5759, 0.9392]);
+        assert_eq!(next.map(|s| round4(s.difficulty.unwrap())), [8.6068, 7.6736, 6.7403, 5.8071]);
+    }
+
+    #[test]
+    fn test_first_review_intervals_are_ordered() {
+        let scheduler = FsrsScheduler::default();
+        let intervals = [ReviewGrade::Again, ReviewGrade::Hard, ReviewGrade::Good, ReviewGrade::Easy]
+            .map(|grade| scheduler.schedule(&ReviewState::default(), grade, start()).interval_days);
+
+        assert_eq!(intervals, [1, 2, 3, 15]);
+    }
+
+    #[test]
+    fn test_retrievability_at_stability_is_ninety_percent() {
+        assert!((FsrsScheduler::retrievability(10.0, 10.0) - 0.9).abs() < 1e-9);
+        assert!((FsrsScheduler::retrievability(0.0, 10.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_legacy_familiarity_seeds_memory() {
+        let scheduler = FsrsScheduler::default();
+        let legacy = ReviewState {
+            familiarity: 3,
+            ..ReviewState::default()
+        };
+
+        let next = scheduler.schedule(&legacy, ReviewGrade::Good, start());
+        let fresh = scheduler.schedule(&ReviewState::default(), ReviewGrade::Good, start());
+
+        // 熟悉度 3 視為曾以 Easy 作答，stability 應大於全新卡片
+        assert!(next.stability.unwrap() > fresh.stability.unwrap());
+        assert_eq!(next.familiarity, 2);
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::domain::value_objects::word_forms::WordForms;
+
+/// 內建的不規則變化表，每行以空白分隔，`#` 開頭為註解
+const IRREGULAR_VERBS: &str = include_str!("../../../resources/irregular_verbs.txt");
+const IRREGULAR_PLURALS: &str = include_str!("../../../resources/irregular_plurals.txt");
+const IRREGULAR_COMPARATIVES: &str = include_str!("../../../resources/irregular_comparatives.txt");
+
+/// 重音在最後一個音節、過去式與 -ing 需要重複字尾子音的多音節動詞
+const FINAL_STRESS: &[&str] = &[
+    "admit", "commit", "compel", "control", "equip", "expel", "occur", "omit", "patrol",
+    "permit", "prefer", "propel", "rebel", "recur", "refer", "regret", "submit", "transfer",
+];
+
+/// 不規則變化表，詞元還原（`lemmatizer`）也以此反查原形
+pub(super) struct IrregularForms {
+    /// 原形 -> (過去式, 過去分詞)
+    pub(super) verbs: HashMap<&'static str, (&'static str, &'static str)>,
+    /// 單數 -> 複數
+    pub(super) plurals: HashMap<&'static str, &'static str>,
+    /// 原級 -> (比較級, 最高級)
+    pub(super) comparatives: HashMap<&'static str, (&'static str, &'static str)>,
+}
+
+pub(super) fn rows(table: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    table
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_whitespace().collect())
+}
+
+/// 取得不規則變化表（第一次呼叫時解析）
+pub(super) fn irregular() -> &'static IrregularForms {
+    static FORMS: OnceLock<IrregularForms> = OnceLock::new();
+    FORMS.get_or_init(|| IrregularForms {
+        verbs: rows(IRREGULAR_VERBS)
+            .filter_map(|row| match row[..] {
+                [base, past, participle] => Some((base, (past, participle))),
+                _ => None,
+            })
+            .collect(),
+        plurals: rows(IRREGULAR_PLURALS)
+            .filter_map(|row| match row[..] {
+                [singular, plural] => Some((singular, plural)),
+                _ => None,
+            })
+            .collect(),
+        comparatives: rows(IRREGULAR_COMPARATIVES)
+            .filter_map(|row| match row[..] {
+                [base, comparative, superlative] => Some((base, (comparative, superlative))),
+                _ => None,
+            })
+            .collect(),
+    })
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// 以子音加 y 結尾，例如 "study"、"happy"
+fn ends_with_consonant_y(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('y') && chars.next().is_some_and(|c| !is_vowel(c))
+}
+
+fn ends_with_sibilant(word: &str) -> bool {
+    ["s", "x", "z", "ch", "sh"].iter().any(|suffix| word.ends_with(suffix))
+}
+
+/// 粗略的音節數：母音（含 y）連續出現算一個，字尾不發音的 e 不算
+fn syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c) || c == 'y';
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee") {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+/// 加上字尾前是否要重複最後一個子音，例如 "stop" -> "stopped"、"prefer" -> "preferring"
+fn doubles_final_consonant(word: &str) -> bool {
+    let chars = word.chars().collect::<Vec<_>>();
+    let [.., before, vowel, last] = chars[..] else { return false };
+    let cvc = !is_vowel(before) && is_vowel(vowel) && !is_vowel(last) && !matches!(last, 'w' | 'x' | 'y');
+    cvc && (syllables(word) == 1 || FINAL_STRESS.contains(&word))
+}
+
+/// 加上以母音開頭的字尾（-ed、-ing、-er、-est）
+fn with_suffix(word: &str, suffix: &str) -> String {
+    if word.ends_with('e') && suffix.starts_with('e') {
+        format!("{}{}", word, &suffix[1..])
+    } else if ends_with_consonant_y(word) && suffix != "ing" {
+        format!("{}i{}", &word[..word.len() - 1], suffix)
+    } else if word.len() > 2 && word.ends_with("ic") && suffix != "er" && suffix != "est" {
+        // panic -> panicked
+        format!("{}k{}", word, suffix)
+    } else if doubles_final_consonant(word) {
+        let last = &word[word.len() - 1..];
+        format!("{}{}{}", word, last, suffix)
+    } else {
+        format!("{}{}", word, suffix)
+    }
+}
+
+fn past(word: &str) -> String {
+    match irregular().verbs.get(word) {
+        Some((past, _)) => past.to_string(),
+        None => with_suffix(word, "ed"),
+    }
+}
+
+fn past_participle(word: &str) -> String {
+    match irregular().verbs.get(word) {
+        Some((_, participle)) => participle.to_string(),
+        None => with_suffix(word, "ed"),
+    }
+}
+
+fn third_person(word: &str) -> String {
+    match word {
+        "be" => "is".to_string(),
+        "have" => "has".to_string(),
+        _ if ends_with_consonant_y(word) => format!("{}ies", &word[..word.len() - 1]),
+        _ if ends_with_sibilant(word) => format!("{}es", word),
+        _ if word.ends_with('o') && !word.ends_with("oo") => format!("{}es", word),
+        _ => format!("{}s", word),
+    }
+}
+
+fn present_participle(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ie") {
+        return format!("{}ying", stem);
+    }
+    let keeps_e = ["ee", "oe", "ye"].iter().any(|suffix| word.ends_with(suffix)) || word.len() <= 2;
+    if word.ends_with('e') && !keeps_e {
+        return format!("{}ing", &word[..word.len() - 1]);
+    }
+    with_suffix(word, "ing")
+}
+
+fn plural(word: &str) -> String {
+    match irregular().plurals.get(word) {
+        Some(plural) => plural.to_string(),
+        None if ends_with_consonant_y(word) => format!("{}ies", &word[..word.len() - 1]),
+        None if ends_with_sibilant(word) => format!("{}es", word),
+        None => format!("{}s", word),
+    }
+}
+
+/// 比較級與最高級：單音節與 -y 結尾的雙音節形容詞加 -er/-est，其餘加 more/most
+fn comparison(word: &str) -> (String, String) {
+    if let Some((comparative, superlative)) = irregular().comparatives.get(word) {
+        return (comparative.to_string(), superlative.to_string());
+    }
+    let short = match syllables(word) {
+        1 => true,
+        2 => ends_with_consonant_y(word) && !word.ends_with("ly"),
+        _ => false,
+    };
+    if short {
+        (with_suffix(word, "er"), with_suffix(word, "est"))
+    } else {
+        (format!("more {}", word), format!("most {}", word))
+    }
+}
+
+/// 依原字的大小寫調整變化後的字，例如 "Run" -> "Ran"、"NASA" 維持全大寫
+fn match_case(original: &str, form: String) -> String {
+    if original.chars().count() > 1 && original.chars().all(|c| c.is_ascii_uppercase()) {
+        return form.to_uppercase();
+    }
+    let mut chars = original.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_uppercase() => {
+            let mut form_chars = form.chars();
+            match form_chars.next() {
+                Some(f) => f.to_ascii_uppercase().to_string() + form_chars.as_str(),
+                None => form,
+            }
+        }
+        _ => form,
+    }
+}
+
+/// 詞性對應到要產生的變化
+#[derive(Debug, Clone, Copy, Default)]
+struct Applicable {
+    verb: bool,
+    noun: bool,
+    comparable: bool,
+}
+
+impl Applicable {
+    const ALL: Self = Self { verb: true, noun: true, comparable: true };
+
+    /// 由詞性文字判斷，例如 dictionaryapi.dev 的 "verb"、"noun"、"adjective"、"adverb"
+    fn from_parts_of_speech(parts_of_speech: &[&str]) -> Self {
+        let tagged = parts_of_speech
+            .iter()
+            .map(|pos| pos.trim().to_lowercase())
+            .filter(|pos| !pos.is_empty())
+            .collect::<Vec<_>>();
+        if tagged.is_empty() {
+            return Self::ALL;
+        }
+
+        let mut applicable = Self::default();
+        for pos in &tagged {
+            let adverb = pos.contains("adverb") || pos == "adv" || pos == "adv.";
+            let adjective = pos.contains("adjective") || pos == "adj" || pos == "adj.";
+            applicable.verb |= (pos.contains("verb") && !adverb) || pos == "v" || pos == "v.";
+            applicable.noun |= (pos.contains("noun") && !pos.contains("pronoun")) || pos == "n" || pos == "n.";
+            applicable.comparable |= adjective || adverb;
+        }
+        applicable
+    }
+}
+
+/// 產生單字所有可能的詞形變化（動詞、名詞與形容詞的變化全部列出）
+pub fn inflect(word: &str) -> WordForms {
+    inflect_as(word, Applicable::ALL)
+}
+
+/// 依詞性產生詞形變化；沒有任何詞性資訊時視同 `inflect`
+///
+/// 片語只變化第一個字（動詞，如 "look up" -> "looked up"）或最後一個字（複數，如 "ice cream" -> "ice creams"），
+/// 不產生比較級。含有字母以外字元的單字不產生任何變化。
+pub fn inflect_for(word: &str, parts_of_speech: &[&str]) -> WordForms {
+    inflect_as(word, Applicable::from_parts_of_speech(parts_of_speech))
+}
+
+fn inflect_as(word: &str, applicable: Applicable) -> WordForms {
+    let tokens = word.split_whitespace().collect::<Vec<_>>();
+    let inflectable = !tokens.is_empty()
+        && tokens.iter().all(|t| t.chars().all(|c| c.is_ascii_alphabetic()));
+    if !inflectable {
+        return WordForms::default();
+    }
+
+    // 以第一個或最後一個字產生變化，再接回片語的其他部分
+    let first = tokens[0];
+    let last = tokens[tokens.len() - 1];
+    let with_first = |form: fn(&str) -> String| {
+        let mut words = vec![match_case(first, form(&first.to_lowercase()))];
+        words.extend(tokens[1..].iter().map(|t| t.to_string()));
+        words.join(" ")
+    };
+    let with_last = |form: fn(&str) -> String| {
+        let mut words = tokens[..tokens.len() - 1].iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        words.push(match_case(last, form(&last.to_lowercase())));
+        words.join(" ")
+    };
+
+    let mut forms = WordForms::default();
+    if applicable.verb {
+        forms.past = Some(with_first(past));
+        forms.past_participle = Some(with_first(past_participle));
+        forms.third_person = Some(with_first(third_person));
+        forms.present_participle = Some(with_first(present_participle));
+    }
+    if applicable.noun {
+        forms.plural = Some(with_last(plural));
+    }
+    if applicable.comparable && tokens.len() == 1 {
+        let (comparative, superlative) = comparison(&first.to_lowercase());
+        forms.comparative = Some(match_case(first, comparative));
+        forms.superlative = Some(match_case(first, superlative));
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verb_forms(word: &str) -> [String; 4] {
+        let forms = inflect_for(word, &["verb"]);
+        [
+            forms.past.unwrap(),
+            forms.past_participle.unwrap(),
+            forms.third_person.unwrap(),
+            forms.present_participle.unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_regular_and_irregular_verbs() {
+        assert_eq!(verb_forms("walk"), ["walked", "walked", "walks", "walking"]);
+        assert_eq!(verb_forms("make"), ["made", "made", "makes", "making"]);
+        assert_eq!(verb_forms("stop"), ["stopped", "stopped", "stops", "stopping"]);
+        assert_eq!(verb_forms("study"), ["studied", "studied", "studies", "studying"]);
+        assert_eq!(verb_forms("prefer"), ["preferred", "preferred", "prefers", "preferring"]);
+        assert_eq!(verb_forms("visit"), ["visited", "visited", "visits", "visiting"]);
+        assert_eq!(verb_forms("die"), ["died", "died", "dies", "dying"]);
+        assert_eq!(verb_forms("panic"), ["panicked", "panicked", "panics", "panicking"]);
+        assert_eq!(verb_forms("watch"), ["watched", "watched", "watches", "watching"]);
+        assert_eq!(verb_forms("go"), ["went", "gone", "goes", "going"]);
+        assert_eq!(verb_forms("be"), ["was", "been", "is", "being"]);
+        assert_eq!(verb_forms("see"), ["saw", "seen", "sees", "seeing"]);
+    }
+
+    #[test]
+    fn test_plurals_and_comparatives() {
+        let plural = |w: &str| inflect_for(w, &["noun"]).plural.unwrap();
+        assert_eq!(plural("apple"), "apples");
+        assert_eq!(plural("box"), "boxes");
+        assert_eq!(plural("city"), "cities");
+        assert_eq!(plural("day"), "days");
+        assert_eq!(plural("child"), "children");
+        assert_eq!(plural("ice cream"), "ice creams");
+
+        let comparison = |w: &str| {
+            let forms = inflect_for(w, &["adjective"]);
+            (forms.comparative.unwrap(), forms.superlative.unwrap())
+        };
+        assert_eq!(comparison("big"), ("bigger".to_string(), "biggest".to_string()));
+        assert_eq!(comparison("large"), ("larger".to_string(), "largest".to_string()));
+        assert_eq!(comparison("happy"), ("happier".to_string(), "happiest".to_string()));
+        assert_eq!(comparison("good"), ("better".to_string(), "best".to_string()));
+        assert_eq!(comparison("beautiful"), ("more beautiful".to_string(), "most beautiful".to_string()));
+        assert_eq!(inflect_for("quickly", &["adverb"]).comparative.as_deref(), Some("more quickly"));
+    }
+
+    #[test]
+    fn test_parts_of_speech_select_forms() {
+        let noun = inflect_for("Apple", &["noun"]);
+        assert_eq!(noun.plural.as_deref(), Some("Apples"));
+        assert_eq!(noun.past, None);
+        assert_eq!(noun.comparative, None);
+
+        let phrase = inflect_for("give up", &["verb"]);
+        assert_eq!(phrase.past.as_deref(), Some("gave up"));
+        assert!(inflect_for("of", &["preposition"]).is_empty());
+        assert!(inflect("e-mail").is_empty());
+
+        let untagged = inflect_for("run", &[""]);
+        assert_eq!(untagged.past.as_deref(), Some("ran"));
+        assert_eq!(untagged.plural.as_deref(), Some("runs"));
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::domain::services::scheduler::Scheduler;
+use crate::domain::value_objects::review::{ReviewGrade, ReviewState, SchedulerKind};
+
+/// 在排程演算法之前加上當日學習步驟的排程器
+///
+/// - 新卡片依 `learning_steps` 學習：Again 回到第一步、Hard 重複目前步驟、Good 前進一步、
+///   Easy 直接畢業；走完所有步驟後才由內部的排程演算法安排第一次複習。
+/// - 複習卡片答 Again 時先由排程演算法計算遺忘後的狀態，再依 `relearning_steps` 重新學習；
+///   畢業時沿用遺忘時算出的間隔，不再重複計算。
+///
+/// 學習中的卡片以 `interval_days` 區分：新卡片尚未排程過為 0，重新學習的卡片則大於 0。
+/// 兩種步驟皆為空陣列時，行為與內部的排程演算法完全相同。
+pub struct SteppedScheduler {
+    inner: Box<dyn Scheduler>,
+    learning_steps: Vec<i32>,
+    relearning_steps: Vec<i32>,
+}
+
+impl SteppedScheduler {
+    pub fn new(inner: Box<dyn Scheduler>, learning_steps: Vec<i32>, relearning_steps: Vec<i32>) -> Self {
+        Self { inner, learning_steps, relearning_steps }
+    }
+
+    /// 停留在第 `step` 步，`minutes` 分鐘後再複習
+    fn at_step(state: &ReviewState, step: usize, minutes: i32, now: NaiveDateTime) -> ReviewState {
+        ReviewState {
+            due_at: Some(now + Duration::minutes(minutes as i64)),
+            last_reviewed_at: Some(now),
+            learning_step: Some(step as i32),
+            ..state.clone()
+        }
+    }
+
+    /// 依評分決定下一步；回傳 `None` 代表畢業
+    fn next_step(steps: &[i32], current: usize, grade: ReviewGrade) -> Option<usize> {
+        let next = match grade {
+            ReviewGrade::Again => 0,
+            ReviewGrade::Hard => current,
+            ReviewGrade::Good => current + 1,
+            ReviewGrade::Easy => return None,
+        };
+        (next < steps.len()).then_some(next)
+    }
+}
+
+impl Scheduler for SteppedScheduler {
+    fn kind(&self) -> SchedulerKind {
+        self.inner.kind()
+    }
+
+    fn schedule(&self, state: &ReviewState, grade: ReviewGrade, now: NaiveDateTime) -> ReviewState {
+        let relearning = state.interval_days > 0;
+
+        match state.learning_step {
+            Some(step) => {
+                let steps = if relearning { &self.relearning_steps } else { &self.learning_steps };
+                // 設定變更後步驟可能變少，超出範圍時視為最後一步
+                let current = (step.max(0) as usize).min(steps.len().saturating_sub(1));
+                match Self::next_step(steps, current, grade) {
+                    Some(next) => Self::at_step(state, next, steps[next], now),
+                    None if relearning => ReviewState {
+                        due_at: Some(now + Duration::days(state.interval_days as i64)),
+                        last_reviewed_at: Some(now),
+                        learning_step: None,
+                        familiarity: grade.as_i32(),
+                        ..state.clone()
+                    },
+                    None => self.inner.schedule(&ReviewState { learning_step: None, ..state.clone() }, grade, now),
+                }
+            }
+            None if state.is_new() && !self.learning_steps.is_empty() => {
+                // 新卡片第一次作答視為位於第一步
+                match Self::next_step(&self.learning_steps, 0, grade) {
+                    Some(next) => Self::at_step(state, next, self.learning_steps[next], now),
+                    None => self.inner.schedule(state, grade, now),
+                }
+            }
+            None if grade == ReviewGrade::Again && !self.relearning_steps.is_empty() => {
+                let lapsed = self.inner.schedule(state, grade, now);
+                Self::at_step(&lapsed, 0, self.relearning_steps[0], now)
+            }
+            None => self.inner.schedule(state, grade, now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::sm2::Sm2Scheduler;
+    use chrono::NaiveDate;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(9, 0, 0).unwrap()
+    }
+
+    fn stepped(learning: &[i32], relearning: &[i32]) -> SteppedScheduler {
+        SteppedScheduler::new(Box::new(Sm2Scheduler::new()), learning.to_vec(), relearning.to_vec())
+    }
+
+    #[test]
+    fn test_new_card_walks_learning_steps_before_graduating() {
+        let scheduler = stepped(&[1, 10], &[]);
+
+        let first = scheduler.schedule(&ReviewState::default(), ReviewGrade::Good, now());
+        assert_eq!(first.learning_step, Some(1));
+        assert_eq!(first.due_at, Some(now() + Duration::minutes(10)));
+        assert_eq!(first.interval_days, 0);
+
+        let again = scheduler.schedule(&first, ReviewGrade::Again, now());
+        assert_eq!(again.learning_step, Some(0));
+        assert_eq!(again.due_at, Some(now() + Duration::minutes(1)));
+
+        let graduated = scheduler.schedule(&first, ReviewGrade::Good, now());
+        assert_eq!(graduated.learning_step, None);
+        assert_eq!(graduated.interval_days, 1);
+        assert_eq!(graduated.repetitions, 1);
+
+        let easy = scheduler.schedule(&ReviewState::default(), ReviewGrade::Easy, now());
+        assert_eq!(easy.learning_step, None);
+        assert_eq!(easy.interval_days, 1);
+    }
+
+    #[test]
+    fn test_lapse_relearns_then_keeps_lapsed_interval() {
+        let scheduler = stepped(&[1, 10], &[10]);
+        let review = ReviewState {
+            interval_days: 20,
+            repetitions: 4,
+            due_at: Some(now()),
+            ..ReviewState::default()
+        };
+
+        let lapsed = scheduler.schedule(&review, ReviewGrade::Again, now());
+        assert_eq!(lapsed.learning_step, Some(0));
+        assert_eq!(lapsed.repetitions, 0);
+        assert_eq!(lapsed.due_at, Some(now() + Duration::minutes(10)));
+
+        let relearned = scheduler.schedule(&lapsed, ReviewGrade::Good, now());
+        assert_eq!(relearned.learning_step, None);
+        assert_eq!(relearned.due_at, Some(now() + Duration::days(lapsed.interval_days as i64)));
+    }
+
+    #[test]
+    fn test_without_steps_matches_inner_scheduler() {
+        let scheduler = stepped(&[], &[]);
+        let inner = Sm2Scheduler::new();
+        for grade in [ReviewGrade::Again, ReviewGrade::Good] {
+            assert_eq!(
+                scheduler.schedule(&ReviewState::default(), grade, now()),
+                inner.schedule(&ReviewState::default(), grade, now())
+            );
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::inflection::{irregular, rows};
+
+/// 不規則變化表以外的例外詞形，每行為「變化形 詞元」
+const LEMMA_EXCEPTIONS: &str = include_str!("../../../resources/lemma_exceptions.txt");
+
+/// 詞元的最短長度，避免 "bed" 之類的字被還原成單一字母
+const MIN_LEMMA_LENGTH: usize = 2;
+
+/// 變化形 -> 可能的詞元（第一次呼叫時由不規則變化表與例外表建立）
+fn exceptions() -> &'static HashMap<&'static str, Vec<&'static str>> {
+    static EXCEPTIONS: OnceLock<HashMap<&'static str, Vec<&'static str>>> = OnceLock::new();
+    EXCEPTIONS.get_or_init(|| {
+        let forms = irregular();
+        let mut pairs = Vec::new();
+        for (base, (past, participle)) in &forms.verbs {
+            pairs.push((*past, *base));
+            pairs.push((*participle, *base));
+        }
+        for (singular, plural) in &forms.plurals {
+            pairs.push((*plural, *singular));
+        }
+        for (base, (comparative, superlative)) in &forms.comparatives {
+            pairs.push((*comparative, *base));
+            pairs.push((*superlative, *base));
+        }
+        pairs.extend(rows(LEMMA_EXCEPTIONS).filter_map(|row| match row[..] {
+            [form, lemma] => Some((form, lemma)),
+            _ => None,
+        }));
+
+        let mut map: HashMap<&'static str, Vec<&'static str>> = HashMap::new();
+        for (form, lemma) in pairs {
+            let lemmas = map.entry(form).or_default();
+            if form != lemma && !lemmas.contains(&lemma) {
+                lemmas.push(lemma);
+            }
+        }
+        // 表格以 HashMap 讀入，排序讓結果固定
+        for lemmas in map.values_mut() {
+            lemmas.sort_unstable();
+        }
+        map
+    })
+}
+
+/// 去掉重複的字尾子音，例如 "runn" -> "run"
+fn undoubled(stem: &str) -> Option<&str> {
+    let mut chars = stem.chars().rev();
+    let (last, before) = (chars.next()?, chars.next()?);
+    let consonant = !matches!(last, 'a' | 'e' | 'i' | 'o' | 'u');
+    (last == before && consonant).then(|| &stem[..stem.len() - last.len_utf8()])
+}
+
+/// 依字尾規則推測可能的詞元，較常見的還原方式排在前面
+fn rule_candidates(word: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+
+    if let Some(stem) = word.strip_suffix("ies") {
+        candidates.push(format!("{}y", stem));
+        candidates.push(format!("{}ie", stem));
+    }
+    if let Some(stem) = word.strip_suffix("ves") {
+        candidates.push(format!("{}f", stem));
+        candidates.push(format!("{}fe", stem));
+    }
+    if word.ends_with('s') && !["ss", "us", "is"].iter().any(|suffix| word.ends_with(suffix)) {
+        candidates.push(word[..word.len() - 1].to_string());
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        candidates.push(stem.to_string());
+    }
+
+    if let Some(stem) = word.strip_suffix("ied") {
+        candidates.push(format!("{}y", stem));
+    }
+    if let Some(stem) = word.strip_suffix("ying") {
+        candidates.push(format!("{}ie", stem));
+    }
+    if let Some(stem) = word.strip_suffix("ier").or_else(|| word.strip_suffix("iest")) {
+        candidates.push(format!("{}y", stem));
+    }
+
+    // -ed、-ing、-er、-est：原字、原字加 e、去掉重複子音、panicked -> panic
+    for suffix in ["ed", "ing", "er", "est"] {
+        let Some(stem) = word.strip_suffix(suffix) else { continue };
+        candidates.push(stem.to_string());
+        candidates.push(format!("{}e", stem));
+        if let Some(single) = undoubled(stem) {
+            candidates.push(single.to_string());
+        }
+        if let Some(single) = stem.strip_suffix("ck") {
+            candidates.push(format!("{}c", single));
+        }
+    }
+
+    candidates
+}
+
+/// 單一個字可能的詞元：先查例外表，再套用字尾規則
+fn word_lemmas(word: &str) -> Vec<String> {
+    let mut lemmas = exceptions()
+        .get(word)
+        .map(|lemmas| lemmas.iter().map(|l| l.to_string()).collect::<Vec<_>>())
+        .unwrap_or_default();
+    lemmas.extend(rule_candidates(word));
+    lemmas
+}
+
+/// 推測變化形可能的詞元（原形），依可能性排序，不含原字
+///
+/// 離線的規則式還原：不規則變化表與例外表優先，其次為字尾規則（-s、-es、-ed、-ing、-er、-est）。
+/// 規則可能產生不存在的字，呼叫端應以既有單字卡驗證。
+/// 片語分別還原第一個字（動詞，如 "looked up"）與最後一個字（複數，如 "ice creams"）。
+pub fn lemma_candidates(word: &str) -> Vec<String> {
+    let lowered = word.to_lowercase();
+    let tokens = lowered.split_whitespace().collect::<Vec<_>>();
+    let Some((first, rest)) = tokens.split_first() else { return Vec::new() };
+
+    let mut candidates = word_lemmas(first)
+        .into_iter()
+        .map(|lemma| [lemma.as_str()].iter().chain(rest).copied().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>();
+    if let Some((last, init)) = tokens.split_last().filter(|_| tokens.len() > 1) {
+        candidates.extend(
+            word_lemmas(last)
+                .into_iter()
+                .map(|lemma| init.iter().copied().chain([lemma.as_str()]).collect::<Vec<_>>().join(" ")),
+        );
+    }
+
+    let original = tokens.join(" ");
+    let mut unique: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let long_enough = candidate.split_whitespace().all(|t| t.chars().count() >= MIN_LEMMA_LENGTH);
+        if long_enough && candidate != original && !unique.contains(&candidate) {
+            unique.push(candidate);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_known(word: &str, known: &[&str]) -> Option<String> {
+        lemma_candidates(word).into_iter().find(|c| known.contains(&c.as_str()))
+    }
+
+    #[test]
+    fn test_irregular_forms_use_tables() {
+        assert_eq!(lemma_candidates("mice")[0], "mouse");
+        assert_eq!(lemma_candidates("went")[0], "go");
+        assert_eq!(lemma_candidates("Children")[0], "child");
+        assert_eq!(lemma_candidates("is")[0], "be");
+        assert_eq!(lemma_candidates("better"), ["good", "well", "bett", "bette", "bet"]);
+    }
+
+    #[test]
+    fn test_suffix_rules_find_known_lemmas() {
+        let known = ["run", "make", "study", "box", "cat", "big", "die", "panic", "walk", "leaf"];
+        assert_eq!(first_known("running", &known).as_deref(), Some("run"));
+        assert_eq!(first_known("making", &known).as_deref(), Some("make"));
+        assert_eq!(first_known("studied", &known).as_deref(), Some("study"));
+        assert_eq!(first_known("studies", &known).as_deref(), Some("study"));
+        assert_eq!(first_known("boxes", &known).as_deref(), Some("box"));
+        assert_eq!(first_known("cats", &known).as_deref(), Some("cat"));
+        assert_eq!(first_known("biggest", &known).as_deref(), Some("big"));
+        assert_eq!(first_known("dying", &known).as_deref(), Some("die"));
+        assert_eq!(first_known("panicked", &known).as_deref(), Some("panic"));
+        assert_eq!(first_known("walked", &known).as_deref(), Some("walk"));
+        assert_eq!(first_known("leaves", &known).as_deref(), Some("leaf"));
+    }
+
+    #[test]
+    fn test_phrases_and_short_words() {
+        assert!(lemma_candidates("looked up").contains(&"look up".to_string()));
+        assert!(lemma_candidates("ice creams").contains(&"ice cream".to_string()));
+        assert!(!lemma_candidates("bed").contains(&"b".to_string()));
+        assert!(!lemma_candidates("walk").contains(&"walk".to_string()));
+        assert!(lemma_candidates("  ").is_empty());
+    }
+
+    #[test]
+    fn test_non_ascii_doubled_letters() {
+        // 重複的字尾是多位元組字元時，不能只切掉一個位元組
+        assert_eq!(lemma_candidates("ééing"), ["éé", "éée"]);
+        assert!(lemma_candidates("grüßßer").contains(&"grüß".to_string()));
+    }
+}
@@ -1 +1,12 @@
-// Domain services will be defined here
+pub mod anki;
+pub mod dictionary;
+pub mod fsrs;
+pub mod inflection;
+pub mod learning_steps;
+pub mod lemmatizer;
+pub mod review_queue;
+pub mod scheduler;
+pub mod sm2;
+pub mod spelling;
+pub mod spreadsheet;
+pub mod tokenizer;
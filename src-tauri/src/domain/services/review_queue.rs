@@ -0,0 +1,68 @@
+/// 將新卡片平均穿插在複習卡片之間
+///
+/// 例如 6 張複習卡與 2 張新卡會排成 `R R R N R R R N`，
+/// 讓新卡片不會全部集中在開頭或結尾。
+pub fn interleave<T>(reviews: Vec<T>, new_cards: Vec<T>) -> Vec<T> {
+    if new_cards.is_empty() {
+        return reviews;
+    }
+    if reviews.is_empty() {
+        return new_cards;
+    }
+
+    let total = reviews.len() + new_cards.len();
+    let new_count = new_cards.len();
+    let mut queue = Vec::with_capacity(total);
+    let mut reviews = reviews.into_iter();
+    let mut new_cards = new_cards.into_iter();
+    let mut placed_new = 0;
+
+    for position in 1..=total {
+        // 第 k 張新卡放在整體進度到達 k / new_count 的位置
+        if placed_new < new_count && position * new_count >= (placed_new + 1) * total {
+            queue.extend(new_cards.next());
+            placed_new += 1;
+        } else if let Some(card) = reviews.next() {
+            queue.push(card);
+        } else {
+            queue.extend(new_cards.next());
+            placed_new += 1;
+        }
+    }
+
+    queue
+}
+
+/// 計算今日剩餘的配額
+pub fn remaining_quota(daily_limit: i64, done_today: i64) -> i64 {
+    (daily_limit - done_today).max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleave_spreads_new_cards() {
+        let queue = interleave(vec!['R'; 6], vec!['N'; 2]);
+        assert_eq!(queue.iter().collect::<String>(), "RRRNRRRN");
+    }
+
+    #[test]
+    fn test_interleave_keeps_order() {
+        let queue = interleave(vec![1, 2, 3], vec![10, 20, 30]);
+        assert_eq!(queue, vec![1, 10, 2, 20, 3, 30]);
+    }
+
+    #[test]
+    fn test_interleave_with_empty_side() {
+        assert_eq!(interleave(Vec::<i32>::new(), vec![1, 2]), vec![1, 2]);
+        assert_eq!(interleave(vec![1, 2], Vec::new()), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_remaining_quota() {
+        assert_eq!(remaining_quota(20, 5), 15);
+        assert_eq!(remaining_quota(20, 25), 0);
+    }
+}
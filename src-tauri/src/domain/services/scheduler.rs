@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+
+use crate::domain::services::fsrs::FsrsScheduler;
+use crate::domain::services::sm2::Sm2Scheduler;
+use crate::domain::value_objects::review::{ReviewGrade, ReviewState, SchedulerKind};
+
+/// 間隔重複排程器的共同介面
+pub trait Scheduler: Send + Sync {
+    fn kind(&self) -> SchedulerKind;
+
+    /// 根據作答評分計算新的排程狀態
+    fn schedule(&self, state: &ReviewState, grade: ReviewGrade, now: NaiveDateTime) -> ReviewState;
+}
+
+/// 依設定檔選擇的演算法建立排程器
+pub fn scheduler_for(kind: SchedulerKind) -> Box<dyn Scheduler> {
+    match kind {
+        SchedulerKind::Sm2 => Box::new(Sm2Scheduler::new()),
+        SchedulerKind::Fsrs => Box::new(FsrsScheduler::default()),
+    }
+}
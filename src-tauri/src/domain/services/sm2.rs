@@ -0,0 +1,147 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::domain::services::scheduler::Scheduler;
+use crate::domain::value_objects::review::{ReviewGrade, ReviewState, SchedulerKind};
+
+/// 難易度係數下限
+const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// SuperMemo SM-2 排程器
+///
+/// 作答評分對應到 SM-2 的 0～5 品質分數：Again→2、Hard→3、Good→4、Easy→5。
+/// 品質低於 3 視為遺忘，重置重複次數並於隔天再複習，難易度係數維持不變。
+#[derive(Debug, Clone, Default)]
+pub struct Sm2Scheduler;
+
+impl Sm2Scheduler {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn quality(grade: ReviewGrade) -> i32 {
+        match grade {
+            ReviewGrade::Again => 2,
+            ReviewGrade::Hard => 3,
+            ReviewGrade::Good => 4,
+            ReviewGrade::Easy => 5,
+        }
+    }
+}
+
+impl Scheduler for Sm2Scheduler {
+    fn kind(&self) -> SchedulerKind {
+        SchedulerKind::Sm2
+    }
+
+    fn schedule(&self, state: &ReviewState, grade: ReviewGrade, now: NaiveDateTime) -> ReviewState {
+        let quality = Self::quality(grade);
+
+        if quality < 3 {
+            return ReviewState {
+                interval_days: 1,
+                repetitions: 0,
+                due_at: Some(now + Duration::days(1)),
+                last_reviewed_at: Some(now),
+                familiarity: grade.as_i32(),
+                ..state.clone()
+            };
+        }
+
+        let repetitions = state.repetitions + 1;
+        let interval_days = match repetitions {
+            1 => 1,
+            2 => 6,
+            _ => (state.interval_days as f64 * state.ease_factor).round() as i32,
+        };
+
+        let q = (5 - quality) as f64;
+        let ease_factor = (state.ease_factor + (0.1 - q * (0.08 + q * 0.02))).max(MIN_EASE_FACTOR);
+
+        ReviewState {
+            ease_factor,
+            interval_days,
+            repetitions,
+            due_at: Some(now + Duration::days(interval_days as i64)),
+            last_reviewed_at: Some(now),
+            familiarity: grade.as_i32(),
+            ..state.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_first_reviews_follow_fixed_intervals() {
+        let scheduler = Sm2Scheduler::new();
+        let first = scheduler.schedule(&ReviewState::default(), ReviewGrade::Good, now());
+        assert_eq!(first.repetitions, 1);
+        assert_eq!(first.interval_days, 1);
+        assert_eq!(first.due_at, Some(now() + Duration::days(1)));
+
+        let second = scheduler.schedule(&first, ReviewGrade::Good, now());
+        assert_eq!(second.repetitions, 2);
+        assert_eq!(second.interval_days, 6);
+    }
+
+    #[test]
+    fn test_interval_grows_by_ease_factor() {
+        let scheduler = Sm2Scheduler::new();
+        let state = ReviewState {
+            ease_factor: 2.5,
+            interval_days: 6,
+            repetitions: 2,
+            due_at: Some(now()),
+            ..ReviewState::default()
+        };
+        let next = scheduler.schedule(&state, ReviewGrade::Good, now());
+        assert_eq!(next.interval_days, 15);
+        // quality 4 不改變難易度係數
+        assert!((next.ease_factor - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ease_factor_adjustments() {
+        let scheduler = Sm2Scheduler::new();
+        let state = ReviewState::default();
+
+        let easy = scheduler.schedule(&state, ReviewGrade::Easy, now());
+        assert!((easy.ease_factor - 2.6).abs() < 1e-9);
+
+        let hard = scheduler.schedule(&state, ReviewGrade::Hard, now());
+        assert!((hard.ease_factor - 2.36).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ease_factor_has_lower_bound() {
+        let scheduler = Sm2Scheduler::new();
+        let mut state = ReviewState::default();
+        for _ in 0..20 {
+            state = scheduler.schedule(&state, ReviewGrade::Hard, now());
+        }
+        assert!((state.ease_factor - MIN_EASE_FACTOR).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lapse_resets_repetitions() {
+        let scheduler = Sm2Scheduler::new();
+        let state = ReviewState {
+            ease_factor: 2.2,
+            interval_days: 30,
+            repetitions: 5,
+            due_at: Some(now()),
+            ..ReviewState::default()
+        };
+        let next = scheduler.schedule(&state, ReviewGrade::Again, now());
+        assert_eq!(next.repetitions, 0);
+        assert_eq!(next.interval_days, 1);
+        assert!((next.ease_factor - 2.2).abs() < 1e-9);
+    }
+}
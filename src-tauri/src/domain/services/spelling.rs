@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+
+/// 建議單字的來源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestionSource {
+    /// 已存在的單字卡
+    Card,
+    /// 內建的英文字表
+    Dictionary,
+}
+
+/// 拼字建議的候選項
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordSuggestion {
+    pub word: String,
+    /// 與查詢字的編輯距離（相鄰字母對調算一次）
+    pub distance: usize,
+    /// Soundex 發音碼是否相同
+    pub phonetic_match: bool,
+    pub source: SuggestionSource,
+    /// 來源為單字卡時的卡片 ID
+    pub card_id: Option<i32>,
+}
+
+/// 建議用的候選字
+#[derive(Debug, Clone)]
+pub struct Candidate<'a> {
+    pub word: &'a str,
+    pub source: SuggestionSource,
+    pub card_id: Option<i32>,
+}
+
+/// 計算兩個字串的編輯距離（Optimal String Alignment）
+///
+/// 插入、刪除、替換與相鄰字母對調各算一次，例如 "recieve" 與 "receive" 的距離為 1。
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let (n, m) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[n][m]
+}
+
+/// 計算英文單字的 Soundex 發音碼（例如 "Robert" → "R163"）
+///
+/// 非英文字母會被忽略；沒有任何英文字母時回傳 `None`。
+pub fn soundex(word: &str) -> Option<String> {
+    fn code(c: char) -> Option<char> {
+        match c {
+            'b' | 'f' | 'p' | 'v' => Some('1'),
+            'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => Some('2'),
+            'd' | 't' => Some('3'),
+            'l' => Some('4'),
+            'm' | 'n' => Some('5'),
+            'r' => Some('6'),
+            _ => None,
+        }
+    }
+
+    let mut letters = word
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase());
+    let first = letters.next()?;
+
+    let mut result = String::with_capacity(4);
+    result.push(first.to_ascii_uppercase());
+    let mut previous = code(first);
+
+    for c in letters {
+        let current = code(c);
+        if let Some(digit) = current {
+            if current != previous {
+                result.push(digit);
+                if result.len() == 4 {
+                    break;
+                }
+            }
+        }
+        // h 與 w 不會隔開相同的代碼，母音則會
+        if c != 'h' && c != 'w' {
+            previous = current;
+        }
+    }
+
+    while result.len() < 4 {
+        result.push('0');
+    }
+    Some(result)
+}
+
+/// 依查詢字長度決定可接受的最大編輯距離
+fn max_distance(query: &str) -> usize {
+    match query.chars().count() {
+        0..=4 => 1,
+        5..=8 => 2,
+        _ => 3,
+    }
+}
+
+/// 從候選字中找出與查詢字相近的單字並排序
+///
+/// 編輯距離在門檻內，或發音碼相同且距離不超過門檻加一的候選字會被採用。
+/// 排序依序為：編輯距離、發音是否相同、來源（單字卡優先）、字母順序。
+/// 與查詢字完全相同的候選字不列入建議。
+pub fn suggest<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = Candidate<'a>>,
+    limit: usize,
+) -> Vec<WordSuggestion> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+
+    let threshold = max_distance(&query);
+    let query_code = soundex(&query);
+    let mut suggestions: Vec<WordSuggestion> = Vec::new();
+
+    for candidate in candidates {
+        let normalized = candidate.word.trim().to_lowercase();
+        if normalized.is_empty() || normalized == query {
+            continue;
+        }
+        // 長度差距已超過門檻時不可能符合，略過計算
+        if normalized.chars().count().abs_diff(query.chars().count()) > threshold + 1 {
+            continue;
+        }
+
+        let distance = edit_distance(&query, &normalized);
+        let phonetic_match = query_code.is_some() && soundex(&normalized) == query_code;
+        if distance > threshold && !(phonetic_match && distance <= threshold + 1) {
+            continue;
+        }
+
+        match suggestions.iter_mut().find(|s| s.word == normalized) {
+            // 同一個字同時出現在單字卡與字表時，保留單字卡
+            Some(existing) if candidate.source < existing.source => {
+                existing.source = candidate.source;
+                existing.card_id = candidate.card_id;
+            }
+            Some(_) => {}
+            None => suggestions.push(WordSuggestion {
+                word: normalized,
+                distance,
+                phonetic_match,
+                source: candidate.source,
+                card_id: candidate.card_id,
+            }),
+        }
+    }
+
+    suggestions.sort_by(|a, b| {
+        a.distance
+            .cmp(&b.distance)
+            .then(b.phonetic_match.cmp(&a.phonetic_match))
+            .then(a.source.cmp(&b.source))
+            .then(a.word.cmp(&b.word))
+    });
+    suggestions.truncate(limit);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary(words: &[&'static str]) -> Vec<Candidate<'static>> {
+        words
+            .iter()
+            .map(|w| Candidate {
+                word: w,
+                source: SuggestionSource::Dictionary,
+                card_id: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("recieve", "receive"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_soundex() {
+        assert_eq!(soundex("Robert").as_deref(), Some("R163"));
+        assert_eq!(soundex("Rupert").as_deref(), Some("R163"));
+        assert_eq!(soundex("Ashcraft").as_deref(), Some("A261"));
+        assert_eq!(soundex("Tymczak").as_deref(), Some("T522"));
+        assert_eq!(soundex("Pfister").as_deref(), Some("P236"));
+        assert_eq!(soundex("123"), None);
+    }
+
+    #[test]
+    fn test_suggest_ranks_by_distance() {
+        let candidates = dictionary(&["receive", "recipe", "relieve", "deceive", "apple"]);
+        let suggestions = suggest("recieve", candidates, 3);
+
+        assert_eq!(suggestions[0].word, "receive");
+        assert_eq!(suggestions[0].distance, 1);
+        assert!(suggestions.iter().all(|s| s.word != "apple"));
+    }
+
+    #[test]
+    fn test_suggest_prefers_cards_over_dictionary() {
+        let mut candidates = dictionary(&["receive"]);
+        candidates.push(Candidate {
+            word: "Receive",
+            source: SuggestionSource::Card,
+            card_id: Some(7),
+        });
+
+        let suggestions = suggest("recieve", candidates, 5);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].source, SuggestionSource::Card);
+        assert_eq!(suggestions[0].card_id, Some(7));
+    }
+
+    #[test]
+    fn test_suggest_skips_exact_match() {
+        let suggestions = suggest("apple", dictionary(&["apple", "apply"]), 5);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].word, "apply");
+    }
+}
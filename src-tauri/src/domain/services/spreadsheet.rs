@@ -0,0 +1,444 @@
+use encoding_rs::{Encoding, BIG5, UTF_16BE, UTF_16LE, UTF_8};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::word_cards::NewWordCard;
+use crate::domain::value_objects::pronunciation::Pronunciation;
+
+/// 自動判斷分隔符號時的候選，次數一樣一致時排在前面的優先
+const DELIMITER_CANDIDATES: [u8; 4] = [b'\t', b',', b';', b'|'];
+
+/// 判斷分隔符號時取樣的行數
+const SNIFF_LINES: usize = 20;
+
+/// 判斷是否為沒有 BOM 的 UTF-16 時取樣的位元組數
+const SNIFF_BYTES: usize = 4096;
+
+/// 判斷某一欄是詞性時，非空白的值中至少要有多少比例是詞性
+const PART_OF_SPEECH_RATIO: f64 = 0.6;
+
+/// 常見的詞性寫法（小寫、不含結尾的句點）
+const PARTS_OF_SPEECH: &[&str] = &[
+    "n", "noun", "v", "verb", "vt", "vi", "adj", "adjective", "adv", "adverb", "prep", "preposition", "conj",
+    "conjunction", "pron", "pronoun", "int", "interj", "interjection", "phr", "phrase", "aux", "art", "det", "num",
+    "名詞", "動詞", "形容詞", "副詞", "介系詞", "介係詞", "介詞", "連接詞", "代名詞", "感嘆詞", "片語",
+];
+
+/// 試算表檔案的文字編碼
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextEncoding {
+    #[serde(rename = "utf-8")]
+    Utf8,
+    #[serde(rename = "utf-16le")]
+    Utf16Le,
+    #[serde(rename = "utf-16be")]
+    Utf16Be,
+    #[serde(rename = "big5")]
+    Big5,
+}
+
+impl TextEncoding {
+    fn encoding(self) -> &'static Encoding {
+        match self {
+            TextEncoding::Utf8 => UTF_8,
+            TextEncoding::Utf16Le => UTF_16LE,
+            TextEncoding::Utf16Be => UTF_16BE,
+            TextEncoding::Big5 => BIG5,
+        }
+    }
+
+    /// 判斷編碼：先看 BOM，再看是否為沒有 BOM 的 UTF-16（英文字母間夾著 0），
+    /// 接著是合法的 UTF-8，最後是能完整解碼的 Big5；都不符合時視為 UTF-8
+    pub fn detect(bytes: &[u8]) -> Self {
+        if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+            return match encoding.name() {
+                "UTF-16LE" => TextEncoding::Utf16Le,
+                "UTF-16BE" => TextEncoding::Utf16Be,
+                _ => TextEncoding::Utf8,
+            };
+        }
+
+        let sample = &bytes[..bytes.len().min(SNIFF_BYTES)];
+        let zeros_at = |parity: usize| sample.iter().skip(parity).step_by(2).filter(|b| **b == 0).count();
+        let pairs = sample.len() / 2;
+        let (even, odd) = (zeros_at(0), zeros_at(1));
+        if pairs > 0 && odd * 4 >= pairs && even == 0 {
+            return TextEncoding::Utf16Le;
+        }
+        if pairs > 0 && even * 4 >= pairs && odd == 0 {
+            return TextEncoding::Utf16Be;
+        }
+
+        if std::str::from_utf8(bytes).is_ok() {
+            return TextEncoding::Utf8;
+        }
+        if BIG5.decode_without_bom_handling_and_without_replacement(bytes).is_some() {
+            return TextEncoding::Big5;
+        }
+        TextEncoding::Utf8
+    }
+
+    /// 解碼並移除 BOM，回傳文字與是否有無法解碼、以替代字元取代的位元組
+    pub fn decode(self, bytes: &[u8]) -> (String, bool) {
+        let (text, had_errors) = self.encoding().decode_with_bom_removal(bytes);
+        (text.into_owned(), had_errors)
+    }
+}
+
+/// 欄位對應到的單字卡欄位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnField {
+    Word,
+    PartOfSpeech,
+    /// 中文解釋或英文定義
+    Definition,
+    /// 例句，一格中有多行時每行一個例句
+    Example,
+    /// 以逗號、分號或頓號分隔
+    Synonyms,
+    Antonyms,
+    Ipa,
+    Ignore,
+}
+
+impl ColumnField {
+    /// 由標題列的欄位名稱判斷；無法辨識時回傳 `None`
+    pub fn from_header(name: &str) -> Option<Self> {
+        let name = name
+            .chars()
+            .filter(|c| !(c.is_whitespace() || matches!(c, '_' | '-' | '.')))
+            .collect::<String>()
+            .to_lowercase();
+        let field = match name.as_str() {
+            "word" | "words" | "term" | "vocabulary" | "vocab" | "headword" | "english" | "單字" | "單詞" | "单词"
+            | "英文" => ColumnField::Word,
+            "pos" | "partofspeech" | "詞性" | "词性" => ColumnField::PartOfSpeech,
+            "definition" | "meaning" | "gloss" | "translation" | "chinese" | "中文" | "解釋" | "中文解釋" | "释义"
+            | "意思" | "定義" | "翻譯" => ColumnField::Definition,
+            "example" | "examples" | "sentence" | "例句" => ColumnField::Example,
+            "synonym" | "synonyms" | "同義詞" | "同義字" => ColumnField::Synonyms,
+            "antonym" | "antonyms" | "反義詞" | "反義字" => ColumnField::Antonyms,
+            "ipa" | "pronunciation" | "phonetic" | "kk" | "音標" => ColumnField::Ipa,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    /// 每份對應最多只能有一欄的欄位
+    fn is_single(self) -> bool {
+        matches!(self, ColumnField::Word | ColumnField::PartOfSpeech | ColumnField::Definition | ColumnField::Ipa)
+    }
+}
+
+/// 解析設定，`None` 代表自動判斷
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetSettings {
+    pub encoding: Option<TextEncoding>,
+    /// 只接受 ASCII 字元
+    pub delimiter: Option<u8>,
+    pub has_header: Option<bool>,
+    /// 依欄位順序對應；超出的欄位略過
+    pub mapping: Option<Vec<ColumnField>>,
+}
+
+/// 一列資料
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpreadsheetRow {
+    /// 在檔案中的行號（從 1 開始）
+    pub line: usize,
+    /// 去除頭尾空白的欄位值
+    pub values: Vec<String>,
+}
+
+/// 解析後的試算表
+#[derive(Debug, Clone)]
+pub struct Spreadsheet {
+    pub encoding: TextEncoding,
+    /// 是否有無法解碼的位元組
+    pub lossy: bool,
+    pub delimiter: u8,
+    /// 標題列；沒有標題列時為 `None`
+    pub headers: Option<Vec<String>>,
+    pub mapping: Vec<ColumnField>,
+    /// 最多的欄位數
+    pub column_count: usize,
+    /// 資料列（不含標題列與空白列）
+    pub rows: Vec<SpreadsheetRow>,
+}
+
+impl Spreadsheet {
+    /// 解碼並解析 CSV/TSV；`settings` 未指定的項目自動判斷
+    ///
+    /// 未指定標題列時，第一列能辨識出單字欄位才視為標題列；未指定對應時，
+    /// 有標題列則依欄位名稱對應，否則第一欄為單字，再依內容找出詞性欄，其餘欄位依序為定義與例句。
+    pub fn parse(bytes: &[u8], settings: &SpreadsheetSettings) -> Result<Self, String> {
+        let encoding = settings.encoding.unwrap_or_else(|| TextEncoding::detect(bytes));
+        let (text, lossy) = encoding.decode(bytes);
+        let delimiter = settings.delimiter.unwrap_or_else(|| detect_delimiter(&text));
+        let mut rows = read_rows(&text, delimiter)?;
+        let column_count = rows.iter().map(|row| row.values.len()).max().unwrap_or_default();
+
+        let header_fields = rows.first().map(|row| {
+            row.values.iter().map(|value| ColumnField::from_header(value)).collect::<Vec<_>>()
+        });
+        let recognized = header_fields
+            .as_ref()
+            .is_some_and(|fields| fields.contains(&Some(ColumnField::Word)));
+        let has_header = settings.has_header.unwrap_or(recognized) && !rows.is_empty();
+        let headers = has_header.then(|| rows.remove(0).values);
+
+        let mapping = match &settings.mapping {
+            Some(mapping) => mapping.clone(),
+            None if has_header && recognized => header_fields
+                .unwrap_or_default()
+                .into_iter()
+                .map(|field| field.unwrap_or(ColumnField::Ignore))
+                .collect(),
+            None => guess_mapping(&rows, column_count),
+        };
+        validate_mapping(&mapping)?;
+
+        Ok(Self { encoding, lossy, delimiter, headers, mapping, column_count, rows })
+    }
+
+    /// 單字欄的值，空白時為 `None`
+    pub fn word<'a>(&self, row: &'a SpreadsheetRow) -> Option<&'a str> {
+        self.mapping
+            .iter()
+            .zip(&row.values)
+            .find(|(field, value)| **field == ColumnField::Word && !value.is_empty())
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 依欄位對應將一列轉為單字卡，並驗證內容；見 `build_card`
+    pub fn card(&self, row: &SpreadsheetRow) -> Result<NewWordCard, String> {
+        build_card(&self.mapping, &row.values)
+    }
+}
+
+/// 依欄位對應將一列的值轉為單字卡，並驗證內容
+///
+/// 每一列是一個詞義；對應中有定義欄時定義不能為空。
+pub fn build_card(mapping: &[ColumnField], values: &[String]) -> Result<NewWordCard, String> {
+    let mut word = "";
+    let mut sense = Sense::default();
+    let mut ipa = None;
+    for (field, value) in mapping.iter().zip(values) {
+        if value.is_empty() {
+            continue;
+        }
+        match field {
+            ColumnField::Word => word = value,
+            ColumnField::PartOfSpeech => sense.part_of_speech = value.clone(),
+            ColumnField::Definition => sense.definition = value.clone(),
+            ColumnField::Example => sense.examples.extend(value.lines().map(String::from)),
+            ColumnField::Synonyms => sense.synonyms.extend(split_list(value)),
+            ColumnField::Antonyms => sense.antonyms.extend(split_list(value)),
+            ColumnField::Ipa => ipa = Some(value.clone()),
+            ColumnField::Ignore => {}
+        }
+    }
+
+    if word.is_empty() {
+        return Err("缺少單字".to_string());
+    }
+    if sense.definition.is_empty() && mapping.contains(&ColumnField::Definition) {
+        return Err(format!("\"{}\" 缺少定義", word));
+    }
+
+    let pos = Some(sense.part_of_speech.clone()).filter(|pos| !pos.is_empty());
+    let pronunciations = ipa
+        .map(|ipa| Pronunciation { ipa: Some(ipa), ..Pronunciation::default() })
+        .into_iter()
+        .collect();
+    NewWordCard {
+        word: word.to_string(),
+        pos,
+        definition: None,
+        verbs: None,
+        familiarity: Some(0),
+        seen_count: Some(0),
+        word_key: None,
+        senses: vec![sense],
+        pronunciations,
+    }
+    .normalized()
+}
+
+/// 找出在取樣的各行中出現次數最一致的分隔符號；都沒有出現時為逗號
+fn detect_delimiter(text: &str) -> u8 {
+    let lines = text.lines().filter(|line| !line.trim().is_empty()).take(SNIFF_LINES).collect::<Vec<_>>();
+    let mut best = (0, b',');
+    for candidate in DELIMITER_CANDIDATES {
+        let counts = lines.iter().map(|line| count_unquoted(line, candidate)).collect::<Vec<_>>();
+        let Some(&first) = counts.first() else { break };
+        if first == 0 {
+            continue;
+        }
+        let consistent = counts.iter().filter(|&&count| count == first).count();
+        if consistent > best.0 {
+            best = (consistent, candidate);
+        }
+    }
+    best.1
+}
+
+/// 計算引號外的分隔符號數量
+fn count_unquoted(line: &str, delimiter: u8) -> usize {
+    let mut quoted = false;
+    let mut count = 0;
+    for byte in line.bytes() {
+        if byte == b'"' {
+            quoted = !quoted;
+        } else if byte == delimiter && !quoted {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// 讀取所有非空白的列
+fn read_rows(text: &str, delimiter: u8) -> Result<Vec<SpreadsheetRow>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| format!("無法解析檔案: {}", e))?;
+        let values = record.iter().map(|value| value.trim().to_string()).collect::<Vec<_>>();
+        if values.iter().all(String::is_empty) {
+            continue;
+        }
+        let line = record.position().map_or(rows.len() + 1, |position| position.line() as usize);
+        rows.push(SpreadsheetRow { line, values });
+    }
+    Ok(rows)
+}
+
+/// 沒有標題列時的欄位對應
+fn guess_mapping(rows: &[SpreadsheetRow], column_count: usize) -> Vec<ColumnField> {
+    let mut mapping = vec![ColumnField::Word];
+    let mut remaining = [ColumnField::Definition, ColumnField::Example].into_iter();
+    let mut has_pos = false;
+    for column in 1..column_count {
+        if !has_pos && is_part_of_speech_column(rows, column) {
+            has_pos = true;
+            mapping.push(ColumnField::PartOfSpeech);
+        } else {
+            mapping.push(remaining.next().unwrap_or(ColumnField::Ignore));
+        }
+    }
+    mapping
+}
+
+fn is_part_of_speech_column(rows: &[SpreadsheetRow], column: usize) -> bool {
+    let values = rows
+        .iter()
+        .filter_map(|row| row.values.get(column))
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>();
+    let matched = values.iter().filter(|value| is_part_of_speech(value)).count();
+    !values.is_empty() && matched as f64 >= values.len() as f64 * PART_OF_SPEECH_RATIO
+}
+
+/// 是否為詞性，可用 `/`、`,`、`&` 或空白連接多個詞性（如 "n./v."）
+fn is_part_of_speech(value: &str) -> bool {
+    let lowered = value.to_lowercase();
+    let mut parts = lowered
+        .split(['/', ',', '&', ' ', '、'])
+        .map(|part| part.trim().trim_end_matches('.'))
+        .filter(|part| !part.is_empty())
+        .peekable();
+    parts.peek().is_some() && parts.all(|part| PARTS_OF_SPEECH.contains(&part))
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value.split([',', ';', '、', '，', '；']).map(|item| item.trim().to_string()).collect()
+}
+
+/// 對應必須有單字欄，且單字、詞性、定義、音標各最多一欄
+pub fn validate_mapping(mapping: &[ColumnField]) -> Result<(), String> {
+    if !mapping.contains(&ColumnField::Word) {
+        return Err("欄位對應中必須有單字欄".to_string());
+    }
+    for field in mapping.iter().filter(|field| field.is_single()) {
+        if mapping.iter().filter(|other| *other == field).count() > 1 {
+            return Err(format!("欄位對應中只能有一個 {:?} 欄", field));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(bytes: &[u8]) -> Spreadsheet {
+        Spreadsheet::parse(bytes, &SpreadsheetSettings::default()).unwrap()
+    }
+
+    #[test]
+    fn test_detects_encoding_delimiter_and_header() {
+        let text = "單字\t詞性\t中文\t例句\napple\tn.\t蘋果\tAn apple a day.\nrun\tv.\t跑, 經營\tI \"run\" daily.\n";
+        let utf16 = [&[0xFF, 0xFE][..], &text.encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<_>>()].concat();
+        let (big5, _, _) = BIG5.encode(text);
+
+        for bytes in [text.as_bytes(), &utf16, &big5] {
+            let sheet = parse(bytes);
+            assert_eq!(sheet.delimiter, b'\t');
+            assert!(!sheet.lossy);
+            assert_eq!(sheet.headers.as_ref().unwrap()[2], "中文");
+            assert_eq!(
+                sheet.mapping,
+                [ColumnField::Word, ColumnField::PartOfSpeech, ColumnField::Definition, ColumnField::Example]
+            );
+            assert_eq!(sheet.rows.len(), 2);
+            assert_eq!(sheet.rows[1].line, 3);
+        }
+        assert_eq!(parse(&utf16).encoding, TextEncoding::Utf16Le);
+        assert_eq!(parse(&big5).encoding, TextEncoding::Big5);
+
+        let card = parse(text.as_bytes()).card(&parse(text.as_bytes()).rows[1]).unwrap();
+        assert_eq!(card.senses[0].part_of_speech, "v.");
+        assert_eq!(card.senses[0].definition, "跑, 經營");
+        assert_eq!(card.senses[0].examples, ["I \"run\" daily."]);
+        assert_eq!(card.pos.as_deref(), Some("v."));
+    }
+
+    #[test]
+    fn test_guesses_mapping_without_header() {
+        let sheet = parse(b"apple,noun,\"a fruit, red or green\"\nrun,v./n.,to move fast\n,adj,missing word\n");
+        assert!(sheet.headers.is_none());
+        assert_eq!(sheet.mapping, [ColumnField::Word, ColumnField::PartOfSpeech, ColumnField::Definition]);
+        assert_eq!(sheet.card(&sheet.rows[0]).unwrap().senses[0].definition, "a fruit, red or green");
+        assert_eq!(sheet.card(&sheet.rows[2]).unwrap_err(), "缺少單字");
+
+        let sheet = parse("apple;蘋果\nbed;床\n".as_bytes());
+        assert_eq!(sheet.delimiter, b';');
+        assert_eq!(sheet.mapping, [ColumnField::Word, ColumnField::Definition]);
+    }
+
+    #[test]
+    fn test_explicit_settings_and_invalid_mapping() {
+        let settings = SpreadsheetSettings {
+            has_header: Some(false),
+            mapping: Some(vec![ColumnField::Ignore, ColumnField::Word, ColumnField::Definition, ColumnField::Ipa]),
+            ..SpreadsheetSettings::default()
+        };
+        let sheet = Spreadsheet::parse(b"1,word,definition,ipa\n2,cat,,/kaet/\n3,dog,a pet,/dog/\n", &settings).unwrap();
+        assert_eq!(sheet.rows.len(), 3);
+        assert_eq!(sheet.card(&sheet.rows[1]).unwrap_err(), "\"cat\" 缺少定義");
+        let dog = sheet.card(&sheet.rows[2]).unwrap();
+        assert_eq!(dog.pronunciations[0].ipa.as_deref(), Some("dog"));
+
+        let settings = SpreadsheetSettings {
+            mapping: Some(vec![ColumnField::Definition, ColumnField::Definition]),
+            ..SpreadsheetSettings::default()
+        };
+        assert!(Spreadsheet::parse(b"a,b\n", &settings).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::value_objects::word::{normalize_text, Word};
+
+/// 一行最多幾個字時視為單字或片語清單中的一筆
+pub const MAX_PHRASE_WORDS: usize = 4;
+
+/// 從文字中找到的單字或片語
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VocabularyCandidate {
+    /// 小寫的單字或片語
+    pub word: String,
+    /// 在文字中出現的次數
+    pub count: i32,
+}
+
+/// 擷取單字的結果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VocabularyExtraction {
+    /// 依出現次數由多到少排序，次數相同時依字母排序
+    pub candidates: Vec<VocabularyCandidate>,
+    /// 非空白的行數
+    pub line_count: usize,
+    /// 擷取到的單字與片語總數（含重複）
+    pub token_count: usize,
+}
+
+/// 從單字清單或一般文章中擷取單字與片語，並計算出現次數
+///
+/// - 每一行先去除清單符號（`-`、`*`、`•`、`1.`、`2)`）；不超過 `MAX_PHRASE_WORDS` 個字、
+///   且沒有句中標點的行視為單字清單中的一筆，片語（如 "give up"）整行保留
+/// - 其他行視為文章，依標點與空白切成單字：保留連字號複合字（well-known），
+///   行尾以連字號斷開的字與下一行接回，縮寫與所有格還原為原本的單字（don't → do、teacher's → teacher）
+/// - 不含字母或含有數字的字、文章中只有一個字母的字都會略過
+pub fn extract_vocabulary(text: &str) -> VocabularyExtraction {
+    let mut counts: HashMap<String, i32> = HashMap::new();
+    let mut extraction = VocabularyExtraction::default();
+
+    for line in join_hyphenated_lines(text) {
+        let line = normalize_text(&line.replace('\u{00AD}', ""));
+        if line.is_empty() {
+            continue;
+        }
+        extraction.line_count += 1;
+
+        let line = strip_list_marker(&line);
+        let tokens = match as_entry(line) {
+            Some(entry) => vec![entry],
+            None => tokenize(line),
+        };
+        for token in tokens {
+            let Ok(word) = Word::parse(&token) else { continue };
+            extraction.token_count += 1;
+            let count = counts.entry(word.key().to_string()).or_insert(0);
+            *count = count.saturating_add(1);
+        }
+    }
+
+    let mut candidates = counts
+        .into_iter()
+        .map(|(word, count)| VocabularyCandidate { word, count })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    extraction.candidates = candidates;
+    extraction
+}
+
+/// 將行尾以連字號斷開的字與下一行接回，例如 "exam-" + "ple" → "example"
+fn join_hyphenated_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut pending: Option<String> = None;
+
+    for line in text.lines() {
+        let line = match pending.take() {
+            Some(mut head) => {
+                let rest = line.trim_start();
+                let (first, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                head.push_str(first);
+                if !tail.is_empty() {
+                    head.push(' ');
+                    head.push_str(tail);
+                }
+                head
+            }
+            None => line.to_string(),
+        };
+
+        let trimmed = line.trim_end();
+        let continues = trimmed
+            .strip_suffix('-')
+            .filter(|head| !head.ends_with('-'))
+            .and_then(|head| head.chars().last())
+            .is_some_and(char::is_alphabetic);
+        // 整行只有一個字時是清單中的項目（如 "well-"），不與下一行相接
+        if continues && trimmed.contains(char::is_whitespace) {
+            pending = Some(trimmed[..trimmed.len() - 1].to_string());
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.extend(pending);
+    lines
+}
+
+/// 去除行首的清單符號與編號
+fn strip_list_marker(line: &str) -> &str {
+    let rest = line.trim_start_matches(['-', '*', '•', '·']).trim_start();
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        if let Some(after) = rest[digits..].strip_prefix(['.', ')']) {
+            return after.trim_start();
+        }
+    }
+    rest
+}
+
+/// 不超過 `MAX_PHRASE_WORDS` 個字、且只有字母與字內的連字號、撇號時，整行視為一筆單字或片語
+fn as_entry(line: &str) -> Option<String> {
+    let line = line.trim_end_matches([',', ';']).trim();
+    let words = line.split(' ').collect::<Vec<_>>();
+    if words.is_empty() || words.len() > MAX_PHRASE_WORDS {
+        return None;
+    }
+    let is_word = |word: &str| {
+        !word.is_empty()
+            && word.chars().all(|c| c.is_alphabetic() || c == '-' || c == '\'')
+            && word.starts_with(char::is_alphabetic)
+            && word.ends_with(char::is_alphabetic)
+    };
+    words.iter().all(|word| is_word(word)).then(|| line.to_lowercase())
+}
+
+/// 將文章切成單字
+fn tokenize(line: &str) -> Vec<String> {
+    line.split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '\''))
+        .flat_map(|chunk| chunk.split("--"))
+        .filter_map(|raw| {
+            let token = raw.trim_matches(['-', '\'']).to_lowercase();
+            let token = expand_contraction(&token);
+            let letters = token.chars().filter(|c| c.is_alphabetic()).count();
+            let valid = letters > 1 && !token.chars().any(|c| c.is_numeric());
+            valid.then_some(token)
+        })
+        .collect()
+}
+
+/// 縮寫與所有格還原為原本的單字
+fn expand_contraction(token: &str) -> String {
+    match token {
+        "can't" => return "can".to_string(),
+        "won't" => return "will".to_string(),
+        "shan't" => return "shall".to_string(),
+        _ => {}
+    }
+    for suffix in ["n't", "'s", "'re", "'ve", "'ll", "'d", "'m"] {
+        if let Some(stem) = token.strip_suffix(suffix).filter(|stem| !stem.is_empty()) {
+            return stem.to_string();
+        }
+    }
+    token.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(extraction: &VocabularyExtraction) -> Vec<(&str, i32)> {
+        extraction.candidates.iter().map(|c| (c.word.as_str(), c.count)).collect()
+    }
+
+    #[test]
+    fn test_word_list_keeps_phrases_and_counts_repeats() {
+        let text = "apple\nBed\n\n  apple \n- give up\n1. Apple,\n2) look forward to\nbed\n";
+        let extraction = extract_vocabulary(text);
+        assert_eq!(counts(&extraction), [("apple", 3), ("bed", 2), ("give up", 1), ("look forward to", 1)]);
+        assert_eq!(extraction.line_count, 7);
+        assert_eq!(extraction.token_count, 7);
+    }
+
+    #[test]
+    fn test_prose_handles_punctuation_contractions_and_hyphens() {
+        let text = "The teacher's well-known book—don't you think?\n\
+                    It's a \u{201C}well-known\u{201D} fact: students' exam-\nples vary (in 2024).";
+        let extraction = extract_vocabulary(text);
+        let words = counts(&extraction);
+
+        assert!(words.contains(&("well-known", 2)));
+        assert!(words.contains(&("teacher", 1)));
+        assert!(words.contains(&("do", 1)));
+        assert!(words.contains(&("it", 1)));
+        assert!(words.contains(&("students", 1)));
+        assert!(words.contains(&("examples", 1)));
+        assert!(!words.iter().any(|(w, _)| *w == "a" || w.contains("2024") || w.contains('\'')));
+    }
+
+    #[test]
+    fn test_large_repetitive_lists_are_counted_once_per_line() {
+        let text = "apple\nbed\ncool\n".repeat(10_000);
+        let extraction = extract_vocabulary(&text);
+        assert_eq!(counts(&extraction), [("apple", 10_000), ("bed", 10_000), ("cool", 10_000)]);
+    }
+}
@@ -1 +1,5 @@
-// Value objects will be defined here
+pub mod pronunciation;
+pub mod review;
+pub mod tag;
+pub mod word;
+pub mod word_forms;
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+/// IPA 的最大長度（字元數）
+pub const MAX_IPA_LENGTH: usize = 100;
+
+/// 音檔網址與本機路徑的最大長度（字元數）
+pub const MAX_LOCATION_LENGTH: usize = 2048;
+
+/// 發音的地區
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Region {
+    Uk,
+    Us,
+}
+
+impl Region {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Region::Uk => "uk",
+            Region::Us => "us",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "uk" | "gb" | "en-gb" => Some(Region::Uk),
+            "us" | "en-us" => Some(Region::Us),
+            _ => None,
+        }
+    }
+
+    /// 由音檔網址推斷地區，例如 dictionaryapi.dev 的 `hello-uk.mp3`
+    pub fn from_audio_url(url: &str) -> Option<Self> {
+        let file = url.rsplit('/').next()?;
+        let stem = file.split_once('.').map_or(file, |(stem, _)| stem).to_lowercase();
+        if stem.ends_with("-uk") {
+            Some(Region::Uk)
+        } else if stem.ends_with("-us") {
+            Some(Region::Us)
+        } else {
+            None
+        }
+    }
+}
+
+/// 單字的一種發音
+///
+/// 由前端傳入時必須先經過 `validated` 驗證；至少要有 IPA、音檔網址或本機音檔其中一項。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Pronunciation {
+    pub region: Option<Region>,
+    /// 不含外圍的 `/…/` 或 `[…]`
+    pub ipa: Option<String>,
+    pub audio_url: Option<String>,
+    /// 已下載到本機的音檔路徑
+    pub local_path: Option<String>,
+}
+
+impl Pronunciation {
+    /// 驗證並正規化：去除頭尾空白、空字串視為未提供、IPA 去除外圍的斜線或方括號
+    pub fn validated(self) -> Result<Self, String> {
+        fn present(value: Option<String>) -> Option<String> {
+            value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+        }
+
+        let ipa = present(self.ipa).map(|ipa| {
+            ipa.trim_start_matches(['/', '['])
+                .trim_end_matches(['/', ']'])
+                .trim()
+                .to_string()
+        });
+        let ipa = ipa.filter(|ipa| !ipa.is_empty());
+        let audio_url = present(self.audio_url);
+        let local_path = present(self.local_path);
+
+        if ipa.is_none() && audio_url.is_none() && local_path.is_none() {
+            return Err("發音至少需要 IPA、音檔網址或本機音檔其中一項".to_string());
+        }
+        if let Some(ipa) = &ipa {
+            if ipa.chars().count() > MAX_IPA_LENGTH {
+                return Err(format!("IPA 長度不能超過 {} 個字元", MAX_IPA_LENGTH));
+            }
+            if ipa.chars().any(char::is_control) {
+                return Err("IPA 不能包含控制字元".to_string());
+            }
+        }
+        if let Some(url) = &audio_url {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err(format!("音檔網址必須以 http:// 或 https:// 開頭: {}", url));
+            }
+            if url.chars().count() > MAX_LOCATION_LENGTH || url.chars().any(char::is_whitespace) {
+                return Err(format!("音檔網址不正確: {}", url));
+            }
+        }
+        if let Some(path) = &local_path {
+            if path.chars().count() > MAX_LOCATION_LENGTH || path.chars().any(char::is_control) {
+                return Err(format!("本機音檔路徑不正確: {}", path));
+            }
+        }
+
+        let region = self
+            .region
+            .or_else(|| audio_url.as_deref().and_then(Region::from_audio_url));
+        Ok(Self { region, ipa, audio_url, local_path })
+    }
+}
+
+/// 驗證多個發音，並移除重複的項目
+pub fn validate_pronunciations(pronunciations: Vec<Pronunciation>) -> Result<Vec<Pronunciation>, String> {
+    let mut validated: Vec<Pronunciation> = Vec::new();
+    for pronunciation in pronunciations {
+        let pronunciation = pronunciation.validated()?;
+        if !validated.contains(&pronunciation) {
+            validated.push(pronunciation);
+        }
+    }
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validated_normalizes_fields() {
+        let pronunciation = Pronunciation {
+            region: None,
+            ipa: Some(" /həˈləʊ/ ".to_string()),
+            audio_url: Some("https://api.dictionaryapi.dev/media/pronunciations/en/hello-uk.mp3".to_string()),
+            local_path: Some("  ".to_string()),
+        }
+        .validated()
+        .unwrap();
+
+        assert_eq!(pronunciation.ipa.as_deref(), Some("həˈləʊ"));
+        assert_eq!(pronunciation.region, Some(Region::Uk));
+        assert_eq!(pronunciation.local_path, None);
+    }
+
+    #[test]
+    fn test_invalid_pronunciations_are_rejected() {
+        assert!(Pronunciation::default().validated().is_err());
+        assert!(Pronunciation {
+            audio_url: Some("file:///etc/passwd".to_string()),
+            ..Pronunciation::default()
+        }
+        .validated()
+        .is_err());
+        assert!(Pronunciation {
+            ipa: Some("/a\u{0}b/".to_string()),
+            ..Pronunciation::default()
+        }
+        .validated()
+        .is_err());
+    }
+
+    #[test]
+    fn test_duplicates_are_removed() {
+        let us = Pronunciation {
+            region: Some(Region::Us),
+            ipa: Some("/həˈloʊ/".to_string()),
+            ..Pronunciation::default()
+        };
+        let validated = validate_pronunciations(vec![us.clone(), us]).unwrap();
+        assert_eq!(validated.len(), 1);
+        assert_eq!(Region::parse("en-US"), Some(Region::Us));
+    }
+}
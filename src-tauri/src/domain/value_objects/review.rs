@@ -0,0 +1,124 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// 資料庫中時間欄位的格式，與 SQLite `CURRENT_TIMESTAMP` 相同（UTC）
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// SM-2 的初始難易度係數
+pub const DEFAULT_EASE_FACTOR: f64 = 2.5;
+
+/// 複習時的作答評分，數值與熟悉度 0～3 對應
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewGrade {
+    /// 完全忘記
+    Again = 0,
+    /// 想起來但很吃力
+    Hard = 1,
+    /// 正常想起
+    Good = 2,
+    /// 毫不費力
+    Easy = 3,
+}
+
+impl ReviewGrade {
+    pub fn as_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl TryFrom<i32> for ReviewGrade {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ReviewGrade::Again),
+            1 => Ok(ReviewGrade::Hard),
+            2 => Ok(ReviewGrade::Good),
+            3 => Ok(ReviewGrade::Easy),
+            _ => Err(format!("評分必須在 0-3 之間，收到: {}", value)),
+        }
+    }
+}
+
+/// 排程演算法種類，儲存於設定檔中
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchedulerKind {
+    Sm2,
+    Fsrs,
+}
+
+impl SchedulerKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SchedulerKind::Sm2 => "sm2",
+            SchedulerKind::Fsrs => "fsrs",
+        }
+    }
+}
+
+impl std::str::FromStr for SchedulerKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sm2" => Ok(SchedulerKind::Sm2),
+            "fsrs" => Ok(SchedulerKind::Fsrs),
+            _ => Err(format!("未知的排程演算法: {}", value)),
+        }
+    }
+}
+
+/// 單字卡的排程狀態
+///
+/// 同時保存 SM-2（ease_factor、interval_days、repetitions）與
+/// FSRS（stability、difficulty）所需的欄位，切換演算法時不會遺失資料。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewState {
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    /// 下次複習時間；`None` 代表尚未複習過的新卡片
+    pub due_at: Option<NaiveDateTime>,
+    pub stability: Option<f64>,
+    pub difficulty: Option<f64>,
+    pub last_reviewed_at: Option<NaiveDateTime>,
+    /// 舊版手動設定的熟悉度（0～3），供尚無排程紀錄的卡片推估初始狀態
+    pub familiarity: i32,
+    /// 目前所在的學習步驟；`None` 代表不在學習或重新學習階段
+    #[serde(default)]
+    pub learning_step: Option<i32>,
+}
+
+impl Default for ReviewState {
+    fn default() -> Self {
+        Self {
+            ease_factor: DEFAULT_EASE_FACTOR,
+            interval_days: 0,
+            repetitions: 0,
+            due_at: None,
+            stability: None,
+            difficulty: None,
+            last_reviewed_at: None,
+            familiarity: 0,
+            learning_step: None,
+        }
+    }
+}
+
+impl ReviewState {
+    pub fn is_new(&self) -> bool {
+        self.due_at.is_none()
+    }
+}
+
+/// 將時間格式化為資料庫儲存格式
+pub fn format_timestamp(time: &NaiveDateTime) -> String {
+    time.format(TIMESTAMP_FORMAT).to_string()
+}
+
+/// 解析資料庫中的時間字串
+pub fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, TIMESTAMP_FORMAT).ok()
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+
+use super::word::normalize_text;
+
+/// 標籤名稱的最大長度（字元數）
+pub const MAX_TAG_NAME_LENGTH: usize = 50;
+
+/// 標籤篩選條件的最大巢狀層數
+pub const MAX_TAG_EXPRESSION_DEPTH: usize = 8;
+
+/// 標籤篩選條件的最大節點數
+pub const MAX_TAG_EXPRESSION_NODES: usize = 100;
+
+/// 經過正規化的標籤名稱
+///
+/// 與單字相同的正規化規則：`text` 保留大小寫供顯示，`key` 轉為小寫供唯一索引使用，
+/// 因此 "Travel" 與 "travel " 視為同一個標籤。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TagName {
+    text: String,
+    key: String,
+}
+
+impl TagName {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let text = normalize_text(raw);
+
+        if text.is_empty() {
+            return Err("標籤名稱不能為空".to_string());
+        }
+        if text.chars().count() > MAX_TAG_NAME_LENGTH {
+            return Err(format!("標籤名稱長度不能超過 {} 個字元", MAX_TAG_NAME_LENGTH));
+        }
+        if text.chars().any(char::is_control) {
+            return Err("標籤名稱不能包含控制字元".to_string());
+        }
+
+        let key = text.to_lowercase();
+        Ok(Self { text, key })
+    }
+
+    /// 顯示用的名稱
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// 唯一索引用的正規化 key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+/// 以標籤篩選單字卡的條件，可任意組合 AND / OR / NOT
+///
+/// 序列化為 `{ "tag": 1 }`、`{ "and": [...] }`、`{ "or": [...] }`、`{ "not": {...} }`。
+/// 空的 `and` 視為成立、空的 `or` 視為不成立。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagExpression {
+    /// 單字卡有此 ID 的標籤
+    Tag(i32),
+    And(Vec<TagExpression>),
+    Or(Vec<TagExpression>),
+    Not(Box<TagExpression>),
+}
+
+impl TagExpression {
+    /// 檢查標籤 ID 與條件的大小，避免產生過於複雜的查詢
+    pub fn validate(&self) -> Result<(), String> {
+        let mut nodes = 0;
+        self.validate_node(1, &mut nodes)
+    }
+
+    fn validate_node(&self, depth: usize, nodes: &mut usize) -> Result<(), String> {
+        *nodes += 1;
+        if depth > MAX_TAG_EXPRESSION_DEPTH {
+            return Err(format!("標籤篩選條件不能超過 {} 層", MAX_TAG_EXPRESSION_DEPTH));
+        }
+        if *nodes > MAX_TAG_EXPRESSION_NODES {
+            return Err(format!("標籤篩選條件不能超過 {} 個項目", MAX_TAG_EXPRESSION_NODES));
+        }
+
+        match self {
+            TagExpression::Tag(tag_id) if *tag_id <= 0 => Err("標籤 ID 必須大於 0".to_string()),
+            TagExpression::Tag(_) => Ok(()),
+            TagExpression::And(children) | TagExpression::Or(children) => children
+                .iter()
+                .try_for_each(|child| child.validate_node(depth + 1, nodes)),
+            TagExpression::Not(child) => child.validate_node(depth + 1, nodes),
+        }
+    }
+
+    /// 依單字卡擁有的標籤判斷是否符合條件
+    pub fn matches(&self, has_tag: &dyn Fn(i32) -> bool) -> bool {
+        match self {
+            TagExpression::Tag(tag_id) => has_tag(*tag_id),
+            TagExpression::And(children) => children.iter().all(|child| child.matches(has_tag)),
+            TagExpression::Or(children) => children.iter().any(|child| child.matches(has_tag)),
+            TagExpression::Not(child) => !child.matches(has_tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_names_share_key_regardless_of_case() {
+        let name = TagName::parse("  Travel   Words ").unwrap();
+        assert_eq!(name.as_str(), "Travel Words");
+        assert_eq!(name.key(), TagName::parse("travel words").unwrap().key());
+        assert!(TagName::parse(" ").is_err());
+        assert!(TagName::parse(&"x".repeat(MAX_TAG_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_expression_deserializes_and_matches() {
+        let expression: TagExpression =
+            serde_json::from_str(r#"{"and":[{"tag":1},{"or":[{"tag":2},{"tag":3}]},{"not":{"tag":4}}]}"#).unwrap();
+        let card = |tags: &'static [i32]| move |tag_id: i32| tags.contains(&tag_id);
+
+        assert!(expression.matches(&card(&[1, 3])));
+        assert!(!expression.matches(&card(&[1, 3, 4])));
+        assert!(!expression.matches(&card(&[2, 3])));
+        assert!(TagExpression::And(Vec::new()).matches(&card(&[])));
+        assert!(!TagExpression::Or(Vec::new()).matches(&card(&[1])));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_ids_and_deep_nesting() {
+        assert!(TagExpression::Or(vec![TagExpression::Tag(1), TagExpression::Tag(2)]).validate().is_ok());
+        assert!(TagExpression::Tag(0).validate().is_err());
+
+        let mut deep = TagExpression::Tag(1);
+        for _ in 0..MAX_TAG_EXPRESSION_DEPTH {
+            deep = TagExpression::Not(Box::new(deep));
+        }
+        assert!(deep.validate().is_err());
+
+        let wide = TagExpression::Or((1..=MAX_TAG_EXPRESSION_NODES as i32).map(TagExpression::Tag).collect());
+        assert!(wide.validate().is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// 單字的最大長度（字元數）
+pub const MAX_WORD_LENGTH: usize = 100;
+
+/// 經過正規化的單字
+///
+/// - `text` 為顯示用的形式：Unicode NFC、彎引號轉為直引號、去除頭尾空白並將內部連續空白合併為一個空格，保留大小寫
+/// - `key` 為唯一索引用的形式：`text` 再做 Unicode 大小寫摺疊（見 `fold_key`）
+///
+/// 因此 "Apple"、"apple " 與 "apple" 會得到相同的 key，"Straße" 與 "STRASSE" 也相同。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Word {
+    text: String,
+    key: String,
+}
+
+impl Word {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let text = normalize_text(raw);
+
+        if text.is_empty() {
+            return Err("單字不能為空".to_string());
+        }
+        if text.chars().count() > MAX_WORD_LENGTH {
+            return Err(format!("單字長度不能超過 {} 個字元", MAX_WORD_LENGTH));
+        }
+        if text.chars().any(char::is_control) {
+            return Err("單字不能包含控制字元".to_string());
+        }
+
+        let key = fold_case(&text);
+        Ok(Self { text, key })
+    }
+
+    /// 顯示用的單字
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// 唯一索引用的正規化 key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl std::fmt::Display for Word {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// 將輸入字串轉為 key；無法成為合法單字時回傳 `None`
+pub fn word_key(raw: &str) -> Option<String> {
+    Word::parse(raw).ok().map(|w| w.key)
+}
+
+/// 產生唯一索引用的 key：正規化文字後做大小寫摺疊，不檢查是否為合法單字
+///
+/// `Word::key` 與無法解析為單字的舊資料、匯入項目都使用此函式，確保比對規則一致。
+pub fn fold_key(raw: &str) -> String {
+    fold_case(&normalize_text(raw))
+}
+
+/// Unicode 大小寫摺疊：轉為小寫，並將 "ß"、詞尾的 "ς"、長 s 與拉丁連字展開為摺疊後的形式
+fn fold_case(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'ß' | 'ẞ' => folded.push_str("ss"),
+            'ς' => folded.push('σ'),
+            'ſ' => folded.push('s'),
+            'ﬀ' => folded.push_str("ff"),
+            'ﬁ' => folded.push_str("fi"),
+            'ﬂ' => folded.push_str("fl"),
+            'ﬃ' => folded.push_str("ffi"),
+            'ﬄ' => folded.push_str("ffl"),
+            'ﬅ' | 'ﬆ' => folded.push_str("st"),
+            _ => folded.extend(c.to_lowercase()),
+        }
+    }
+    folded.nfc().collect()
+}
+
+pub(crate) fn normalize_text(raw: &str) -> String {
+    let replaced = raw.nfc().map(|c| match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' | '\u{FF07}' => '\'',
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '\u{FF02}' => '"',
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' => '-',
+        _ => c,
+    });
+
+    let mut text = String::with_capacity(raw.len());
+    for c in replaced {
+        if c.is_whitespace() {
+            if !text.is_empty() && !text.ends_with(' ') {
+                text.push(' ');
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    if text.ends_with(' ') {
+        text.pop();
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_and_whitespace_share_key() {
+        let keys = ["Apple", "apple ", "  apple", "APPLE"]
+            .iter()
+            .map(|raw| Word::parse(raw).unwrap().key().to_string())
+            .collect::<Vec<_>>();
+        assert!(keys.iter().all(|k| k == "apple"));
+    }
+
+    #[test]
+    fn test_display_form_keeps_case() {
+        let word = Word::parse("  New   York\t").unwrap();
+        assert_eq!(word.as_str(), "New York");
+        assert_eq!(word.key(), "new york");
+    }
+
+    #[test]
+    fn test_smart_quotes_are_straightened() {
+        assert_eq!(Word::parse("don\u{2019}t").unwrap().key(), "don't");
+        assert_eq!(Word::parse("\u{201C}hi\u{201D}").unwrap().as_str(), "\"hi\"");
+    }
+
+    #[test]
+    fn test_key_is_case_folded() {
+        assert_eq!(Word::parse("Straße").unwrap().key(), "strasse");
+        assert_eq!(Word::parse("STRASSE").unwrap().key(), "strasse");
+        assert_eq!(Word::parse("ΟΔΟΣ").unwrap().key(), Word::parse("οδος").unwrap().key());
+        assert_eq!(Word::parse("\u{FB01}nd").unwrap().key(), "find");
+    }
+
+    #[test]
+    fn test_fold_key_matches_word_key_for_invalid_words() {
+        assert_eq!(fold_key("  Don\u{2019}t   Stop "), Word::parse("don't stop").unwrap().key());
+        // 超過長度上限的文字無法成為單字，但 key 的規則相同
+        let long = format!("{}ß", "A".repeat(MAX_WORD_LENGTH));
+        assert!(Word::parse(&long).is_err());
+        assert_eq!(fold_key(&long), format!("{}ss", "a".repeat(MAX_WORD_LENGTH)));
+    }
+
+    #[test]
+    fn test_unicode_is_nfc_normalized() {
+        // "e" + 組合用尖音符 與 預組字元 "é" 應相同
+        let decomposed = Word::parse("cafe\u{0301}").unwrap();
+        let composed = Word::parse("caf\u{00E9}").unwrap();
+        assert_eq!(decomposed, composed);
+    }
+
+    #[test]
+    fn test_invalid_words_are_rejected() {
+        assert!(Word::parse("   ").is_err());
+        assert!(Word::parse(&"a".repeat(MAX_WORD_LENGTH + 1)).is_err());
+        assert!(Word::parse("a\u{0007}b").is_err());
+    }
+}
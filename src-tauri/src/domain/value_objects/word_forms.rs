@@ -0,0 +1,77 @@
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::sqlite::{Sqlite, SqliteValue};
+use serde::{Deserialize, Serialize};
+
+/// 單字的詞形變化，儲存在 `word_cards.verbs` 欄位
+///
+/// 依詞性只會填入適用的欄位：動詞的時態、名詞的複數、形容詞與副詞的比較級。
+/// 資料庫中以 JSON 文字儲存，未填入的欄位不會寫出。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+#[serde(default)]
+pub struct WordForms {
+    /// 過去式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub past: Option<String>,
+    /// 過去分詞
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub past_participle: Option<String>,
+    /// 第三人稱單數現在式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_person: Option<String>,
+    /// 現在分詞（-ing）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub present_participle: Option<String>,
+    /// 複數
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plural: Option<String>,
+    /// 比較級
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparative: Option<String>,
+    /// 最高級
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub superlative: Option<String>,
+}
+
+impl WordForms {
+    /// 是否沒有任何詞形變化
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl FromSql<Text, Sqlite> for WordForms {
+    /// 舊版存放的 `"{}"` 或無法解析的文字一律視為沒有詞形變化，不讓單筆資料影響整個查詢
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let text = <String as FromSql<Text, Sqlite>>::from_sql(value)?;
+        Ok(serde_json::from_str(&text).unwrap_or_default())
+    }
+}
+
+impl ToSql<Text, Sqlite> for WordForms {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(serde_json::to_string(self)?);
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_only_present_forms() {
+        let forms = WordForms {
+            plural: Some("apples".to_string()),
+            ..WordForms::default()
+        };
+        let json = serde_json::to_string(&forms).unwrap();
+
+        assert_eq!(json, r#"{"plural":"apples"}"#);
+        assert_eq!(serde_json::from_str::<WordForms>(&json).unwrap(), forms);
+        assert!(serde_json::from_str::<WordForms>("{}").unwrap().is_empty());
+    }
+}
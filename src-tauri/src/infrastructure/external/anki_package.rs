@@ -0,0 +1,311 @@
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Integer, Text};
+use diesel::sqlite::SqliteConnection;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::domain::services::anki::{
+    AnkiCard, AnkiCollection, AnkiNote, AnkiNoteType, AnkiPackageReader, AnkiReview,
+};
+
+/// 套件中收藏資料庫的檔名，依優先順序排列；`collection.anki21` 為 2.1 版的舊格式
+const COLLECTION_ENTRIES: [&str; 2] = ["collection.anki21", "collection.anki2"];
+
+/// Anki 2.1.50 之後預設的 zstd 壓縮格式；有此檔時 `collection.anki2` 只是提示升級的空殼
+const COMPRESSED_COLLECTION_ENTRY: &str = "collection.anki21b";
+
+/// 筆記欄位之間的分隔字元
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// 讀取 Anki 匯出的 .apkg 與 .colpkg（zip 內含 SQLite 收藏資料庫）
+///
+/// 收藏資料庫會先解壓縮到暫存檔再以唯讀方式查詢，讀取完畢後刪除。
+#[derive(Default)]
+pub struct AnkiPackageFile;
+
+impl AnkiPackageFile {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AnkiPackageReader for AnkiPackageFile {
+    fn read(&self, path: &str) -> Result<AnkiCollection, String> {
+        let mut file = File::open(path).map_err(|e| format!("無法開啟檔案: {}", e))?;
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher).map_err(|e| format!("讀取檔案失敗: {}", e))?;
+        let content_hash = format!("{:x}", hasher.finalize());
+
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("不是有效的 Anki 套件: {}", e))?;
+        let has_entry = |archive: &zip::ZipArchive<File>, name: &str| archive.file_names().any(|entry| entry == name);
+        let entry = if has_entry(&archive, COLLECTION_ENTRIES[0]) {
+            COLLECTION_ENTRIES[0]
+        } else if has_entry(&archive, COMPRESSED_COLLECTION_ENTRY) {
+            return Err("不支援新版 Anki 的壓縮格式，請在 Anki 匯出時勾選「支援舊版 Anki」".to_string());
+        } else if has_entry(&archive, COLLECTION_ENTRIES[1]) {
+            COLLECTION_ENTRIES[1]
+        } else {
+            return Err("套件中找不到收藏資料庫 collection.anki2".to_string());
+        };
+
+        let database = TempDatabase::new();
+        {
+            let mut source = archive.by_name(entry).map_err(|e| format!("無法讀取 {}: {}", entry, e))?;
+            let mut target = File::create(&database.path).map_err(|e| format!("無法建立暫存檔: {}", e))?;
+            io::copy(&mut source, &mut target).map_err(|e| format!("解壓縮 {} 失敗: {}", entry, e))?;
+        }
+
+        let url = database.path.to_string_lossy().into_owned();
+        let mut connection = SqliteConnection::establish(&url).map_err(|e| format!("無法開啟收藏資料庫: {}", e))?;
+        let mut collection = read_collection(&mut connection).map_err(|e| format!("讀取收藏資料庫失敗: {}", e))?;
+        collection.content_hash = content_hash;
+        Ok(collection)
+    }
+}
+
+/// 解壓縮出的暫存資料庫，離開作用域時刪除
+struct TempDatabase {
+    path: PathBuf,
+}
+
+impl TempDatabase {
+    fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let name = format!("anki-import-{}-{}.sqlite", std::process::id(), COUNTER.fetch_add(1, Ordering::SeqCst));
+        Self { path: std::env::temp_dir().join(name) }
+    }
+}
+
+impl Drop for TempDatabase {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[derive(QueryableByName)]
+struct CollectionRow {
+    #[diesel(sql_type = BigInt)]
+    crt: i64,
+    #[diesel(sql_type = Text)]
+    models: String,
+}
+
+#[derive(QueryableByName)]
+struct NoteTypeRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = Text)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct FieldRow {
+    #[diesel(sql_type = BigInt)]
+    ntid: i64,
+    #[diesel(sql_type = Text)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct NoteRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = BigInt)]
+    mid: i64,
+    #[diesel(sql_type = Text)]
+    flds: String,
+}
+
+#[derive(QueryableByName)]
+struct CardRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = BigInt)]
+    nid: i64,
+    #[diesel(sql_type = Integer)]
+    ord: i32,
+    #[diesel(sql_type = Integer)]
+    card_type: i32,
+    #[diesel(sql_type = Integer)]
+    queue: i32,
+    #[diesel(sql_type = BigInt)]
+    due: i64,
+    #[diesel(sql_type = Integer)]
+    ivl: i32,
+    #[diesel(sql_type = Integer)]
+    factor: i32,
+    #[diesel(sql_type = Integer)]
+    reps: i32,
+    #[diesel(sql_type = Integer)]
+    lapses: i32,
+}
+
+#[derive(QueryableByName)]
+struct ReviewRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = BigInt)]
+    cid: i64,
+    #[diesel(sql_type = Integer)]
+    ease: i32,
+    #[diesel(sql_type = Integer)]
+    ivl: i32,
+    #[diesel(sql_type = Integer)]
+    factor: i32,
+    #[diesel(sql_type = Integer)]
+    time: i32,
+    #[diesel(sql_type = Integer)]
+    review_type: i32,
+}
+
+fn read_collection(connection: &mut SqliteConnection) -> QueryResult<AnkiCollection> {
+    let collection = diesel::sql_query("SELECT crt, models FROM col LIMIT 1").get_result::<CollectionRow>(connection)?;
+    let note_types = match parse_models(&collection.models) {
+        note_types if !note_types.is_empty() => note_types,
+        _ => read_note_types(connection)?,
+    };
+
+    let notes = diesel::sql_query("SELECT id, mid, flds FROM notes ORDER BY id")
+        .load::<NoteRow>(connection)?
+        .into_iter()
+        .map(|row| AnkiNote {
+            id: row.id,
+            note_type_id: row.mid,
+            fields: row.flds.split(FIELD_SEPARATOR).map(String::from).collect(),
+        })
+        .collect();
+    let cards = diesel::sql_query(
+        "SELECT id, nid, ord, type AS card_type, queue, due, ivl, factor, reps, lapses FROM cards ORDER BY id",
+    )
+    .load::<CardRow>(connection)?
+    .into_iter()
+    .map(|row| AnkiCard {
+        id: row.id,
+        note_id: row.nid,
+        ord: row.ord,
+        card_type: row.card_type,
+        queue: row.queue,
+        due: row.due,
+        interval: row.ivl,
+        factor: row.factor,
+        reps: row.reps,
+        lapses: row.lapses,
+    })
+    .collect();
+    let reviews = diesel::sql_query("SELECT id, cid, ease, ivl, factor, time, type AS review_type FROM revlog ORDER BY id")
+        .load::<ReviewRow>(connection)?
+        .into_iter()
+        .map(|row| AnkiReview {
+            id: row.id,
+            card_id: row.cid,
+            ease: row.ease,
+            interval: row.ivl,
+            factor: row.factor,
+            duration_ms: row.time,
+            kind: row.review_type,
+        })
+        .collect();
+
+    Ok(AnkiCollection { created_at: collection.crt, note_types, notes, cards, reviews, content_hash: String::new() })
+}
+
+/// 舊格式把筆記類型以 JSON 存在 `col.models`：`{ "<id>": { "name": ..., "flds": [{ "name": ..., "ord": 0 }] } }`
+fn parse_models(models: &str) -> Vec<AnkiNoteType> {
+    let Ok(serde_json::Value::Object(models)) = serde_json::from_str::<serde_json::Value>(models) else {
+        return Vec::new();
+    };
+    let mut note_types = models
+        .iter()
+        .filter_map(|(key, model)| {
+            let id = model.get("id").and_then(|id| id.as_i64()).or_else(|| key.parse().ok())?;
+            let name = model.get("name").and_then(|name| name.as_str()).unwrap_or_default().to_string();
+            let mut fields = model
+                .get("flds")?
+                .as_array()?
+                .iter()
+                .filter_map(|field| {
+                    let ord = field.get("ord").and_then(|ord| ord.as_i64()).unwrap_or_default();
+                    Some((ord, field.get("name")?.as_str()?.to_string()))
+                })
+                .collect::<Vec<_>>();
+            fields.sort_by_key(|(ord, _)| *ord);
+            Some(AnkiNoteType { id, name, fields: fields.into_iter().map(|(_, name)| name).collect() })
+        })
+        .collect::<Vec<_>>();
+    note_types.sort_by_key(|note_type| note_type.id);
+    note_types
+}
+
+/// 新格式把筆記類型存在 `notetypes` 與 `fields` 資料表
+fn read_note_types(connection: &mut SqliteConnection) -> QueryResult<Vec<AnkiNoteType>> {
+    let mut fields: HashMap<i64, Vec<String>> = HashMap::new();
+    for field in diesel::sql_query("SELECT ntid, name FROM fields ORDER BY ntid, ord").load::<FieldRow>(connection)? {
+        fields.entry(field.ntid).or_default().push(field.name);
+    }
+    Ok(diesel::sql_query("SELECT id, name FROM notetypes ORDER BY id")
+        .load::<NoteTypeRow>(connection)?
+        .into_iter()
+        .map(|row| AnkiNoteType { fields: fields.remove(&row.id).unwrap_or_default(), id: row.id, name: row.name })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// 建立只含測試所需欄位的舊格式套件
+    fn write_package(path: &std::path::Path, entry: &str) {
+        let database = TempDatabase::new();
+        let mut connection = SqliteConnection::establish(&database.path.to_string_lossy()).unwrap();
+        let models = r#"{"1700": {"id": 1700, "name": "Basic", "flds": [{"name": "Back", "ord": 1}, {"name": "Front", "ord": 0}]}}"#;
+        for statement in [
+            "CREATE TABLE col (crt INTEGER, models TEXT)".to_string(),
+            "CREATE TABLE notes (id INTEGER, mid INTEGER, flds TEXT)".to_string(),
+            "CREATE TABLE cards (id INTEGER, nid INTEGER, ord INTEGER, type INTEGER, queue INTEGER, due INTEGER, \
+             ivl INTEGER, factor INTEGER, reps INTEGER, lapses INTEGER)"
+                .to_string(),
+            "CREATE TABLE revlog (id INTEGER, cid INTEGER, ease INTEGER, ivl INTEGER, factor INTEGER, time INTEGER, \
+             type INTEGER)"
+                .to_string(),
+            format!("INSERT INTO col VALUES (1704067200, '{}')", models),
+            "INSERT INTO notes VALUES (10, 1700, 'apple\u{1f}<b>蘋果</b>')".to_string(),
+            "INSERT INTO cards VALUES (20, 10, 0, 2, 2, 30, 12, 2500, 4, 0)".to_string(),
+            "INSERT INTO revlog VALUES (1704153600000, 20, 3, 12, 2500, 5000, 1)".to_string(),
+        ] {
+            diesel::sql_query(statement).execute(&mut connection).unwrap();
+        }
+        drop(connection);
+
+        let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+        zip.start_file(entry, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(&fs::read(&database.path).unwrap()).unwrap();
+        zip.start_file("media", zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"{}").unwrap();
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn test_reads_legacy_package() {
+        let path = std::env::temp_dir().join(format!("anki-test-{}.apkg", std::process::id()));
+        write_package(&path, "collection.anki2");
+        let collection = AnkiPackageFile::new().read(&path.to_string_lossy());
+        write_package(&path, "collection.anki21b");
+        let compressed = AnkiPackageFile::new().read(&path.to_string_lossy());
+        fs::remove_file(&path).unwrap();
+
+        let collection = collection.unwrap();
+        assert_eq!(collection.created_at, 1_704_067_200);
+        assert_eq!(collection.note_types[0].fields, ["Front", "Back"]);
+        assert_eq!(collection.notes[0].fields, ["apple", "<b>蘋果</b>"]);
+        assert_eq!((collection.cards[0].card_type, collection.cards[0].interval), (2, 12));
+        assert_eq!((collection.reviews[0].ease, collection.reviews[0].duration_ms), (3, 5000));
+        assert_eq!(collection.content_hash.len(), 64);
+        assert!(compressed.unwrap_err().contains("舊版"));
+        assert!(AnkiPackageFile::new().read("/nonexistent/deck.apkg").is_err());
+    }
+}
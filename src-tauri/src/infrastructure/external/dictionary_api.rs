@@ -0,0 +1,184 @@
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::word_cards::NewWordCard;
+use crate::domain::services::dictionary::Dictionary;
+use crate::domain::value_objects::pronunciation::Pronunciation;
+
+/// Free Dictionary API 的查詢網址，單字接在最後
+const DICTIONARY_API_URL: &str = "https://api.dictionaryapi.dev/api/v2/entries/en/";
+
+/// 單次查詢的逾時時間
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct RawEntry {
+    word: String,
+    phonetic: Option<String>,
+    #[serde(default)]
+    phonetics: Vec<RawPhonetic>,
+    #[serde(default)]
+    meanings: Vec<RawMeaning>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawPhonetic {
+    text: Option<String>,
+    audio: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMeaning {
+    part_of_speech: String,
+    #[serde(default)]
+    definitions: Vec<RawDefinition>,
+    #[serde(default)]
+    synonyms: Vec<String>,
+    #[serde(default)]
+    antonyms: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDefinition {
+    definition: String,
+    example: Option<String>,
+    #[serde(default)]
+    synonyms: Vec<String>,
+    #[serde(default)]
+    antonyms: Vec<String>,
+}
+
+/// 透過 dictionaryapi.dev 查詢單字，與前端查詢字典時使用同一個服務
+pub struct DictionaryApiClient {
+    agent: ureq::Agent,
+    base_url: String,
+}
+
+impl DictionaryApiClient {
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build(),
+            base_url: DICTIONARY_API_URL.to_string(),
+        }
+    }
+}
+
+impl Default for DictionaryApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dictionary for DictionaryApiClient {
+    fn lookup(&self, word: &str) -> Result<Option<NewWordCard>, String> {
+        let url = format!("{}{}", self.base_url, encode_path_segment(word));
+        let body = match self.agent.get(&url).call() {
+            Ok(response) => response.into_string().map_err(|e| format!("讀取字典回應失敗: {}", e))?,
+            // 查無此字時 API 回傳 404
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(ureq::Error::Status(status, _)) => return Err(format!("字典服務回應錯誤: HTTP {}", status)),
+            Err(e) => return Err(format!("無法連線到字典服務: {}", e)),
+        };
+        parse_entries(&body)
+    }
+}
+
+/// 將單字編碼為網址的一段路徑；英數字以外的字元都以 `%XX` 表示
+fn encode_path_segment(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// 解析 API 回應，只取第一筆最常用的詞條
+///
+/// 對應方式與前端保存字典詞條時相同：每個定義為一個詞義，定義層級沒有同義詞、反義詞時使用詞性層級的。
+fn parse_entries(body: &str) -> Result<Option<NewWordCard>, String> {
+    let entries: Vec<RawEntry> = match serde_json::from_str(body) {
+        Ok(entries) => entries,
+        // 查無此字時回傳的是物件而不是陣列
+        Err(_) if body.trim_start().starts_with('{') => return Ok(None),
+        Err(e) => return Err(format!("字典回應格式不正確: {}", e)),
+    };
+    let Some(entry) = entries.into_iter().next() else { return Ok(None) };
+
+    let part_of_speech = entry.meanings.iter().map(|m| m.part_of_speech.clone()).collect::<Vec<_>>();
+    let senses = entry
+        .meanings
+        .iter()
+        .flat_map(|meaning| {
+            meaning.definitions.iter().map(move |definition| Sense {
+                part_of_speech: meaning.part_of_speech.clone(),
+                definition: definition.definition.clone(),
+                examples: definition.example.iter().cloned().collect(),
+                synonyms: if definition.synonyms.is_empty() { meaning.synonyms.clone() } else { definition.synonyms.clone() },
+                antonyms: if definition.antonyms.is_empty() { meaning.antonyms.clone() } else { definition.antonyms.clone() },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let present = |value: &Option<String>| value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+    let ipa = present(&entry.phonetic).or_else(|| entry.phonetics.iter().find_map(|p| present(&p.text)));
+    let audio_url = entry.phonetics.iter().find_map(|p| present(&p.audio));
+    let pronunciations = if ipa.is_some() || audio_url.is_some() {
+        vec![Pronunciation { ipa, audio_url, ..Pronunciation::default() }]
+    } else {
+        Vec::new()
+    };
+
+    Ok(Some(NewWordCard {
+        word: entry.word,
+        pos: serde_json::to_string(&part_of_speech).ok(),
+        definition: None,
+        verbs: None,
+        familiarity: Some(0),
+        seen_count: Some(1),
+        word_key: None,
+        senses,
+        pronunciations,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_first_entry_into_senses_and_pronunciation() {
+        let body = r#"[{
+            "word": "hello",
+            "phonetic": "/həˈləʊ/",
+            "phonetics": [{"text": "/həˈləʊ/"}, {"audio": "https://api.dictionaryapi.dev/media/hello-uk.mp3"}],
+            "meanings": [
+                {"partOfSpeech": "noun", "definitions": [{"definition": "A greeting.", "example": "hello there"}], "synonyms": ["greeting"]},
+                {"partOfSpeech": "verb", "definitions": [{"definition": "To greet.", "synonyms": ["hail"]}]}
+            ]
+        }, {"word": "hello", "meanings": []}]"#;
+
+        let card = parse_entries(body).unwrap().unwrap();
+        assert_eq!(card.word, "hello");
+        assert_eq!(card.pos.as_deref(), Some(r#"["noun","verb"]"#));
+        assert_eq!(card.senses.len(), 2);
+        assert_eq!(card.senses[0].examples, ["hello there"]);
+        assert_eq!(card.senses[0].synonyms, ["greeting"]);
+        assert_eq!(card.senses[1].synonyms, ["hail"]);
+        assert_eq!(card.pronunciations[0].ipa.as_deref(), Some("/həˈləʊ/"));
+        assert!(card.pronunciations[0].audio_url.as_deref().unwrap().ends_with("hello-uk.mp3"));
+        assert!(card.normalized().is_ok());
+    }
+
+    #[test]
+    fn test_not_found_and_malformed_responses() {
+        let not_found = r#"{"title": "No Definitions Found", "message": "Sorry pal"}"#;
+        assert!(parse_entries(not_found).unwrap().is_none());
+        assert!(parse_entries("[]").unwrap().is_none());
+        assert!(parse_entries("<html>").is_err());
+        assert_eq!(encode_path_segment("ice cream"), "ice%20cream");
+        assert_eq!(encode_path_segment("café"), "caf%C3%A9");
+    }
+}
@@ -1 +1,3 @@
-// External service integrations will be defined here
+pub mod anki_package;
+pub mod dictionary_api;
+pub mod word_list;
@@ -0,0 +1,16 @@
+use std::sync::OnceLock;
+
+/// 內建英文字表，每行一個單字
+const ENGLISH_WORDS: &str = include_str!("../../../resources/english_words.txt");
+
+/// 取得內建英文字表（第一次呼叫時解析）
+pub fn english_words() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| {
+        ENGLISH_WORDS
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect()
+    })
+}
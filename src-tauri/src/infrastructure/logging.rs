@@ -186,7 +186,7 @@ pub fn cleanup_old_logs(keep_days: u64) -> Result<(), Box<dyn std::error::Error>
             let entry = entry?;
             let path = entry.path();
             
-            if path.is_file() && path.extension().map_or(false, |ext| ext == "log") {
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "log") {
                 if let Ok(metadata) = entry.metadata() {
                     if let Ok(modified) = metadata.modified() {
                         if modified < cutoff_time {
@@ -0,0 +1,145 @@
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Integer, Text};
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use std::collections::HashMap;
+
+use super::schema::decks;
+use crate::domain::aggregates::deck::{Deck, DeckCounts, DeckSettings};
+
+define_sql_function!(fn last_insert_rowid() -> Integer);
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = decks)]
+#[diesel(check_for_backend(Sqlite))]
+struct DeckRow {
+    id: Option<i32>,
+    name: String,
+    parent_id: Option<i32>,
+    new_cards_per_day: i32,
+    reviews_per_day: i32,
+    learning_steps: String,
+    relearning_steps: String,
+    created_at: Option<String>,
+}
+
+#[derive(Insertable, AsChangeset)]
+#[diesel(table_name = decks)]
+#[diesel(treat_none_as_null = true)]
+struct DeckChanges<'a> {
+    name: &'a str,
+    parent_id: Option<i32>,
+    new_cards_per_day: i32,
+    reviews_per_day: i32,
+    learning_steps: String,
+    relearning_steps: String,
+}
+
+impl From<DeckRow> for Deck {
+    /// 無法解析的學習步驟視為不使用學習步驟，不讓單筆資料影響整個查詢
+    fn from(row: DeckRow) -> Self {
+        let steps = |text: &str| serde_json::from_str::<Vec<i32>>(text).unwrap_or_default();
+        Deck {
+            id: row.id,
+            name: row.name,
+            parent_id: row.parent_id,
+            settings: DeckSettings {
+                new_cards_per_day: row.new_cards_per_day,
+                reviews_per_day: row.reviews_per_day,
+                learning_steps: steps(&row.learning_steps),
+                relearning_steps: steps(&row.relearning_steps),
+            },
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl<'a> From<&'a Deck> for DeckChanges<'a> {
+    fn from(deck: &'a Deck) -> Self {
+        let steps = |values: &[i32]| serde_json::to_string(values).unwrap_or_else(|_| "[]".to_string());
+        DeckChanges {
+            name: &deck.name,
+            parent_id: deck.parent_id,
+            new_cards_per_day: deck.settings.new_cards_per_day,
+            reviews_per_day: deck.settings.reviews_per_day,
+            learning_steps: steps(&deck.settings.learning_steps),
+            relearning_steps: steps(&deck.settings.relearning_steps),
+        }
+    }
+}
+
+/// 所有牌組，依 ID 排序
+pub fn load_decks(connection: &mut SqliteConnection) -> QueryResult<Vec<Deck>> {
+    Ok(decks::table
+        .order(decks::id.asc())
+        .select(DeckRow::as_select())
+        .load::<DeckRow>(connection)?
+        .into_iter()
+        .map(Deck::from)
+        .collect())
+}
+
+pub fn load_deck(connection: &mut SqliteConnection, deck_id: i32) -> QueryResult<Option<Deck>> {
+    Ok(decks::table
+        .filter(decks::id.eq(Some(deck_id)))
+        .select(DeckRow::as_select())
+        .first::<DeckRow>(connection)
+        .optional()?
+        .map(Deck::from))
+}
+
+/// 新增或更新牌組，回傳保存後的牌組；呼叫端負責包在交易中
+pub fn save_deck(connection: &mut SqliteConnection, deck: &Deck) -> QueryResult<Deck> {
+    let changes = DeckChanges::from(deck);
+    let deck_id = match deck.id {
+        Some(deck_id) => {
+            let updated = diesel::update(decks::table.filter(decks::id.eq(Some(deck_id))))
+                .set(&changes)
+                .execute(connection)?;
+            if updated == 0 {
+                return Err(diesel::result::Error::NotFound);
+            }
+            deck_id
+        }
+        None => {
+            diesel::insert_into(decks::table).values(&changes).execute(connection)?;
+            diesel::select(last_insert_rowid()).get_result::<i32>(connection)?
+        }
+    };
+    load_deck(connection, deck_id)?.ok_or(diesel::result::Error::NotFound)
+}
+
+#[derive(QueryableByName)]
+struct DeckCountRow {
+    #[diesel(sql_type = Integer)]
+    deck_id: i32,
+    #[diesel(sql_type = BigInt)]
+    new_count: i64,
+    #[diesel(sql_type = BigInt)]
+    learning_count: i64,
+    #[diesel(sql_type = BigInt)]
+    due_count: i64,
+}
+
+/// 每個牌組本身的新卡片、學習中與到期卡片數量；沒有卡片的牌組不會出現在結果中
+pub fn count_cards_by_deck(
+    connection: &mut SqliteConnection,
+    due_before: &str,
+) -> QueryResult<HashMap<i32, DeckCounts>> {
+    let rows = diesel::sql_query(
+        "SELECT deck_id, \
+                SUM(due_at IS NULL) AS new_count, \
+                SUM(learning_step IS NOT NULL) AS learning_count, \
+                SUM(learning_step IS NULL AND due_at IS NOT NULL AND due_at < ?) AS due_count \
+         FROM word_cards GROUP BY deck_id",
+    )
+    .bind::<Text, _>(due_before)
+    .load::<DeckCountRow>(connection)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let counts = DeckCounts { new: row.new_count, learning: row.learning_count, due: row.due_count };
+            (row.deck_id, counts)
+        })
+        .collect())
+}
@@ -0,0 +1,117 @@
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Double, Integer, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::domain::repositories::word_card_repository::{HIGHLIGHT_END, HIGHLIGHT_START};
+
+/// 摘要長度（以詞數計）
+const SNIPPET_TOKENS: i32 = 16;
+
+/// 全文檢索的單筆命中結果
+#[derive(QueryableByName, Debug, Clone)]
+pub struct FullTextHit {
+    #[diesel(sql_type = Integer)]
+    pub card_id: i32,
+    /// bm25 分數，數值越小越相關
+    #[diesel(sql_type = Double)]
+    pub score: f64,
+    #[diesel(sql_type = Text)]
+    pub snippet: String,
+}
+
+/// 將 `search_terms` 拆出的詞轉為 FTS5 查詢字串
+///
+/// 每個詞都會加上雙引號避免被解析為 FTS5 語法，並加上 `*` 做前綴比對，
+/// 多個詞之間為 AND 關係。
+pub fn build_match_query(terms: &[String]) -> String {
+    terms
+        .iter()
+        .map(|term| format!("\"{}\"*", term.replace('"', "")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 在 `word_cards_fts` 中檢索，單字欄位的權重高於定義
+pub fn search(
+    connection: &mut SqliteConnection,
+    match_query: &str,
+    limit: i64,
+) -> QueryResult<Vec<FullTextHit>> {
+    diesel::sql_query(format!(
+        "SELECT rowid AS card_id, \
+                bm25(word_cards_fts, 10.0, 1.0) AS score, \
+                snippet(word_cards_fts, 1, '{start}', '{end}', '…', {tokens}) AS snippet \
+         FROM word_cards_fts \
+         WHERE word_cards_fts MATCH ? \
+         ORDER BY score \
+         LIMIT ?",
+        start = HIGHLIGHT_START,
+        end = HIGHLIGHT_END,
+        tokens = SNIPPET_TOKENS,
+    ))
+    .bind::<Text, _>(match_query)
+    .bind::<BigInt, _>(limit)
+    .load::<FullTextHit>(connection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::word_card_repository::search_terms;
+    use crate::infrastructure::persistence::migrations::run_migrations;
+
+    fn match_query(input: &str) -> String {
+        build_match_query(&search_terms(input))
+    }
+
+    fn connection_with_cards() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        run_migrations(&mut conn).unwrap();
+        diesel::sql_query(
+            "INSERT INTO word_cards (word, definition) VALUES \
+             ('reap', '[verb] to cut and gather a crop\n例句: They reap the harvest in autumn.'), \
+             ('harvest', '[noun] the process of gathering in crops'), \
+             ('sow', '[verb] to plant seed')",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_build_match_query() {
+        assert_eq!(match_query("harv"), "\"harv\"*");
+        assert_eq!(match_query("  cut  crop "), "\"cut\"* \"crop\"*");
+        assert_eq!(match_query("don't"), "\"don't\"*");
+        assert_eq!(match_query("\"OR\" NEAR("), "\"OR\"* \"NEAR\"*");
+        assert!(search_terms(" ?! ").is_empty());
+    }
+
+    #[test]
+    fn test_prefix_search_ranks_word_matches_first() {
+        let mut conn = connection_with_cards();
+        let hits = search(&mut conn, &match_query("harv"), 10).unwrap();
+
+        assert_eq!(hits.len(), 2);
+        // 單字本身命中的 harvest 應排在例句命中的 reap 之前
+        assert_eq!(hits[0].card_id, 2);
+        assert_eq!(hits[1].card_id, 1);
+        assert!(hits[1].snippet.contains("<mark>harvest</mark>"));
+    }
+
+    #[test]
+    fn test_index_follows_updates_and_deletes() {
+        let mut conn = connection_with_cards();
+        diesel::sql_query("UPDATE word_cards SET definition = '[verb] to scatter seed' WHERE word = 'sow'")
+            .execute(&mut conn)
+            .unwrap();
+        diesel::sql_query("DELETE FROM word_cards WHERE word = 'harvest'")
+            .execute(&mut conn)
+            .unwrap();
+
+        let scatter = search(&mut conn, &match_query("scatter"), 10).unwrap();
+        assert_eq!(scatter.len(), 1);
+        assert!(search(&mut conn, &match_query("plant"), 10).unwrap().is_empty());
+        assert_eq!(search(&mut conn, &match_query("harvest"), 10).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel::sql_types::Integer;
+use diesel::sqlite::SqliteConnection;
+
+use super::schema::{card_sources, imports, word_cards};
+use crate::domain::entities::imports::{
+    CardSource, ImportOutcome, ImportRecord, ImportStatus, ImportUndoSummary, NewCardSource, NewImportRecord,
+};
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::value_objects::review::format_timestamp;
+
+define_sql_function!(fn last_insert_rowid() -> Integer);
+
+pub fn load_import(connection: &mut SqliteConnection, import_id: i32) -> QueryResult<Option<ImportRecord>> {
+    imports::table
+        .filter(imports::id.eq(Some(import_id)))
+        .select(ImportRecord::as_select())
+        .first::<ImportRecord>(connection)
+        .optional()
+}
+
+/// 所有匯入紀錄，新的在前
+pub fn load_imports(connection: &mut SqliteConnection) -> QueryResult<Vec<ImportRecord>> {
+    imports::table
+        .order(imports::id.desc())
+        .select(ImportRecord::as_select())
+        .load::<ImportRecord>(connection)
+}
+
+pub fn insert_import(connection: &mut SqliteConnection, record: &NewImportRecord) -> QueryResult<ImportRecord> {
+    diesel::insert_into(imports::table).values(record).execute(connection)?;
+    let import_id = diesel::select(last_insert_rowid()).get_result::<i32>(connection)?;
+    load_import(connection, import_id)?.ok_or(diesel::result::Error::NotFound)
+}
+
+/// 寫入匯入結束時的狀態與數量；紀錄不存在時回傳 `NotFound`
+pub fn finish_import(
+    connection: &mut SqliteConnection,
+    import_id: i32,
+    outcome: &ImportOutcome,
+) -> QueryResult<ImportRecord> {
+    let updated = diesel::update(imports::table.filter(imports::id.eq(Some(import_id))))
+        .set((
+            imports::status.eq(outcome.status.as_str()),
+            imports::success_count.eq(outcome.success_count),
+            imports::exists_count.eq(outcome.exists_count),
+            imports::failed_count.eq(outcome.failed_count),
+            imports::finished_at.eq(Some(format_timestamp(&Utc::now().naive_utc()))),
+        ))
+        .execute(connection)?;
+    if updated == 0 {
+        return Err(diesel::result::Error::NotFound);
+    }
+    load_import(connection, import_id)?.ok_or(diesel::result::Error::NotFound)
+}
+
+/// 新增單字卡來源，已有相同來源時累加出現次數，並將次數累加到單字卡上；呼叫端負責包在交易中
+pub fn upsert_card_source(connection: &mut SqliteConnection, source: &NewCardSource) -> QueryResult<CardSource> {
+    if source.occurrence_count != 0 {
+        diesel::update(word_cards::table.filter(word_cards::id.eq(source.card_id)))
+            .set(word_cards::occurrence_count.eq(word_cards::occurrence_count + source.occurrence_count))
+            .execute(connection)?;
+    }
+
+    let existing = card_sources::table
+        .filter(card_sources::card_id.eq(source.card_id))
+        .filter(card_sources::kind.eq(&source.kind))
+        .filter(card_sources::import_id.is(source.import_id))
+        .select(CardSource::as_select())
+        .first::<CardSource>(connection)
+        .optional()?;
+
+    let source_id = match existing {
+        Some(existing) => {
+            diesel::update(card_sources::table.filter(card_sources::id.eq(existing.id)))
+                .set((
+                    card_sources::created_card.eq(existing.created_card || source.created_card),
+                    card_sources::occurrence_count.eq(existing.occurrence_count.saturating_add(source.occurrence_count)),
+                ))
+                .execute(connection)?;
+            existing.id
+        }
+        None => {
+            diesel::insert_into(card_sources::table).values(source).execute(connection)?;
+            Some(diesel::select(last_insert_rowid()).get_result::<i32>(connection)?)
+        }
+    };
+
+    card_sources::table
+        .filter(card_sources::id.eq(source_id))
+        .select(CardSource::as_select())
+        .first::<CardSource>(connection)
+}
+
+pub fn load_card_sources(connection: &mut SqliteConnection, card_id: i32) -> QueryResult<Vec<CardSource>> {
+    card_sources::table
+        .filter(card_sources::card_id.eq(card_id))
+        .order(card_sources::id.asc())
+        .select(CardSource::as_select())
+        .load::<CardSource>(connection)
+}
+
+/// 匯入紀錄中的單字卡，依單字排序（不分大小寫）
+pub fn load_import_cards(
+    connection: &mut SqliteConnection,
+    import_id: i32,
+) -> QueryResult<Vec<(CardSource, WordCard)>> {
+    card_sources::table
+        .inner_join(word_cards::table)
+        .filter(card_sources::import_id.eq(Some(import_id)))
+        .order(word_cards::word_key.asc())
+        .select((CardSource::as_select(), WordCard::as_select()))
+        .load::<(CardSource, WordCard)>(connection)
+}
+
+/// 移除單字卡的所有來源；呼叫端負責包在交易中
+pub fn remove_card_sources(connection: &mut SqliteConnection, card_id: i32) -> QueryResult<usize> {
+    diesel::delete(card_sources::table.filter(card_sources::card_id.eq(card_id))).execute(connection)
+}
+
+/// 復原匯入；`delete_card` 負責刪除單字卡及其相關資料。呼叫端負責包在交易中
+pub fn undo_import(
+    connection: &mut SqliteConnection,
+    import_id: i32,
+    delete_card: &mut dyn FnMut(&mut SqliteConnection, i32) -> QueryResult<usize>,
+) -> QueryResult<ImportUndoSummary> {
+    let sources = card_sources::table
+        .filter(card_sources::import_id.eq(Some(import_id)))
+        .select(CardSource::as_select())
+        .load::<CardSource>(connection)?;
+    diesel::delete(card_sources::table.filter(card_sources::import_id.eq(Some(import_id)))).execute(connection)?;
+
+    let mut summary = ImportUndoSummary::default();
+    for source in sources {
+        let other_sources = card_sources::table
+            .filter(card_sources::card_id.eq(source.card_id))
+            .count()
+            .get_result::<i64>(connection)?;
+        if source.created_card && other_sources == 0 {
+            summary.deleted_count += delete_card(connection, source.card_id)?;
+            continue;
+        }
+
+        diesel::update(word_cards::table.filter(word_cards::id.eq(Some(source.card_id))))
+            .set(word_cards::occurrence_count.eq(diesel::dsl::sql::<Integer>(&format!(
+                "MAX(occurrence_count - {}, 0)",
+                source.occurrence_count
+            ))))
+            .execute(connection)?;
+        summary.updated_count += 1;
+    }
+
+    diesel::update(imports::table.filter(imports::id.eq(Some(import_id))))
+        .set(imports::status.eq(ImportStatus::Undone.as_str()))
+        .execute(connection)?;
+    Ok(summary)
+}
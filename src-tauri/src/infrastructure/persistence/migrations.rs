@@ -0,0 +1,462 @@
+use diesel::prelude::*;
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use diesel::migration::MigrationSource;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use log::{info, warn};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use super::schema::{pronunciations, review_logs, senses, word_cards};
+use super::DatabaseError;
+use crate::domain::entities::senses::{parse_legacy_definition, render_definition};
+use crate::domain::entities::word_cards::{word_forms, WordCard};
+use crate::domain::value_objects::word::{fold_key, Word};
+
+/// 編譯時嵌入 `migrations/` 目錄下的所有遷移，依版本號排序執行
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// 執行所有尚未套用的遷移
+///
+/// 版本記錄存放在 Diesel 的 `__diesel_schema_migrations` 表格中。
+/// 所有待執行的遷移會在同一個交易中套用，任何一步失敗都會整批回滾。
+/// 若資料庫中存在本程式不認得的版本（由較新版本的應用程式建立），
+/// 則回傳 `DatabaseError::SchemaTooNew`，不做任何修改。
+///
+/// SQL 遷移之後會在同一交易中執行無法以 SQL 表達的資料遷移
+/// （見 `backfill_word_keys`、`backfill_senses`、`backfill_word_forms`）。
+pub fn run_migrations(connection: &mut SqliteConnection) -> Result<(), DatabaseError> {
+    let known_versions = MigrationSource::<Sqlite>::migrations(&MIGRATIONS)
+        .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?
+        .iter()
+        .map(|m| m.name().version().to_string())
+        .collect::<Vec<_>>();
+
+    let applied_versions = connection
+        .applied_migrations()
+        .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+
+    let unknown_versions = applied_versions
+        .iter()
+        .filter(|v| !known_versions.contains(v))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !unknown_versions.is_empty() {
+        warn!("資料庫包含未知的遷移版本: {:?}", unknown_versions);
+        return Err(DatabaseError::SchemaTooNew(format!(
+            "database has migrations {} which this app version does not know about",
+            unknown_versions.join(", ")
+        )));
+    }
+
+    let applied = connection
+        .transaction::<_, Box<dyn std::error::Error + Send + Sync>, _>(|conn| {
+            let versions = conn
+                .run_pending_migrations(MIGRATIONS)?
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            backfill_word_keys(conn)?;
+            backfill_senses(conn)?;
+            backfill_word_forms(conn)?;
+            Ok(versions)
+        })
+        .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+    if applied.is_empty() {
+        info!("資料庫結構已是最新版本");
+    } else {
+        info!("已套用 {} 個資料庫遷移: {:?}", applied.len(), applied);
+    }
+    Ok(())
+}
+
+/// 為尚未有 `word_key` 的單字卡計算正規化 key，並合併 key 相同的重複卡片
+///
+/// 保留 ID 最小（或已有 key）的卡片，其餘卡片的內容與作答紀錄併入後刪除；
+/// 保留的卡片沒有發音時改用重複卡片的發音。
+/// 只處理 `word_key` 為 NULL 的資料，重複執行不會有任何影響。
+fn backfill_word_keys(connection: &mut SqliteConnection) -> QueryResult<()> {
+    let pending = word_cards::table
+        .filter(word_cards::word_key.is_null())
+        .count()
+        .get_result::<i64>(connection)?;
+    if pending == 0 {
+        return Ok(());
+    }
+
+    let cards = word_cards::table
+        .order(word_cards::id.asc())
+        .select(WordCard::as_select())
+        .load::<WordCard>(connection)?;
+
+    // key -> 保留的卡片；已有 key 的卡片優先
+    let mut survivors: HashMap<String, WordCard> = HashMap::new();
+    let mut duplicates: Vec<(String, WordCard)> = Vec::new();
+    let (keyed, unkeyed): (Vec<_>, Vec<_>) = cards.into_iter().partition(|c| c.word_key.is_some());
+
+    for card in keyed {
+        let key = card.word_key.clone().unwrap_or_default();
+        survivors.insert(key, card);
+    }
+    for mut card in unkeyed {
+        let (text, key) = match Word::parse(&card.word) {
+            Ok(word) => (word.as_str().to_string(), word.key().to_string()),
+            Err(_) => (card.word.clone(), fold_key(&card.word)),
+        };
+        match survivors.entry(key) {
+            Entry::Occupied(entry) => duplicates.push((entry.key().clone(), card)),
+            Entry::Vacant(entry) => {
+                card.word = text;
+                entry.insert(card);
+            }
+        }
+    }
+
+    for (key, duplicate) in &duplicates {
+        if let Some(survivor) = survivors.get_mut(key) {
+            info!("合併重複單字卡: '{}' -> '{}'", duplicate.word, survivor.word);
+            survivor.absorb(duplicate);
+
+            diesel::update(review_logs::table.filter(review_logs::card_id.nullable().eq(duplicate.id)))
+                .set(review_logs::card_id.eq(survivor.id.unwrap_or_default()))
+                .execute(connection)?;
+
+            let duplicate_pronunciations =
+                pronunciations::table.filter(pronunciations::card_id.nullable().eq(duplicate.id));
+            let survivor_has_pronunciations = diesel::select(diesel::dsl::exists(
+                pronunciations::table.filter(pronunciations::card_id.nullable().eq(survivor.id)),
+            ))
+            .get_result::<bool>(connection)?;
+            if survivor_has_pronunciations {
+                diesel::delete(duplicate_pronunciations).execute(connection)?;
+            } else {
+                diesel::update(duplicate_pronunciations)
+                    .set(pronunciations::card_id.eq(survivor.id.unwrap_or_default()))
+                    .execute(connection)?;
+            }
+            diesel::delete(word_cards::table.filter(word_cards::id.eq(duplicate.id)))
+                .execute(connection)?;
+        }
+    }
+
+    for (key, mut survivor) in survivors {
+        if survivor.word_key.is_some() && !duplicates.iter().any(|(k, _)| *k == key) {
+            continue;
+        }
+        survivor.word_key = Some(key);
+        diesel::update(word_cards::table.filter(word_cards::id.eq(survivor.id)))
+            .set(&survivor)
+            .execute(connection)?;
+    }
+
+    info!("已為 {} 張單字卡建立正規化 key，合併 {} 張重複卡片", pending, duplicates.len());
+    Ok(())
+}
+
+/// 將舊版以文字儲存的定義解析為結構化詞義
+///
+/// 只處理有定義但還沒有任何詞義的卡片，並把 `definition` 改寫為由詞義產生的檢索用文字；
+/// 重複執行不會有任何影響。
+fn backfill_senses(connection: &mut SqliteConnection) -> QueryResult<()> {
+    let pending = word_cards::table
+        .filter(word_cards::definition.is_not_null())
+        .filter(diesel::dsl::not(diesel::dsl::exists(
+            senses::table.filter(senses::card_id.nullable().eq(word_cards::id)),
+        )))
+        .select((word_cards::id, word_cards::definition))
+        .load::<(Option<i32>, Option<String>)>(connection)?;
+
+    let mut converted = 0;
+    for (card_id, definition) in pending {
+        let (Some(card_id), Some(definition)) = (card_id, definition) else { continue };
+        let parsed = parse_legacy_definition(&definition);
+        if parsed.is_empty() {
+            continue;
+        }
+
+        super::senses::replace_senses(connection, card_id, &parsed)?;
+        diesel::update(word_cards::table.filter(word_cards::id.eq(card_id)))
+            .set(word_cards::definition.eq(render_definition(&parsed)))
+            .execute(connection)?;
+        converted += 1;
+    }
+
+    if converted > 0 {
+        info!("已將 {} 張單字卡的定義轉換為結構化詞義", converted);
+    }
+    Ok(())
+}
+
+/// 為尚未有詞形變化的單字卡產生詞形變化
+///
+/// 舊版前端一律存入 `"{}"`；只處理 `verbs` 為 NULL 或 `"{}"` 的卡片，依單字與詞義的詞性產生。
+/// 沒有任何變化的卡片改為 NULL，重複執行不會有任何影響。
+fn backfill_word_forms(connection: &mut SqliteConnection) -> QueryResult<()> {
+    let pending = word_cards::table
+        .filter(word_cards::verbs.is_null().or(word_cards::verbs.eq("{}")))
+        .select((word_cards::id, word_cards::word, word_cards::verbs))
+        .load::<(Option<i32>, String, Option<String>)>(connection)?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let card_ids = pending.iter().filter_map(|(id, _, _)| *id).collect::<Vec<_>>();
+    let mut card_senses = super::senses::load_senses(connection, &card_ids)?;
+
+    let mut generated = 0;
+    for (card_id, word, legacy) in pending {
+        let Some(card_id) = card_id else { continue };
+        let forms = word_forms(&word, &card_senses.remove(&card_id).unwrap_or_default());
+        if forms.is_none() && legacy.is_none() {
+            continue;
+        }
+
+        diesel::update(word_cards::table.filter(word_cards::id.eq(card_id)))
+            .set(word_cards::verbs.eq(&forms))
+            .execute(connection)?;
+        if forms.is_some() {
+            generated += 1;
+        }
+    }
+
+    if generated > 0 {
+        info!("已為 {} 張單字卡產生詞形變化", generated);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").expect("in-memory database")
+    }
+
+    fn table_exists(conn: &mut SqliteConnection, name: &str) -> bool {
+        #[derive(QueryableByName)]
+        struct Count {
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            count: i64,
+        }
+
+        diesel::sql_query("SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind::<diesel::sql_types::Text, _>(name)
+            .get_result::<Count>(conn)
+            .map(|c| c.count > 0)
+            .unwrap_or(false)
+    }
+
+    /// 依序套用遷移，直到名稱含有 `name` 的遷移之前停止
+    fn migrate_until(conn: &mut SqliteConnection, name: &str) {
+        while conn.has_pending_migration(MIGRATIONS).unwrap() {
+            let next = MigrationSource::<Sqlite>::migrations(&MIGRATIONS)
+                .unwrap()
+                .into_iter()
+                .find(|m| !conn.applied_migrations().unwrap().contains(&m.name().version()))
+                .unwrap();
+            if next.name().to_string().contains(name) {
+                break;
+            }
+            conn.run_next_migration(MIGRATIONS).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_fresh_database_is_migrated() {
+        let mut conn = memory_connection();
+        run_migrations(&mut conn).unwrap();
+
+        assert!(table_exists(&mut conn, "word_cards"));
+        assert!(!conn.has_pending_migration(MIGRATIONS).unwrap());
+    }
+
+    #[test]
+    fn test_migrations_are_idempotent() {
+        let mut conn = memory_connection();
+        run_migrations(&mut conn).unwrap();
+        run_migrations(&mut conn).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_database_without_version_table() {
+        // 舊版程式直接以 CREATE TABLE 建表，沒有任何版本紀錄
+        let mut conn = memory_connection();
+        diesel::sql_query(
+            "CREATE TABLE word_cards (
+              id INTEGER PRIMARY KEY AUTOINCREMENT,
+              word TEXT NOT NULL UNIQUE,
+              pos TEXT,
+              definition TEXT,
+              pronunciation TEXT,
+              verbs TEXT,
+              familiarity INTEGER DEFAULT 0,
+              seen_count INTEGER DEFAULT 1,
+              created_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        diesel::sql_query("INSERT INTO word_cards (word) VALUES ('legacy')")
+            .execute(&mut conn)
+            .unwrap();
+
+        run_migrations(&mut conn).unwrap();
+
+        #[derive(QueryableByName)]
+        struct Word {
+            #[diesel(sql_type = diesel::sql_types::Text)]
+            word: String,
+        }
+        let rows = diesel::sql_query("SELECT word FROM word_cards")
+            .load::<Word>(&mut conn)
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].word, "legacy");
+    }
+
+    #[test]
+    fn test_legacy_duplicates_are_merged() {
+        let mut conn = memory_connection();
+        // 只套用新增 word_key 之前的遷移，模擬舊資料
+        migrate_until(&mut conn, "add_word_key");
+        diesel::sql_query(
+            "INSERT INTO word_cards (word, definition, seen_count, familiarity) VALUES \
+             ('Apple', NULL, 2, 1), ('apple ', '[noun] a fruit', 3, 2), ('pear', NULL, 1, 0)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        diesel::sql_query("INSERT INTO review_logs (card_id, grade, scheduler, previous_state, new_state) VALUES (2, 2, 'sm2', '{}', '{}')")
+            .execute(&mut conn)
+            .unwrap();
+
+        run_migrations(&mut conn).unwrap();
+
+        let cards = word_cards::table
+            .order(word_cards::id.asc())
+            .select(WordCard::as_select())
+            .load::<WordCard>(&mut conn)
+            .unwrap();
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].word, "Apple");
+        assert_eq!(cards[0].word_key.as_deref(), Some("apple"));
+        assert_eq!(cards[0].definition.as_deref(), Some("[noun] a fruit"));
+        assert_eq!(cards[0].seen_count, Some(5));
+        assert_eq!(cards[0].familiarity, Some(2));
+        assert_eq!(cards[1].word_key.as_deref(), Some("pear"));
+
+        let log_card_ids = review_logs::table
+            .select(review_logs::card_id)
+            .load::<i32>(&mut conn)
+            .unwrap();
+        assert_eq!(log_card_ids, vec![1]);
+    }
+
+    #[test]
+    fn test_lowercased_keys_are_refolded() {
+        let mut conn = memory_connection();
+        // 舊版以小寫產生 key，"Straße" 與 "strasse" 是兩張不同的單字卡
+        migrate_until(&mut conn, "fold_word_keys");
+        diesel::sql_query(
+            "INSERT INTO word_cards (word, seen_count, word_key) VALUES \
+             ('strasse', 1, 'strasse'), ('Straße', 2, 'straße'), ('apple', 1, 'apple')",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        run_migrations(&mut conn).unwrap();
+
+        let cards = word_cards::table
+            .order(word_cards::id.asc())
+            .select(WordCard::as_select())
+            .load::<WordCard>(&mut conn)
+            .unwrap();
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].word, "strasse");
+        assert_eq!(cards[0].word_key.as_deref(), Some("strasse"));
+        assert_eq!(cards[0].seen_count, Some(3));
+        assert_eq!(cards[1].word_key.as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn test_legacy_definitions_become_senses() {
+        let mut conn = memory_connection();
+        migrate_until(&mut conn, "create_senses");
+        diesel::sql_query(
+            "INSERT INTO word_cards (word, word_key, definition) VALUES \
+             ('set', 'set', '[verb] to put [something] down\n例句: Set it here.\n\n[noun] a group\n同義詞: group, collection'), \
+             ('blank', 'blank', NULL), ('2024', '2024', NULL)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        run_migrations(&mut conn).unwrap();
+        run_migrations(&mut conn).unwrap();
+
+        let loaded = super::super::senses::load_senses(&mut conn, &[1, 2]).unwrap();
+        let set = &loaded[&1];
+        assert_eq!(set.len(), 2);
+        assert_eq!(set[0].definition, "to put [something] down");
+        assert_eq!(set[0].examples, ["Set it here."]);
+        assert_eq!(set[1].synonyms, ["group", "collection"]);
+        assert!(!loaded.contains_key(&2));
+
+        // 詞形變化依詞義的詞性產生，無法變化的單字維持 NULL
+        let forms = word_cards::table
+            .order(word_cards::id.asc())
+            .select(word_cards::verbs)
+            .load::<Option<crate::domain::value_objects::word_forms::WordForms>>(&mut conn)
+            .unwrap();
+        let set = forms[0].as_ref().unwrap();
+        assert_eq!(set.past.as_deref(), Some("set"));
+        assert_eq!(set.plural.as_deref(), Some("sets"));
+        assert_eq!(set.comparative, None);
+        assert!(forms[1].is_some());
+        assert!(forms[2].is_none());
+    }
+
+    #[test]
+    fn test_legacy_pronunciation_json_becomes_rows() {
+        let mut conn = memory_connection();
+        migrate_until(&mut conn, "create_pronunciations");
+        diesel::sql_query(
+            "INSERT INTO word_cards (word, word_key, pronunciation) VALUES \
+             ('hello', 'hello', '{\"phonetic\":\"/həˈləʊ/\",\"audio\":\"https://example.com/hello-uk.mp3\"}'), \
+             ('broken', 'broken', 'not json'), \
+             ('empty', 'empty', '{\"phonetic\":\"\",\"audio\":\"\"}'), \
+             ('Hello ', NULL, '{\"phonetic\":\"həˈloʊ\",\"audio\":\"\"}')",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        run_migrations(&mut conn).unwrap();
+
+        let loaded = super::super::pronunciations::load_pronunciations(&mut conn, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(loaded.len(), 1);
+        let hello = &loaded[&1];
+        // 重複卡片併入 hello，已有發音時不再附加重複卡片的發音
+        assert_eq!(hello.len(), 1);
+        assert_eq!(hello[0].ipa.as_deref(), Some("həˈləʊ"));
+        assert_eq!(hello[0].audio_url.as_deref(), Some("https://example.com/hello-uk.mp3"));
+        assert_eq!(hello[0].region, Some(crate::domain::value_objects::pronunciation::Region::Uk));
+        assert_eq!(pronunciations::table.count().get_result::<i64>(&mut conn).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_database_from_newer_app_is_rejected() {
+        let mut conn = memory_connection();
+        run_migrations(&mut conn).unwrap();
+        diesel::sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES ('99991231000000')")
+            .execute(&mut conn)
+            .unwrap();
+
+        match run_migrations(&mut conn) {
+            Err(DatabaseError::SchemaTooNew(msg)) => assert!(msg.contains("99991231000000")),
+            other => panic!("expected SchemaTooNew, got {:?}", other),
+        }
+    }
+}
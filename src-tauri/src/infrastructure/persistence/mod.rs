@@ -1,38 +1,34 @@
+pub mod decks;
+pub mod full_text;
+pub mod imports;
+pub mod migrations;
+pub mod pool;
+pub mod pronunciations;
 pub mod schema;
+pub mod senses;
+pub mod tags;
 
-use diesel::prelude::*;
-use diesel::sqlite::SqliteConnection;
 use log::{info, warn};
 use std::env;
 use std::path::PathBuf;
 
+pub use pool::{Database, DbConnection, DbPool};
+
 /// 應用程式名稱，用於建立資料目錄
 const APP_NAME: &str = "vocab-flashcards";
 
 /// 資料庫檔案名稱
 const DB_FILE_NAME: &str = "word_cards.db";
 
-/// 建立 word_cards 表格的 SQL 語句
-const CREATE_WORD_CARDS_TABLE: &str = r#"
-CREATE TABLE IF NOT EXISTS word_cards (
-  id INTEGER PRIMARY KEY AUTOINCREMENT,
-  word TEXT NOT NULL UNIQUE,
-  pos TEXT,
-  definition TEXT,
-  pronunciation TEXT,
-  verbs TEXT,
-  familiarity INTEGER DEFAULT 0,
-  seen_count INTEGER DEFAULT 1,
-  created_at TEXT DEFAULT CURRENT_TIMESTAMP
-);
-"#;
-
 /// 資料庫連接錯誤類型
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DatabaseError {
     ConnectionFailed(String),
     InitializationFailed(String),
     DirectoryCreationFailed(String),
+    MigrationFailed(String),
+    /// 資料庫由較新版本的應用程式建立，目前版本無法安全開啟
+    SchemaTooNew(String),
 }
 
 impl std::fmt::Display for DatabaseError {
@@ -41,42 +37,14 @@ impl std::fmt::Display for DatabaseError {
             DatabaseError::ConnectionFailed(msg) => write!(f, "Database connection failed: {}", msg),
             DatabaseError::InitializationFailed(msg) => write!(f, "Database initialization failed: {}", msg),
             DatabaseError::DirectoryCreationFailed(msg) => write!(f, "Directory creation failed: {}", msg),
+            DatabaseError::MigrationFailed(msg) => write!(f, "Database migration failed: {}", msg),
+            DatabaseError::SchemaTooNew(msg) => write!(f, "Database was created by a newer version of the app: {}", msg),
         }
     }
 }
 
 impl std::error::Error for DatabaseError {}
 
-/// 建立資料庫連接並初始化表格
-pub fn establish_connection() -> SqliteConnection {
-    match try_establish_connection() {
-        Ok(connection) => connection,
-        Err(e) => {
-            panic!("Fatal database error: {}", e);
-        }
-    }
-}
-
-/// 嘗試建立資料庫連接（可處理錯誤的版本）
-fn try_establish_connection() -> Result<SqliteConnection, DatabaseError> {
-    let database_url = get_database_url();
-    
-    // 確保資料庫目錄存在
-    ensure_database_directory(&database_url)?;
-    
-    info!("連接資料庫: {}", database_url);
-    
-    // 建立連接
-    let mut connection = SqliteConnection::establish(&database_url)
-        .map_err(|e| DatabaseError::ConnectionFailed(format!("{}: {}", database_url, e)))?;
-    
-    // 初始化資料庫表格
-    initialize_database(&mut connection)?;
-    
-    info!("資料庫連接建立成功");
-    Ok(connection)
-}
-
 /// 確保資料庫目錄存在
 fn ensure_database_directory(database_url: &str) -> Result<(), DatabaseError> {
     if let Some(parent) = std::path::Path::new(database_url).parent() {
@@ -160,24 +128,26 @@ fn get_app_data_directory() -> Option<PathBuf> {
     }
 }
 
-/// 初始化資料庫表格
-fn initialize_database(connection: &mut SqliteConnection) -> Result<(), DatabaseError> {
-    info!("初始化資料庫表格...");
-    
-    diesel::sql_query(CREATE_WORD_CARDS_TABLE)
-        .execute(connection)
-        .map_err(|e| DatabaseError::InitializationFailed(e.to_string()))?;
-    
-    info!("資料庫表格初始化完成");
-    Ok(())
-}
+/// 初始化資料庫：於應用程式啟動時執行一次
+///
+/// 建立連接池並套用所有待執行的遷移，回傳的連接池交由 Tauri 管理，供所有指令共用。
+pub fn initialize_database() -> Result<DbPool, DatabaseError> {
+    let database_url = get_database_url();
 
-/// 測試資料庫連接是否正常
-pub fn test_connection() -> Result<String, String> {
-    match try_establish_connection() {
-        Ok(_) => Ok("資料庫連接測試成功".to_string()),
-        Err(e) => Err(e.to_string()),
-    }
+    // 確保資料庫目錄存在
+    ensure_database_directory(&database_url)?;
+
+    info!("連接資料庫: {}", database_url);
+    let pool = pool::create_pool(&database_url)?;
+
+    info!("初始化資料庫結構...");
+    let mut connection = pool
+        .get()
+        .map_err(|e| DatabaseError::ConnectionFailed(e.to_string()))?;
+    migrations::run_migrations(&mut connection)?;
+
+    info!("資料庫結構初始化完成");
+    Ok(pool)
 }
 
 #[cfg(test)]
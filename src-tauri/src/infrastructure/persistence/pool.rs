@@ -0,0 +1,138 @@
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, CustomizeConnection, Pool, PooledConnection};
+use diesel::sqlite::SqliteConnection;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use super::DatabaseError;
+
+/// 應用程式共用的資料庫連接池，於啟動時建立一次
+pub type DbPool = Pool<ConnectionManager<SqliteConnection>>;
+
+/// 從連接池取出的連接，離開作用域時自動歸還
+pub type DbConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
+
+/// 連接池的最大連接數
+///
+/// WAL 模式下讀取可以並行，寫入仍會依序進行，因此不需要太多連接。
+const MAX_POOL_SIZE: u32 = 8;
+
+/// 等待資料庫鎖的時間（毫秒），避免大量匯入時立即回傳 SQLITE_BUSY
+const BUSY_TIMEOUT_MS: u32 = 5000;
+
+/// 每個新連接建立時套用的 PRAGMA 設定
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionOptions {
+    pub busy_timeout_ms: u32,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            busy_timeout_ms: BUSY_TIMEOUT_MS,
+        }
+    }
+}
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for ConnectionOptions {
+    fn on_acquire(&self, connection: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        // busy_timeout 必須最先設定，後續切換 WAL 時才會等待其他連接釋放鎖
+        // 外鍵約束在 SQLite 預設關閉，需每個連接各自開啟
+        connection
+            .batch_execute(&format!(
+                "PRAGMA busy_timeout = {}; \
+                 PRAGMA journal_mode = WAL; \
+                 PRAGMA synchronous = NORMAL; \
+                 PRAGMA foreign_keys = ON;",
+                self.busy_timeout_ms
+            ))
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+/// 建立連接池；建立時會先開啟一個連接，確認資料庫可以使用
+pub fn create_pool(database_url: &str) -> Result<DbPool, DatabaseError> {
+    let manager = ConnectionManager::<SqliteConnection>::new(database_url);
+    Pool::builder()
+        .max_size(MAX_POOL_SIZE)
+        .connection_timeout(Duration::from_secs(30))
+        .connection_customizer(Box::new(ConnectionOptions::default()))
+        .build(manager)
+        .map_err(|e| DatabaseError::ConnectionFailed(format!("{}: {}", database_url, e)))
+}
+
+/// 交由 Tauri 管理的資料庫狀態
+///
+/// 啟動時初始化失敗不會終止應用程式，而是保留錯誤讓前端顯示並提供重試。
+pub struct Database {
+    pool: RwLock<Result<DbPool, DatabaseError>>,
+}
+
+impl Database {
+    pub fn new(pool: Result<DbPool, DatabaseError>) -> Self {
+        Self {
+            pool: RwLock::new(pool),
+        }
+    }
+
+    /// 取得連接池；資料庫尚未成功初始化時回傳初始化的錯誤
+    pub fn pool(&self) -> Result<DbPool, DatabaseError> {
+        self.pool.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 以新的初始化結果取代目前的狀態
+    pub fn replace(&self, pool: Result<DbPool, DatabaseError>) {
+        *self.pool.write().unwrap_or_else(|e| e.into_inner()) = pool;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.pool.read().unwrap_or_else(|e| e.into_inner()).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::prelude::*;
+    use diesel::sql_types::{BigInt, Text};
+
+    #[derive(QueryableByName)]
+    struct Number {
+        #[diesel(sql_type = BigInt)]
+        value: i64,
+    }
+
+    #[derive(QueryableByName)]
+    struct Mode {
+        #[diesel(sql_type = Text)]
+        journal_mode: String,
+    }
+
+    #[test]
+    fn test_pool_applies_pragmas() {
+        let path = std::env::temp_dir().join(format!("pool-test-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let pool = create_pool(&path.to_string_lossy()).unwrap();
+        let mut conn = pool.get().unwrap();
+
+        let foreign_keys = diesel::sql_query("SELECT foreign_keys AS value FROM pragma_foreign_keys")
+            .get_result::<Number>(&mut conn)
+            .unwrap();
+        let busy_timeout = diesel::sql_query("SELECT timeout AS value FROM pragma_busy_timeout")
+            .get_result::<Number>(&mut conn)
+            .unwrap();
+        let mode = diesel::sql_query("PRAGMA journal_mode")
+            .get_result::<Mode>(&mut conn)
+            .unwrap();
+
+        assert_eq!(foreign_keys.value, 1);
+        assert_eq!(busy_timeout.value, i64::from(BUSY_TIMEOUT_MS));
+        assert_eq!(mode.journal_mode, "wal");
+
+        drop(conn);
+        drop(pool);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.to_string_lossy(), suffix));
+        }
+    }
+}
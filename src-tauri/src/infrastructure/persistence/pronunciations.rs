@@ -0,0 +1,78 @@
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use std::collections::HashMap;
+
+use super::schema::pronunciations;
+use crate::domain::value_objects::pronunciation::{Pronunciation, Region};
+
+#[derive(Queryable)]
+struct PronunciationRow {
+    card_id: i32,
+    region: Option<String>,
+    ipa: Option<String>,
+    audio_url: Option<String>,
+    local_path: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = pronunciations)]
+struct NewPronunciationRow<'a> {
+    card_id: i32,
+    position: i32,
+    region: Option<&'a str>,
+    ipa: Option<&'a str>,
+    audio_url: Option<&'a str>,
+    local_path: Option<&'a str>,
+}
+
+/// 取得多張單字卡的發音，依 `position` 排序；沒有發音的卡片不會出現在結果中
+pub fn load_pronunciations(
+    connection: &mut SqliteConnection,
+    card_ids: &[i32],
+) -> QueryResult<HashMap<i32, Vec<Pronunciation>>> {
+    let rows = pronunciations::table
+        .filter(pronunciations::card_id.eq_any(card_ids))
+        .order((pronunciations::card_id.asc(), pronunciations::position.asc()))
+        .select((
+            pronunciations::card_id,
+            pronunciations::region,
+            pronunciations::ipa,
+            pronunciations::audio_url,
+            pronunciations::local_path,
+        ))
+        .load::<PronunciationRow>(connection)?;
+
+    let mut result: HashMap<i32, Vec<Pronunciation>> = HashMap::new();
+    for row in rows {
+        result.entry(row.card_id).or_default().push(Pronunciation {
+            region: row.region.as_deref().and_then(Region::parse),
+            ipa: row.ipa,
+            audio_url: row.audio_url,
+            local_path: row.local_path,
+        });
+    }
+    Ok(result)
+}
+
+/// 以 `new_pronunciations` 取代單字卡原有的發音；呼叫端負責包在交易中
+pub fn replace_pronunciations(
+    connection: &mut SqliteConnection,
+    card_id: i32,
+    new_pronunciations: &[Pronunciation],
+) -> QueryResult<()> {
+    diesel::delete(pronunciations::table.filter(pronunciations::card_id.eq(card_id))).execute(connection)?;
+
+    let rows = (0..)
+        .zip(new_pronunciations)
+        .map(|(position, p)| NewPronunciationRow {
+            card_id,
+            position,
+            region: p.region.as_ref().map(Region::as_str),
+            ipa: p.ipa.as_deref(),
+            audio_url: p.audio_url.as_deref(),
+            local_path: p.local_path.as_deref(),
+        })
+        .collect::<Vec<_>>();
+    diesel::insert_into(pronunciations::table).values(&rows).execute(connection)?;
+    Ok(())
+}
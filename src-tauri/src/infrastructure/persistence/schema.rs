@@ -1,15 +1,181 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    card_sources (id) {
+        id -> Nullable<Integer>,
+        card_id -> Integer,
+        kind -> Text,
+        import_id -> Nullable<Integer>,
+        created_card -> Bool,
+        occurrence_count -> Integer,
+        created_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    card_tags (card_id, tag_id) {
+        card_id -> Integer,
+        tag_id -> Integer,
+    }
+}
+
+diesel::table! {
+    decks (id) {
+        id -> Nullable<Integer>,
+        name -> Text,
+        parent_id -> Nullable<Integer>,
+        new_cards_per_day -> Integer,
+        reviews_per_day -> Integer,
+        learning_steps -> Text,
+        relearning_steps -> Text,
+        created_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    imports (id) {
+        id -> Nullable<Integer>,
+        file_name -> Nullable<Text>,
+        file_hash -> Nullable<Text>,
+        status -> Text,
+        total_count -> Integer,
+        success_count -> Integer,
+        exists_count -> Integer,
+        failed_count -> Integer,
+        created_at -> Nullable<Text>,
+        finished_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    profiles (id) {
+        id -> Nullable<Integer>,
+        name -> Text,
+        scheduler -> Text,
+        is_active -> Integer,
+        created_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    pronunciations (id) {
+        id -> Nullable<Integer>,
+        card_id -> Integer,
+        position -> Integer,
+        region -> Nullable<Text>,
+        ipa -> Nullable<Text>,
+        audio_url -> Nullable<Text>,
+        local_path -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    review_logs (id) {
+        id -> Nullable<Integer>,
+        card_id -> Integer,
+        reviewed_at -> Text,
+        grade -> Integer,
+        scheduler -> Text,
+        elapsed_days -> Integer,
+        duration_ms -> Nullable<Integer>,
+        previous_state -> Text,
+        new_state -> Text,
+    }
+}
+
+diesel::table! {
+    sense_antonyms (id) {
+        id -> Nullable<Integer>,
+        sense_id -> Integer,
+        position -> Integer,
+        word -> Text,
+    }
+}
+
+diesel::table! {
+    sense_examples (id) {
+        id -> Nullable<Integer>,
+        sense_id -> Integer,
+        position -> Integer,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    sense_synonyms (id) {
+        id -> Nullable<Integer>,
+        sense_id -> Integer,
+        position -> Integer,
+        word -> Text,
+    }
+}
+
+diesel::table! {
+    senses (id) {
+        id -> Nullable<Integer>,
+        card_id -> Integer,
+        position -> Integer,
+        part_of_speech -> Text,
+        definition -> Text,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Nullable<Integer>,
+        name -> Text,
+        name_key -> Text,
+        created_at -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     word_cards (id) {
         id -> Nullable<Integer>,
         word -> Text,
         pos -> Nullable<Text>,
         definition -> Nullable<Text>,
-        pronunciation -> Nullable<Text>,
         verbs -> Nullable<Text>,
         familiarity -> Nullable<Integer>,
         seen_count -> Nullable<Integer>,
         created_at -> Nullable<Text>,
+        ease_factor -> Double,
+        interval_days -> Integer,
+        repetitions -> Integer,
+        due_at -> Nullable<Text>,
+        stability -> Nullable<Double>,
+        difficulty -> Nullable<Double>,
+        last_reviewed_at -> Nullable<Text>,
+        word_key -> Nullable<Text>,
+        deck_id -> Integer,
+        learning_step -> Nullable<Integer>,
+        occurrence_count -> Integer,
     }
 }
+
+diesel::joinable!(card_sources -> imports (import_id));
+diesel::joinable!(card_sources -> word_cards (card_id));
+diesel::joinable!(card_tags -> tags (tag_id));
+diesel::joinable!(card_tags -> word_cards (card_id));
+diesel::joinable!(pronunciations -> word_cards (card_id));
+diesel::joinable!(review_logs -> word_cards (card_id));
+diesel::joinable!(sense_antonyms -> senses (sense_id));
+diesel::joinable!(sense_examples -> senses (sense_id));
+diesel::joinable!(sense_synonyms -> senses (sense_id));
+diesel::joinable!(senses -> word_cards (card_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    card_sources,
+    card_tags,
+    decks,
+    imports,
+    profiles,
+    pronunciations,
+    review_logs,
+    sense_antonyms,
+    sense_examples,
+    sense_synonyms,
+    senses,
+    tags,
+    word_cards,
+);
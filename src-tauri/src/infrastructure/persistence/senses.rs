@@ -0,0 +1,135 @@
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use std::collections::HashMap;
+
+use super::schema::{sense_antonyms, sense_examples, sense_synonyms, senses};
+use crate::domain::entities::senses::Sense;
+
+define_sql_function!(fn last_insert_rowid() -> diesel::sql_types::Integer);
+
+#[derive(Queryable)]
+struct SenseRow {
+    id: Option<i32>,
+    card_id: i32,
+    part_of_speech: String,
+    definition: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = senses)]
+struct NewSenseRow<'a> {
+    card_id: i32,
+    position: i32,
+    part_of_speech: &'a str,
+    definition: &'a str,
+}
+
+/// 詞義底下的一個例句、同義詞或反義詞，依 `position` 排序
+type ItemRow = (i32, String);
+
+fn group_items(rows: Vec<ItemRow>) -> HashMap<i32, Vec<String>> {
+    let mut grouped: HashMap<i32, Vec<String>> = HashMap::new();
+    for (sense_id, value) in rows {
+        grouped.entry(sense_id).or_default().push(value);
+    }
+    grouped
+}
+
+/// 取得多張單字卡的詞義，依 `position` 排序；沒有詞義的卡片不會出現在結果中
+pub fn load_senses(
+    connection: &mut SqliteConnection,
+    card_ids: &[i32],
+) -> QueryResult<HashMap<i32, Vec<Sense>>> {
+    let rows = senses::table
+        .filter(senses::card_id.eq_any(card_ids))
+        .order((senses::card_id.asc(), senses::position.asc()))
+        .select((senses::id, senses::card_id, senses::part_of_speech, senses::definition))
+        .load::<SenseRow>(connection)?;
+    let sense_ids = rows.iter().filter_map(|r| r.id).collect::<Vec<_>>();
+
+    let mut examples = group_items(
+        sense_examples::table
+            .filter(sense_examples::sense_id.eq_any(&sense_ids))
+            .order((sense_examples::sense_id.asc(), sense_examples::position.asc()))
+            .select((sense_examples::sense_id, sense_examples::text))
+            .load::<ItemRow>(connection)?,
+    );
+    let mut synonyms = group_items(
+        sense_synonyms::table
+            .filter(sense_synonyms::sense_id.eq_any(&sense_ids))
+            .order((sense_synonyms::sense_id.asc(), sense_synonyms::position.asc()))
+            .select((sense_synonyms::sense_id, sense_synonyms::word))
+            .load::<ItemRow>(connection)?,
+    );
+    let mut antonyms = group_items(
+        sense_antonyms::table
+            .filter(sense_antonyms::sense_id.eq_any(&sense_ids))
+            .order((sense_antonyms::sense_id.asc(), sense_antonyms::position.asc()))
+            .select((sense_antonyms::sense_id, sense_antonyms::word))
+            .load::<ItemRow>(connection)?,
+    );
+
+    let mut result: HashMap<i32, Vec<Sense>> = HashMap::new();
+    for row in rows {
+        let sense_id = row.id.unwrap_or_default();
+        result.entry(row.card_id).or_default().push(Sense {
+            part_of_speech: row.part_of_speech,
+            definition: row.definition,
+            examples: examples.remove(&sense_id).unwrap_or_default(),
+            synonyms: synonyms.remove(&sense_id).unwrap_or_default(),
+            antonyms: antonyms.remove(&sense_id).unwrap_or_default(),
+        });
+    }
+    Ok(result)
+}
+
+/// 以 `new_senses` 取代單字卡原有的詞義；呼叫端負責包在交易中
+pub fn replace_senses(
+    connection: &mut SqliteConnection,
+    card_id: i32,
+    new_senses: &[Sense],
+) -> QueryResult<()> {
+    // 明確刪除子資料，不依賴連接是否啟用 foreign_keys
+    let old_ids = senses::table
+        .filter(senses::card_id.eq(card_id))
+        .select(senses::id)
+        .load::<Option<i32>>(connection)?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    diesel::delete(sense_examples::table.filter(sense_examples::sense_id.eq_any(&old_ids))).execute(connection)?;
+    diesel::delete(sense_synonyms::table.filter(sense_synonyms::sense_id.eq_any(&old_ids))).execute(connection)?;
+    diesel::delete(sense_antonyms::table.filter(sense_antonyms::sense_id.eq_any(&old_ids))).execute(connection)?;
+    diesel::delete(senses::table.filter(senses::card_id.eq(card_id))).execute(connection)?;
+
+    for (position, sense) in (0..).zip(new_senses) {
+        diesel::insert_into(senses::table)
+            .values(NewSenseRow {
+                card_id,
+                position,
+                part_of_speech: &sense.part_of_speech,
+                definition: &sense.definition,
+            })
+            .execute(connection)?;
+        let sense_id = diesel::select(last_insert_rowid()).get_result::<i32>(connection)?;
+
+        let examples = (0..)
+            .zip(&sense.examples)
+            .map(|(i, text)| (sense_examples::sense_id.eq(sense_id), sense_examples::position.eq(i), sense_examples::text.eq(text)))
+            .collect::<Vec<_>>();
+        diesel::insert_into(sense_examples::table).values(&examples).execute(connection)?;
+
+        let synonyms = (0..)
+            .zip(&sense.synonyms)
+            .map(|(i, w)| (sense_synonyms::sense_id.eq(sense_id), sense_synonyms::position.eq(i), sense_synonyms::word.eq(w)))
+            .collect::<Vec<_>>();
+        diesel::insert_into(sense_synonyms::table).values(&synonyms).execute(connection)?;
+
+        let antonyms = (0..)
+            .zip(&sense.antonyms)
+            .map(|(i, w)| (sense_antonyms::sense_id.eq(sense_id), sense_antonyms::position.eq(i), sense_antonyms::word.eq(w)))
+            .collect::<Vec<_>>();
+        diesel::insert_into(sense_antonyms::table).values(&antonyms).execute(connection)?;
+    }
+    Ok(())
+}
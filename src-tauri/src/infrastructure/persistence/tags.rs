@@ -0,0 +1,54 @@
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use std::collections::HashMap;
+
+use super::schema::{card_tags, tags};
+use crate::domain::entities::tags::Tag;
+use crate::domain::value_objects::tag::TagExpression;
+
+/// 取得多張單字卡的標籤，依名稱排序（不分大小寫）；沒有標籤的卡片不會出現在結果中
+pub fn load_card_tags(
+    connection: &mut SqliteConnection,
+    card_ids: &[i32],
+) -> QueryResult<HashMap<i32, Vec<Tag>>> {
+    let rows = card_tags::table
+        .inner_join(tags::table)
+        .filter(card_tags::card_id.eq_any(card_ids))
+        .order((card_tags::card_id.asc(), tags::name_key.asc()))
+        .select((card_tags::card_id, Tag::as_select()))
+        .load::<(i32, Tag)>(connection)?;
+
+    let mut result: HashMap<i32, Vec<Tag>> = HashMap::new();
+    for (card_id, tag) in rows {
+        result.entry(card_id).or_default().push(tag);
+    }
+    Ok(result)
+}
+
+/// 移除單字卡的所有標籤；呼叫端負責包在交易中
+pub fn remove_card_tags(connection: &mut SqliteConnection, card_id: i32) -> QueryResult<usize> {
+    diesel::delete(card_tags::table.filter(card_tags::card_id.eq(card_id))).execute(connection)
+}
+
+/// 將標籤條件轉為 `word_cards` 查詢可用的 SQL 條件
+///
+/// 條件中只有整數 ID，不含使用者輸入的文字，因此可以直接組成 SQL。
+pub fn tag_condition_sql(expression: &TagExpression) -> String {
+    fn join(children: &[TagExpression], separator: &str, empty: &str) -> String {
+        if children.is_empty() {
+            return empty.to_string();
+        }
+        let parts = children.iter().map(tag_condition_sql).collect::<Vec<_>>();
+        format!("({})", parts.join(separator))
+    }
+
+    match expression {
+        TagExpression::Tag(tag_id) => format!(
+            "EXISTS (SELECT 1 FROM card_tags WHERE card_tags.card_id = word_cards.id AND card_tags.tag_id = {})",
+            tag_id
+        ),
+        TagExpression::And(children) => join(children, " AND ", "1"),
+        TagExpression::Or(children) => join(children, " OR ", "0"),
+        TagExpression::Not(child) => format!("(NOT {})", tag_condition_sql(child)),
+    }
+}
@@ -0,0 +1,110 @@
+use diesel::prelude::*;
+use diesel::result::Error;
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::domain::aggregates::deck::{Deck, DeckCounts, DEFAULT_DECK_ID};
+use crate::domain::repositories::deck_repository::DeckRepository;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::infrastructure::persistence::{decks, schema, DbConnection, DbPool};
+use crate::infrastructure::repositories::diesel_word_card_repository::{
+    connection, ensure_cards_exist, TransactionError, ID_BATCH_SIZE,
+};
+
+/// 以 SQLite 儲存的牌組儲存庫；與單字卡儲存庫共用連接池
+#[derive(Clone)]
+pub struct DieselDeckRepository {
+    pool: DbPool,
+}
+
+impl DieselDeckRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+impl DeckRepository for DieselDeckRepository {
+    fn list_decks(&self) -> RepositoryResult<Vec<Deck>> {
+        let mut conn = self.connection()?;
+        Ok(decks::load_decks(&mut conn)?)
+    }
+
+    fn find_deck(&self, target_id: i32) -> RepositoryResult<Option<Deck>> {
+        let mut conn = self.connection()?;
+        Ok(decks::load_deck(&mut conn, target_id)?)
+    }
+
+    fn save_deck(&self, deck: &Deck) -> RepositoryResult<Deck> {
+        let mut conn = self.connection()?;
+        let saved = conn.transaction::<_, Error, _>(|conn| decks::save_deck(conn, deck))?;
+        Ok(saved)
+    }
+
+    fn delete_deck(&self, target_id: i32) -> RepositoryResult<bool> {
+        if target_id == DEFAULT_DECK_ID {
+            return Err(RepositoryError::Conflict("預設牌組不能刪除".to_string()));
+        }
+        let mut conn = self.connection()?;
+        let deleted_rows = conn.immediate_transaction::<_, Error, _>(|conn| {
+            let Some(deck) = decks::load_deck(conn, target_id)? else { return Ok(0) };
+
+            diesel::update(schema::decks::table.filter(schema::decks::parent_id.eq(Some(target_id))))
+                .set(schema::decks::parent_id.eq(deck.parent_id))
+                .execute(conn)?;
+            diesel::update(schema::word_cards::table.filter(schema::word_cards::deck_id.eq(target_id)))
+                .set(schema::word_cards::deck_id.eq(deck.parent_id.unwrap_or(DEFAULT_DECK_ID)))
+                .execute(conn)?;
+            diesel::delete(schema::decks::table.filter(schema::decks::id.eq(Some(target_id)))).execute(conn)
+        })?;
+        Ok(deleted_rows > 0)
+    }
+
+    fn move_cards(&self, card_ids: &[i32], target_id: i32) -> RepositoryResult<usize> {
+        let cards = card_ids.iter().copied().collect::<BTreeSet<_>>();
+        let mut conn = self.connection()?;
+        let moved = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            if decks::load_deck(conn, target_id)?.is_none() {
+                return Err(TransactionError::Repository(RepositoryError::NotFound(format!("找不到牌組: {}", target_id))));
+            }
+            ensure_cards_exist(conn, &cards)?;
+
+            let requested = cards.iter().map(|i| Some(*i)).collect::<Vec<_>>();
+            let mut moved = 0;
+            for batch in requested.chunks(ID_BATCH_SIZE) {
+                moved += diesel::update(
+                    schema::word_cards::table
+                        .filter(schema::word_cards::id.eq_any(batch))
+                        .filter(schema::word_cards::deck_id.ne(target_id)),
+                )
+                .set(schema::word_cards::deck_id.eq(target_id))
+                    .execute(conn)?;
+            }
+            Ok(moved)
+        })?;
+        Ok(moved)
+    }
+
+    fn count_cards_by_deck(&self, due_before: &str) -> RepositoryResult<HashMap<i32, DeckCounts>> {
+        let mut conn = self.connection()?;
+        Ok(decks::count_cards_by_deck(&mut conn, due_before)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::diesel =>
+        default_deck_exists,
+        save_deck_creates_and_renames,
+        move_cards_skips_cards_already_in_deck,
+        move_cards_requires_existing_deck_and_cards,
+        count_cards_by_deck_separates_new_learning_and_due,
+        delete_deck_moves_children_and_cards_up,
+        default_deck_cannot_be_deleted,
+    );
+}
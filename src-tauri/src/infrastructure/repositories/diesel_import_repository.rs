@@ -0,0 +1,102 @@
+use std::collections::BTreeSet;
+
+use crate::domain::entities::imports::{
+    CardSource, ImportOutcome, ImportRecord, ImportUndoSummary, ImportedCard, NewCardSource, NewImportRecord,
+};
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::infrastructure::persistence::{imports, DbConnection, DbPool};
+use crate::infrastructure::repositories::diesel_word_card_repository::{
+    connection, delete_card, ensure_cards_exist, TransactionError,
+};
+
+/// 以 SQLite 儲存的匯入紀錄儲存庫；與單字卡儲存庫共用連接池，撤銷匯入時一併刪除單字卡
+#[derive(Clone)]
+pub struct DieselImportRepository {
+    pool: DbPool,
+}
+
+impl DieselImportRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+impl ImportRepository for DieselImportRepository {
+    fn create_import(&self, record: &NewImportRecord) -> RepositoryResult<ImportRecord> {
+        let mut conn = self.connection()?;
+        Ok(imports::insert_import(&mut conn, record)?)
+    }
+
+    fn finish_import(&self, import_id: i32, outcome: &ImportOutcome) -> RepositoryResult<ImportRecord> {
+        let mut conn = self.connection()?;
+        Ok(imports::finish_import(&mut conn, import_id, outcome)?)
+    }
+
+    fn list_imports(&self) -> RepositoryResult<Vec<ImportRecord>> {
+        let mut conn = self.connection()?;
+        Ok(imports::load_imports(&mut conn)?)
+    }
+
+    fn find_import(&self, import_id: i32) -> RepositoryResult<Option<ImportRecord>> {
+        let mut conn = self.connection()?;
+        Ok(imports::load_import(&mut conn, import_id)?)
+    }
+
+    fn add_card_source(&self, source: &NewCardSource) -> RepositoryResult<CardSource> {
+        let cards = BTreeSet::from([source.card_id]);
+        let mut conn = self.connection()?;
+        let saved = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            ensure_cards_exist(conn, &cards)?;
+            if let Some(import_id) = source.import_id {
+                if imports::load_import(conn, import_id)?.is_none() {
+                    return Err(TransactionError::Repository(RepositoryError::NotFound(format!("找不到匯入紀錄: {}", import_id))));
+                }
+            }
+            Ok(imports::upsert_card_source(conn, source)?)
+        })?;
+        Ok(saved)
+    }
+
+    fn list_card_sources(&self, card_id: i32) -> RepositoryResult<Vec<CardSource>> {
+        let mut conn = self.connection()?;
+        Ok(imports::load_card_sources(&mut conn, card_id)?)
+    }
+
+    fn list_import_cards(&self, import_id: i32) -> RepositoryResult<Vec<ImportedCard>> {
+        let mut conn = self.connection()?;
+        Ok(imports::load_import_cards(&mut conn, import_id)?)
+    }
+
+    fn undo_import(&self, import_id: i32) -> RepositoryResult<ImportUndoSummary> {
+        let mut conn = self.connection()?;
+        let summary = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            if imports::load_import(conn, import_id)?.is_none() {
+                return Err(TransactionError::Repository(RepositoryError::NotFound(format!("找不到匯入紀錄: {}", import_id))));
+            }
+            Ok(imports::undo_import(conn, import_id, &mut delete_card)?)
+        })?;
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::diesel =>
+        create_import_starts_running,
+        card_sources_accumulate_per_import,
+        card_sources_require_existing_card_and_import,
+        finish_import_records_outcome,
+        imports_are_listed_newest_first,
+        import_cards_are_sorted_by_word,
+        undo_import_removes_only_cards_it_created,
+        undo_import_requires_existing_import,
+        deleting_card_removes_its_sources,
+    );
+}
@@ -0,0 +1,63 @@
+use diesel::prelude::*;
+use diesel::result::Error;
+
+use crate::domain::entities::profiles::Profile;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::SchedulerKind;
+use crate::infrastructure::persistence::schema::profiles::dsl::*;
+use crate::infrastructure::persistence::{DbConnection, DbPool};
+use crate::infrastructure::repositories::diesel_word_card_repository::connection;
+
+/// 以 SQLite 儲存的設定檔儲存庫
+#[derive(Clone)]
+pub struct DieselProfileRepository {
+    pool: DbPool,
+}
+
+impl DieselProfileRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+impl ProfileRepository for DieselProfileRepository {
+    fn find_active_profile(&self) -> RepositoryResult<Option<Profile>> {
+        let mut conn = self.connection()?;
+        Ok(profiles
+            .filter(is_active.eq(1))
+            .order(id.asc())
+            .select(Profile::as_select())
+            .first::<Profile>(&mut conn)
+            .optional()?)
+    }
+
+    fn update_scheduler(&self, profile_id: i32, kind: SchedulerKind) -> RepositoryResult<Option<Profile>> {
+        let mut conn = self.connection()?;
+        let updated = conn.transaction::<_, Error, _>(|conn| {
+            diesel::update(profiles.filter(id.eq(Some(profile_id))))
+                .set(scheduler.eq(kind.as_str()))
+                .execute(conn)?;
+            profiles
+                .filter(id.eq(Some(profile_id)))
+                .select(Profile::as_select())
+                .first::<Profile>(conn)
+                .optional()
+        })?;
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::diesel =>
+        default_profile_uses_sm2,
+        update_scheduler_returns_updated_profile,
+    );
+}
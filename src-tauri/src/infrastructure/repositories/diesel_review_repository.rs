@@ -0,0 +1,152 @@
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::result::Error;
+use diesel::sql_types::Bool;
+use diesel::sqlite::Sqlite;
+
+use crate::domain::entities::review_logs::{NewReviewLog, ReviewLog};
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::repositories::review_repository::{ReviewCounts, ReviewLogFilter, ReviewRepository};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::{format_timestamp, ReviewState};
+use crate::infrastructure::persistence::schema::review_logs;
+use crate::infrastructure::persistence::schema::word_cards::dsl::*;
+use crate::infrastructure::persistence::{schema, DbConnection, DbPool};
+use crate::infrastructure::repositories::diesel_word_card_repository::connection;
+
+/// 作答前 due_at 為空代表該次作答是新卡片的第一次學習
+const WAS_NEW: &str = "json_extract(previous_state, '$.due_at') IS NULL";
+
+/// 以 SQLite 儲存的作答紀錄與複習佇列儲存庫；與單字卡儲存庫共用連接池
+#[derive(Clone)]
+pub struct DieselReviewRepository {
+    pool: DbPool,
+}
+
+impl DieselReviewRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+/// 依牌組篩選的單字卡查詢
+fn cards_in(deck_ids: Option<&[i32]>) -> schema::word_cards::BoxedQuery<'static, Sqlite> {
+    let mut query = word_cards.into_boxed();
+    if let Some(ids) = deck_ids {
+        query = query.filter(deck_id.eq_any(ids.to_vec()));
+    }
+    query
+}
+
+/// `since` 之後符合條件的作答次數
+fn count_logs(conn: &mut SqliteConnection, since: &str, condition: String, deck_ids: Option<&[i32]>) -> QueryResult<i64> {
+    let mut query = review_logs::table
+        .filter(review_logs::reviewed_at.ge(since.to_string()))
+        .filter(sql::<Bool>(&condition))
+        .into_boxed();
+    if let Some(ids) = deck_ids {
+        let cards_in_decks = word_cards.filter(deck_id.eq_any(ids.to_vec())).select(id.assume_not_null());
+        query = query.filter(review_logs::card_id.eq_any(cards_in_decks));
+    }
+    query.count().get_result::<i64>(conn)
+}
+
+impl ReviewRepository for DieselReviewRepository {
+    fn record_review(&self, card_id: i32, state: &ReviewState, log: &NewReviewLog) -> RepositoryResult<Option<WordCard>> {
+        let mut conn = self.connection()?;
+        let reviewed = conn.transaction::<_, Error, _>(|conn| {
+            let updated_rows = diesel::update(word_cards.filter(id.eq(Some(card_id))))
+                .set((
+                    ease_factor.eq(state.ease_factor),
+                    interval_days.eq(state.interval_days),
+                    repetitions.eq(state.repetitions),
+                    due_at.eq(state.due_at.as_ref().map(format_timestamp)),
+                    stability.eq(state.stability),
+                    difficulty.eq(state.difficulty),
+                    last_reviewed_at.eq(state.last_reviewed_at.as_ref().map(format_timestamp)),
+                    familiarity.eq(Some(state.familiarity)),
+                    learning_step.eq(state.learning_step),
+                    seen_count.eq(seen_count + 1),
+                ))
+                .execute(conn)?;
+            if updated_rows == 0 {
+                return Ok(None);
+            }
+
+            diesel::insert_into(review_logs::table).values(log).execute(conn)?;
+            word_cards
+                .filter(id.eq(Some(card_id)))
+                .select(WordCard::as_select())
+                .first::<WordCard>(conn)
+                .map(Some)
+        })?;
+        Ok(reviewed)
+    }
+
+    fn list_review_logs(&self, filter: &ReviewLogFilter, limit: i64) -> RepositoryResult<Vec<ReviewLog>> {
+        let mut conn = self.connection()?;
+        let mut statement = review_logs::table.into_boxed();
+        if let Some(card) = filter.card_id {
+            statement = statement.filter(review_logs::card_id.eq(card));
+        }
+        if let Some(since) = &filter.since {
+            statement = statement.filter(review_logs::reviewed_at.ge(since.clone()));
+        }
+        if let Some(until) = &filter.until {
+            statement = statement.filter(review_logs::reviewed_at.lt(until.clone()));
+        }
+
+        Ok(statement
+            .order((review_logs::reviewed_at.desc(), review_logs::id.desc()))
+            .limit(limit)
+            .select(ReviewLog::as_select())
+            .load::<ReviewLog>(&mut conn)?)
+    }
+
+    fn count_reviews_since(&self, since: &str, deck_ids: Option<&[i32]>) -> RepositoryResult<ReviewCounts> {
+        let mut conn = self.connection()?;
+        Ok(ReviewCounts {
+            new: count_logs(&mut conn, since, WAS_NEW.to_string(), deck_ids)?,
+            reviews: count_logs(&mut conn, since, format!("NOT ({})", WAS_NEW), deck_ids)?,
+        })
+    }
+
+    fn list_due_cards(&self, due_before: &str, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>> {
+        let mut conn = self.connection()?;
+        Ok(cards_in(deck_ids)
+            .filter(due_at.is_not_null())
+            .filter(due_at.lt(due_before.to_string()))
+            .order((due_at.asc(), id.asc()))
+            .limit(limit)
+            .select(WordCard::as_select())
+            .load::<WordCard>(&mut conn)?)
+    }
+
+    fn list_new_cards(&self, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>> {
+        let mut conn = self.connection()?;
+        Ok(cards_in(deck_ids)
+            .filter(due_at.is_null())
+            .order((created_at.asc(), id.asc()))
+            .limit(limit)
+            .select(WordCard::as_select())
+            .load::<WordCard>(&mut conn)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::diesel =>
+        record_review_updates_state_and_writes_log,
+        record_review_skips_missing_cards,
+        review_logs_are_filtered_newest_first,
+        reviews_since_are_split_into_new_and_reviews,
+        due_cards_are_ordered_by_due_time,
+        new_cards_are_ordered_by_creation,
+    );
+}
@@ -0,0 +1,236 @@
+use diesel::dsl::count_star;
+use diesel::prelude::*;
+use diesel::result::Error;
+use diesel::sql_types::Integer;
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::domain::entities::tags::{NewTag, Tag, TagCount};
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::infrastructure::persistence::{schema, DbConnection, DbPool};
+use crate::infrastructure::repositories::diesel_word_card_repository::{
+    connection, ensure_cards_exist, missing_ids, TransactionError, ID_BATCH_SIZE,
+};
+
+/// 以 SQLite 儲存的標籤儲存庫；與單字卡儲存庫共用連接池
+#[derive(Clone)]
+pub struct DieselTagRepository {
+    pool: DbPool,
+}
+
+impl DieselTagRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+/// 確認所有標籤都存在；呼叫端負責包在交易中
+fn ensure_tags_exist(conn: &mut SqliteConnection, tag_ids: &BTreeSet<i32>) -> Result<(), TransactionError> {
+    let requested = tag_ids.iter().map(|i| Some(*i)).collect::<Vec<_>>();
+    let mut found = Vec::with_capacity(requested.len());
+    for batch in requested.chunks(ID_BATCH_SIZE) {
+        found.extend(
+            schema::tags::table
+                .filter(schema::tags::id.eq_any(batch))
+                .select(schema::tags::id)
+                .load::<Option<i32>>(conn)?
+                .into_iter()
+                .flatten(),
+        );
+    }
+    match missing_ids(tag_ids, &found).as_slice() {
+        [] => Ok(()),
+        missing => Err(TransactionError::Repository(RepositoryError::NotFound(format!("找不到標籤: {:?}", missing)))),
+    }
+}
+
+fn find_tag_by_key(conn: &mut SqliteConnection, key: &str) -> QueryResult<Option<Tag>> {
+    schema::tags::table
+        .filter(schema::tags::name_key.eq(key))
+        .select(Tag::as_select())
+        .first::<Tag>(conn)
+        .optional()
+}
+
+fn find_tag_by_id(conn: &mut SqliteConnection, tag_id: i32) -> Result<Tag, TransactionError> {
+    schema::tags::table
+        .filter(schema::tags::id.eq(Some(tag_id)))
+        .select(Tag::as_select())
+        .first::<Tag>(conn)
+        .optional()?
+        .ok_or_else(|| TransactionError::Repository(RepositoryError::NotFound(format!("找不到標籤: {}", tag_id))))
+}
+
+fn tag_card_count(conn: &mut SqliteConnection, tag_id: i32) -> QueryResult<i64> {
+    schema::card_tags::table
+        .filter(schema::card_tags::tag_id.eq(tag_id))
+        .count()
+        .get_result::<i64>(conn)
+}
+
+/// 一次寫入的單字卡標籤筆數，避免超過 SQLite 的參數數量上限
+const CARD_TAG_BATCH_SIZE: usize = 500;
+
+impl TagRepository for DieselTagRepository {
+    fn list_tags(&self) -> RepositoryResult<Vec<TagCount>> {
+        let mut conn = self.connection()?;
+        let all_tags = schema::tags::table
+            .order((schema::tags::name_key.asc(), schema::tags::id.asc()))
+            .select(Tag::as_select())
+            .load::<Tag>(&mut conn)?;
+        let counts = schema::card_tags::table
+            .group_by(schema::card_tags::tag_id)
+            .select((schema::card_tags::tag_id, count_star()))
+            .load::<(i32, i64)>(&mut conn)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        Ok(all_tags
+            .into_iter()
+            .map(|tag| {
+                let card_count = tag.id.and_then(|i| counts.get(&i).copied()).unwrap_or(0);
+                TagCount { tag, card_count }
+            })
+            .collect())
+    }
+
+    fn find_tag(&self, tag_id: i32) -> RepositoryResult<Option<Tag>> {
+        let mut conn = self.connection()?;
+        Ok(schema::tags::table
+            .filter(schema::tags::id.eq(Some(tag_id)))
+            .select(Tag::as_select())
+            .first::<Tag>(&mut conn)
+            .optional()?)
+    }
+
+    fn create_tag(&self, tag: &NewTag) -> RepositoryResult<Tag> {
+        let mut conn = self.connection()?;
+        let created = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            if find_tag_by_key(conn, &tag.name_key)?.is_some() {
+                return Err(TransactionError::Repository(RepositoryError::Conflict(format!("標籤 '{}' 已存在", tag.name))));
+            }
+            diesel::insert_into(schema::tags::table).values(tag).execute(conn)?;
+            Ok(find_tag_by_key(conn, &tag.name_key)?.ok_or(Error::NotFound)?)
+        })?;
+        Ok(created)
+    }
+
+    fn rename_tag(&self, tag_id: i32, tag: &NewTag) -> RepositoryResult<Tag> {
+        let mut conn = self.connection()?;
+        let renamed = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            find_tag_by_id(conn, tag_id)?;
+            if find_tag_by_key(conn, &tag.name_key)?.is_some_and(|other| other.id != Some(tag_id)) {
+                return Err(TransactionError::Repository(RepositoryError::Conflict(format!("標籤 '{}' 已存在", tag.name))));
+            }
+            diesel::update(schema::tags::table.filter(schema::tags::id.eq(Some(tag_id))))
+                .set((schema::tags::name.eq(&tag.name), schema::tags::name_key.eq(&tag.name_key)))
+                .execute(conn)?;
+            find_tag_by_id(conn, tag_id)
+        })?;
+        Ok(renamed)
+    }
+
+    fn merge_tags(&self, source_ids: &[i32], target_id: i32) -> RepositoryResult<TagCount> {
+        let sources = source_ids.iter().copied().filter(|i| *i != target_id).collect::<BTreeSet<_>>();
+        let mut conn = self.connection()?;
+        let merged = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            let target = find_tag_by_id(conn, target_id)?;
+            ensure_tags_exist(conn, &sources)?;
+
+            let source_list = sources.iter().copied().collect::<Vec<_>>();
+            diesel::insert_or_ignore_into(schema::card_tags::table)
+                .values(
+                    schema::card_tags::table
+                        .filter(schema::card_tags::tag_id.eq_any(&source_list))
+                        .select((schema::card_tags::card_id, target_id.into_sql::<Integer>())),
+                )
+                .into_columns((schema::card_tags::card_id, schema::card_tags::tag_id))
+                .execute(conn)?;
+            diesel::delete(schema::card_tags::table.filter(schema::card_tags::tag_id.eq_any(&source_list)))
+                .execute(conn)?;
+            diesel::delete(
+                schema::tags::table.filter(schema::tags::id.eq_any(source_list.iter().map(|i| Some(*i)).collect::<Vec<_>>())),
+            )
+            .execute(conn)?;
+
+            let card_count = tag_card_count(conn, target_id)?;
+            Ok(TagCount { tag: target, card_count })
+        })?;
+        Ok(merged)
+    }
+
+    fn delete_tag(&self, tag_id: i32) -> RepositoryResult<bool> {
+        let mut conn = self.connection()?;
+        let deleted_rows = conn.transaction::<_, Error, _>(|conn| {
+            diesel::delete(schema::card_tags::table.filter(schema::card_tags::tag_id.eq(tag_id))).execute(conn)?;
+            diesel::delete(schema::tags::table.filter(schema::tags::id.eq(Some(tag_id)))).execute(conn)
+        })?;
+        Ok(deleted_rows > 0)
+    }
+
+    fn assign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize> {
+        let cards = card_ids.iter().copied().collect::<BTreeSet<_>>();
+        let tag_set = tag_ids.iter().copied().collect::<BTreeSet<_>>();
+        let mut conn = self.connection()?;
+        let assigned = conn.immediate_transaction::<_, TransactionError, _>(|conn| {
+            ensure_cards_exist(conn, &cards)?;
+            ensure_tags_exist(conn, &tag_set)?;
+
+            let rows = cards
+                .iter()
+                .flat_map(|c| {
+                    tag_set
+                        .iter()
+                        .map(move |t| (schema::card_tags::card_id.eq(*c), schema::card_tags::tag_id.eq(*t)))
+                })
+                .collect::<Vec<_>>();
+            let mut inserted = 0;
+            for batch in rows.chunks(CARD_TAG_BATCH_SIZE) {
+                inserted += diesel::insert_or_ignore_into(schema::card_tags::table).values(batch).execute(conn)?;
+            }
+            Ok(inserted)
+        })?;
+        Ok(assigned)
+    }
+
+    fn unassign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize> {
+        let mut conn = self.connection()?;
+        let removed = conn.immediate_transaction::<_, Error, _>(|conn| {
+            let mut removed = 0;
+            for card_batch in card_ids.chunks(ID_BATCH_SIZE) {
+                for tag_batch in tag_ids.chunks(ID_BATCH_SIZE) {
+                    removed += diesel::delete(
+                        schema::card_tags::table
+                            .filter(schema::card_tags::card_id.eq_any(card_batch))
+                            .filter(schema::card_tags::tag_id.eq_any(tag_batch)),
+                    )
+                    .execute(conn)?;
+                }
+            }
+            Ok(removed)
+        })?;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::diesel =>
+        tag_names_are_unique_ignoring_case,
+        assign_tags_skips_existing_pairs,
+        assign_tags_requires_existing_cards_and_tags,
+        tag_expressions_filter_cards,
+        card_details_include_sorted_tags,
+        rename_tag_rejects_other_tag_names,
+        merge_tags_moves_cards_to_target,
+        bulk_tag_selections_are_batched,
+        deleting_removes_tag_assignments,
+    );
+}
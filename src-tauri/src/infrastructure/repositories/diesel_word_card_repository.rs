@@ -0,0 +1,440 @@
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error};
+use diesel::sql_types::{Bool, Text};
+use diesel::sqlite::Sqlite;
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::domain::entities::review_logs::NewReviewLog;
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::tags::Tag;
+use crate::domain::entities::word_cards::{MergePolicy, NewWordCard, SaveOutcome, WordCard, WordCardEntry};
+use crate::domain::repositories::word_card_repository::{
+    FullTextMatch, RepositoryError, RepositoryResult, SortDirection, WordCardFilter, WordCardRepository,
+    WordCardSortKey,
+};
+use crate::infrastructure::persistence::schema::word_cards::dsl::*;
+use crate::domain::value_objects::pronunciation::Pronunciation;
+use crate::domain::value_objects::review::{format_timestamp, ReviewState};
+use crate::infrastructure::persistence::{full_text, imports, pronunciations, schema, senses, tags, DbConnection, DbPool};
+
+impl From<Error> for RepositoryError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotFound => RepositoryError::NotFound("找不到指定的資料".to_string()),
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+                RepositoryError::Conflict(format!("資料已存在: {}", info.message()))
+            }
+            Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info) => {
+                RepositoryError::Conflict(format!("資料仍被其他紀錄參照: {}", info.message()))
+            }
+            other => RepositoryError::Storage(other.to_string()),
+        }
+    }
+}
+
+/// 以 SQLite 儲存的單字卡儲存庫，每次操作從連接池取得連接
+#[derive(Clone)]
+pub struct DieselWordCardRepository {
+    pool: DbPool,
+}
+
+impl DieselWordCardRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn connection(&self) -> RepositoryResult<DbConnection> {
+        connection(&self.pool)
+    }
+}
+
+/// 從連接池取得連接；各個 SQLite 儲存庫共用
+pub(super) fn connection(pool: &DbPool) -> RepositoryResult<DbConnection> {
+    pool.get()
+        .map_err(|e| RepositoryError::Storage(format!("取得資料庫連接失敗: {}", e)))
+}
+
+/// 跳脫 LIKE 樣式中的萬用字元
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 依篩選條件建立查詢
+fn filtered_word_cards(filter: &WordCardFilter) -> schema::word_cards::BoxedQuery<'static, Sqlite> {
+    let mut query = word_cards.into_boxed();
+
+    if let Some(text) = filter.text.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        let pattern = format!("%{}%", escape_like(text));
+        query = query.filter(
+            word.like(pattern.clone())
+                .escape('\\')
+                .or(definition.like(pattern).escape('\\')),
+        );
+    }
+
+    if let Some(levels) = &filter.familiarity {
+        query = query.filter(familiarity.eq_any(levels.iter().map(|l| Some(*l)).collect::<Vec<_>>()));
+    }
+
+    if let Some(from) = &filter.created_from {
+        query = query.filter(created_at.ge(from.clone()));
+    }
+
+    if let Some(to) = &filter.created_to {
+        query = query.filter(created_at.lt(to.clone()));
+    }
+
+    if let Some(ids) = &filter.deck_ids {
+        query = query.filter(deck_id.eq_any(ids.clone()));
+    }
+
+    if let Some(expression) = &filter.tags {
+        query = query.filter(sql::<Bool>(&tags::tag_condition_sql(expression)));
+    }
+
+    query
+}
+
+impl WordCardRepository for DieselWordCardRepository {
+    fn find_by_id(&self, card_id: i32) -> RepositoryResult<Option<WordCard>> {
+        let mut conn = self.connection()?;
+        Ok(word_cards
+            .filter(id.eq(Some(card_id)))
+            .select(WordCard::as_select())
+            .first::<WordCard>(&mut conn)
+            .optional()?)
+    }
+
+    fn find_by_key(&self, key: &str) -> RepositoryResult<Option<WordCard>> {
+        let mut conn = self.connection()?;
+        Ok(word_cards
+            .filter(word_key.eq(key))
+            .select(WordCard::as_select())
+            .first::<WordCard>(&mut conn)
+            .optional()?)
+    }
+
+    fn find_by_ids(&self, card_ids: &[i32]) -> RepositoryResult<Vec<WordCard>> {
+        let mut conn = self.connection()?;
+        let mut cards = Vec::with_capacity(card_ids.len());
+        for batch in card_ids.chunks(ID_BATCH_SIZE) {
+            cards.extend(
+                word_cards
+                    .filter(id.eq_any(batch.iter().map(|i| Some(*i)).collect::<Vec<_>>()))
+                    .select(WordCard::as_select())
+                    .load::<WordCard>(&mut conn)?,
+            );
+        }
+        Ok(cards)
+    }
+
+    fn list(
+        &self,
+        filter: &WordCardFilter,
+        sort_key: WordCardSortKey,
+        sort_direction: SortDirection,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<WordCard>> {
+        let mut conn = self.connection()?;
+
+        let mut statement = filtered_word_cards(filter);
+        statement = match (sort_key, sort_direction) {
+            (WordCardSortKey::Word, SortDirection::Asc) => statement.order(sql::<Text>("word COLLATE NOCASE").asc()),
+            (WordCardSortKey::Word, SortDirection::Desc) => statement.order(sql::<Text>("word COLLATE NOCASE").desc()),
+            (WordCardSortKey::Familiarity, SortDirection::Asc) => statement.order(familiarity.asc()),
+            (WordCardSortKey::Familiarity, SortDirection::Desc) => statement.order(familiarity.desc()),
+            (WordCardSortKey::CreatedAt, SortDirection::Asc) => statement.order(created_at.asc()),
+            (WordCardSortKey::CreatedAt, SortDirection::Desc) => statement.order(created_at.desc()),
+        };
+
+        Ok(statement
+            .then_order_by(id.asc())
+            .offset(offset)
+            .limit(limit)
+            .select(WordCard::as_select())
+            .load::<WordCard>(&mut conn)?)
+    }
+
+    fn count(&self, filter: &WordCardFilter) -> RepositoryResult<i64> {
+        let mut conn = self.connection()?;
+        Ok(filtered_word_cards(filter).count().get_result::<i64>(&mut conn)?)
+    }
+
+    fn search(&self, terms: &[String], limit: i64) -> RepositoryResult<Vec<FullTextMatch>> {
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        // 檢索索引只回傳 ID，卡片另外依 ID 取回後再依排名排列
+        let hits = {
+            let mut conn = self.connection()?;
+            full_text::search(&mut conn, &full_text::build_match_query(terms), limit)?
+        };
+        let ids = hits.iter().map(|hit| hit.card_id).collect::<Vec<_>>();
+        let mut cards = self
+            .find_by_ids(&ids)?
+            .into_iter()
+            .filter_map(|card| Some((card.id?, card)))
+            .collect::<HashMap<_, _>>();
+        Ok(hits
+            .into_iter()
+            .filter_map(|hit| {
+                let card = cards.remove(&hit.card_id)?;
+                Some(FullTextMatch { card, score: hit.score, snippet: hit.snippet })
+            })
+            .collect())
+    }
+
+    fn list_words(&self) -> RepositoryResult<Vec<(i32, String)>> {
+        let mut conn = self.connection()?;
+        Ok(word_cards
+            .select((id.assume_not_null(), word))
+            .order(id.asc())
+            .load::<(i32, String)>(&mut conn)?)
+    }
+
+    fn find_senses(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Sense>>> {
+        let mut conn = self.connection()?;
+        let mut result = HashMap::new();
+        for batch in card_ids.chunks(ID_BATCH_SIZE) {
+            result.extend(senses::load_senses(&mut conn, batch)?);
+        }
+        Ok(result)
+    }
+
+    fn find_pronunciations(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Pronunciation>>> {
+        let mut conn = self.connection()?;
+        let mut result = HashMap::new();
+        for batch in card_ids.chunks(ID_BATCH_SIZE) {
+            result.extend(pronunciations::load_pronunciations(&mut conn, batch)?);
+        }
+        Ok(result)
+    }
+
+    fn find_tags(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Tag>>> {
+        let mut conn = self.connection()?;
+        let mut result = HashMap::new();
+        for batch in card_ids.chunks(ID_BATCH_SIZE) {
+            result.extend(tags::load_card_tags(&mut conn, batch)?);
+        }
+        Ok(result)
+    }
+
+    fn save(&self, card: &NewWordCard, policy: MergePolicy) -> RepositoryResult<(SaveOutcome, WordCardEntry)> {
+        let card_key = card
+            .word_key
+            .clone()
+            .ok_or_else(|| RepositoryError::Storage(format!("單字 '{}' 尚未正規化", card.word)))?;
+        let mut conn = self.connection()?;
+
+        // IMMEDIATE 交易在開始時即取得寫入鎖，讀取現有資料到寫回之間不會被其他連線插入
+        let result = conn.immediate_transaction::<_, Error, _>(|conn| {
+            let existing = word_cards
+                .filter(word_key.eq(&card_key))
+                .select(WordCard::as_select())
+                .first::<WordCard>(conn)
+                .optional()?;
+
+            // 衝突時寫回的內容；單字不存在時不會用到，沿用新卡片的內容即可
+            let (outcome, merged, merged_senses, merged_pronunciations) = match existing {
+                Some(current) => {
+                    let current_id = current.id.unwrap_or_default();
+                    let current_senses = senses::load_senses(conn, &[current_id])?
+                        .remove(&current_id)
+                        .unwrap_or_default();
+                    let current_pronunciations = pronunciations::load_pronunciations(conn, &[current_id])?
+                        .remove(&current_id)
+                        .unwrap_or_default();
+                    let mut entry = WordCardEntry {
+                        card: current,
+                        senses: current_senses,
+                        pronunciations: current_pronunciations,
+                        tags: Vec::new(),
+                    };
+                    let outcome = entry.apply_incoming(card, policy);
+                    let current = entry.card;
+                    (
+                        outcome,
+                        (current.word, current.pos, current.definition, current.verbs),
+                        entry.senses,
+                        entry.pronunciations,
+                    )
+                }
+                None => (
+                    SaveOutcome::Inserted,
+                    (card.word.clone(), card.pos.clone(), card.definition.clone(), card.verbs.clone()),
+                    card.senses.clone(),
+                    card.pronunciations.clone(),
+                ),
+            };
+            let (merged_word, merged_pos, merged_definition, merged_verbs) = merged;
+
+            diesel::insert_into(word_cards)
+                .values(card)
+                .on_conflict(word_key)
+                .do_update()
+                .set((
+                    word.eq(merged_word),
+                    pos.eq(merged_pos),
+                    definition.eq(merged_definition),
+                    verbs.eq(merged_verbs),
+                    seen_count.eq(seen_count + 1),
+                ))
+                .execute(conn)?;
+
+            let saved = word_cards
+                .filter(word_key.eq(&card_key))
+                .select(WordCard::as_select())
+                .first::<WordCard>(conn)?;
+            let saved_id = saved.id.unwrap_or_default();
+            senses::replace_senses(conn, saved_id, &merged_senses)?;
+            pronunciations::replace_pronunciations(conn, saved_id, &merged_pronunciations)?;
+            let saved_tags = tags::load_card_tags(conn, &[saved_id])?.remove(&saved_id).unwrap_or_default();
+            Ok((
+                outcome,
+                WordCardEntry {
+                    card: saved,
+                    senses: merged_senses,
+                    pronunciations: merged_pronunciations,
+                    tags: saved_tags,
+                },
+            ))
+        })?;
+
+        Ok(result)
+    }
+
+    fn update(&self, card: &WordCard) -> RepositoryResult<()> {
+        let mut conn = self.connection()?;
+        let updated_rows = diesel::update(word_cards.filter(id.eq(card.id)))
+            .set(card)
+            .execute(&mut conn)?;
+
+        if updated_rows == 0 {
+            return Err(RepositoryError::NotFound(format!("找不到單字卡: {}", card.id.unwrap_or_default())));
+        }
+        Ok(())
+    }
+
+    fn update_familiarity(&self, card_id: i32, level: i32) -> RepositoryResult<bool> {
+        let mut conn = self.connection()?;
+        let updated_rows = diesel::update(word_cards.filter(id.eq(Some(card_id))))
+            .set(familiarity.eq(Some(level)))
+            .execute(&mut conn)?;
+        Ok(updated_rows > 0)
+    }
+
+    fn increment_seen_count(&self, card_id: i32) -> RepositoryResult<bool> {
+        let mut conn = self.connection()?;
+        let updated_rows = diesel::update(word_cards.filter(id.eq(Some(card_id))))
+            .set(seen_count.eq(seen_count + 1))
+            .execute(&mut conn)?;
+        Ok(updated_rows > 0)
+    }
+
+    fn restore_review_history(&self, card_id: i32, state: &ReviewState, logs: &[NewReviewLog]) -> RepositoryResult<bool> {
+        let mut conn = self.connection()?;
+        let updated = conn.transaction::<_, Error, _>(|conn| {
+            let updated_rows = diesel::update(word_cards.filter(id.eq(Some(card_id))))
+                .set((
+                    ease_factor.eq(state.ease_factor),
+                    interval_days.eq(state.interval_days),
+                    repetitions.eq(state.repetitions),
+                    due_at.eq(state.due_at.as_ref().map(format_timestamp)),
+                    stability.eq(state.stability),
+                    difficulty.eq(state.difficulty),
+                    last_reviewed_at.eq(state.last_reviewed_at.as_ref().map(format_timestamp)),
+                    familiarity.eq(Some(state.familiarity)),
+                    learning_step.eq(state.learning_step),
+                ))
+                .execute(conn)?;
+            if updated_rows == 0 {
+                return Ok(false);
+            }
+            for log in logs {
+                diesel::insert_into(schema::review_logs::table).values(log).execute(conn)?;
+            }
+            Ok(true)
+        })?;
+        Ok(updated)
+    }
+
+    fn delete(&self, card_id: i32) -> RepositoryResult<bool> {
+        let mut conn = self.connection()?;
+        let deleted_rows = conn.transaction::<_, Error, _>(|conn| delete_card(conn, card_id))?;
+        Ok(deleted_rows > 0)
+    }
+}
+
+/// 刪除單字卡及其詞義、發音、標籤與來源；呼叫端負責包在交易中
+pub(super) fn delete_card(conn: &mut SqliteConnection, card_id: i32) -> QueryResult<usize> {
+    senses::replace_senses(conn, card_id, &[])?;
+    pronunciations::replace_pronunciations(conn, card_id, &[])?;
+    tags::remove_card_tags(conn, card_id)?;
+    imports::remove_card_sources(conn, card_id)?;
+    diesel::delete(word_cards.filter(id.eq(Some(card_id)))).execute(conn)
+}
+
+/// `requested` 中不存在於 `found` 的 ID，用於產生找不到資料的錯誤訊息
+pub(super) fn missing_ids(requested: &BTreeSet<i32>, found: &[i32]) -> Vec<i32> {
+    requested.iter().copied().filter(|i| !found.contains(i)).collect()
+}
+
+
+/// 確認所有單字卡都存在；呼叫端負責包在交易中
+pub(super) fn ensure_cards_exist(conn: &mut SqliteConnection, card_ids: &BTreeSet<i32>) -> Result<(), TransactionError> {
+    let requested = card_ids.iter().map(|i| Some(*i)).collect::<Vec<_>>();
+    let mut found = Vec::with_capacity(requested.len());
+    for batch in requested.chunks(ID_BATCH_SIZE) {
+        found.extend(word_cards.filter(id.eq_any(batch)).select(id).load::<Option<i32>>(conn)?.into_iter().flatten());
+    }
+    match missing_ids(card_ids, &found).as_slice() {
+        [] => Ok(()),
+        missing => Err(TransactionError::Repository(RepositoryError::NotFound(format!("找不到單字卡: {:?}", missing)))),
+    }
+}
+
+/// 交易中可能發生的錯誤：資料庫錯誤或檢查未通過
+pub(super) enum TransactionError {
+    Database(Error),
+    Repository(RepositoryError),
+}
+
+impl From<Error> for TransactionError {
+    fn from(error: Error) -> Self {
+        TransactionError::Database(error)
+    }
+}
+
+impl From<TransactionError> for RepositoryError {
+    fn from(error: TransactionError) -> Self {
+        match error {
+            TransactionError::Database(error) => RepositoryError::from(error),
+            TransactionError::Repository(error) => error,
+        }
+    }
+}
+
+/// 每個查詢最多綁定的 ID 數；大量選取時分批查詢，避免超過 SQLite 的綁定參數上限
+pub(super) const ID_BATCH_SIZE: usize = 500;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::tests::{contract_tests, exercise_repository, memory_pool, Repositories};
+
+    #[test]
+    fn test_diesel_repository_contract() {
+        exercise_repository(&DieselWordCardRepository::new(memory_pool()));
+    }
+
+    contract_tests!(Repositories::diesel =>
+        search_ranks_word_matches_first,
+        list_words_returns_every_card_by_id,
+    );
+}
@@ -0,0 +1,125 @@
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::MutexGuard;
+
+use crate::domain::aggregates::deck::{Deck, DeckCounts, DEFAULT_DECK_ID};
+use crate::domain::repositories::deck_repository::DeckRepository;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::domain::value_objects::review::format_timestamp;
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的牌組儲存庫；與同一個 store 建立的單字卡儲存庫共用單字卡資料
+pub struct InMemoryDeckRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryDeckRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+impl DeckRepository for InMemoryDeckRepository {
+    fn list_decks(&self) -> RepositoryResult<Vec<Deck>> {
+        let mut decks = self.state().decks.clone();
+        decks.sort_by_key(|d| d.id);
+        Ok(decks)
+    }
+
+    fn find_deck(&self, deck_id: i32) -> RepositoryResult<Option<Deck>> {
+        Ok(self.state().decks.iter().find(|d| d.id == Some(deck_id)).cloned())
+    }
+
+    fn save_deck(&self, deck: &Deck) -> RepositoryResult<Deck> {
+        let mut state = self.state();
+        match deck.id {
+            Some(deck_id) => {
+                let existing = state
+                    .decks
+                    .iter_mut()
+                    .find(|d| d.id == Some(deck_id))
+                    .ok_or_else(|| RepositoryError::NotFound(format!("找不到牌組: {}", deck_id)))?;
+                *existing = Deck { created_at: existing.created_at.clone(), ..deck.clone() };
+                Ok(existing.clone())
+            }
+            None => {
+                state.last_deck_id += 1;
+                let created = Deck {
+                    id: Some(state.last_deck_id),
+                    created_at: Some(format_timestamp(&Utc::now().naive_utc())),
+                    ..deck.clone()
+                };
+                state.decks.push(created.clone());
+                Ok(created)
+            }
+        }
+    }
+
+    fn delete_deck(&self, deck_id: i32) -> RepositoryResult<bool> {
+        if deck_id == DEFAULT_DECK_ID {
+            return Err(RepositoryError::Conflict("預設牌組不能刪除".to_string()));
+        }
+        let mut state = self.state();
+        let Some(parent_id) = state.decks.iter().find(|d| d.id == Some(deck_id)).map(|d| d.parent_id) else {
+            return Ok(false);
+        };
+
+        for child in state.decks.iter_mut().filter(|d| d.parent_id == Some(deck_id)) {
+            child.parent_id = parent_id;
+        }
+        for card in state.cards.iter_mut().filter(|c| c.deck_id == deck_id) {
+            card.deck_id = parent_id.unwrap_or(DEFAULT_DECK_ID);
+        }
+        state.decks.retain(|d| d.id != Some(deck_id));
+        Ok(true)
+    }
+
+    fn move_cards(&self, card_ids: &[i32], deck_id: i32) -> RepositoryResult<usize> {
+        let mut state = self.state();
+        if !state.decks.iter().any(|d| d.id == Some(deck_id)) {
+            return Err(RepositoryError::NotFound(format!("找不到牌組: {}", deck_id)));
+        }
+        state.ensure_exist(card_ids, &[])?;
+
+        let mut moved = 0;
+        for card in state.cards.iter_mut() {
+            if card.id.is_some_and(|i| card_ids.contains(&i)) && card.deck_id != deck_id {
+                card.deck_id = deck_id;
+                moved += 1;
+            }
+        }
+        Ok(moved)
+    }
+
+    fn count_cards_by_deck(&self, due_before: &str) -> RepositoryResult<HashMap<i32, DeckCounts>> {
+        let state = self.state();
+        let mut counts = HashMap::<i32, DeckCounts>::new();
+        for card in &state.cards {
+            let due = card.learning_step.is_none() && card.due_at.as_deref().is_some_and(|d| d < due_before);
+            let entry = counts.entry(card.deck_id).or_default();
+            entry.new += i64::from(card.due_at.is_none());
+            entry.learning += i64::from(card.learning_step.is_some());
+            entry.due += i64::from(due);
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::in_memory =>
+        default_deck_exists,
+        save_deck_creates_and_renames,
+        move_cards_skips_cards_already_in_deck,
+        move_cards_requires_existing_deck_and_cards,
+        count_cards_by_deck_separates_new_learning_and_due,
+        delete_deck_moves_children_and_cards_up,
+        default_deck_cannot_be_deleted,
+    );
+}
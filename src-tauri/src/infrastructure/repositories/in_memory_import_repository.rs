@@ -0,0 +1,164 @@
+use chrono::Utc;
+use std::sync::MutexGuard;
+
+use crate::domain::entities::imports::{
+    CardSource, ImportOutcome, ImportRecord, ImportStatus, ImportUndoSummary, ImportedCard, NewCardSource,
+    NewImportRecord,
+};
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::format_timestamp;
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的匯入紀錄儲存庫；與同一個 store 建立的單字卡儲存庫共用單字卡資料
+pub struct InMemoryImportRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryImportRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+impl ImportRepository for InMemoryImportRepository {
+    fn create_import(&self, record: &NewImportRecord) -> RepositoryResult<ImportRecord> {
+        let mut state = self.state();
+        state.last_import_id += 1;
+        let created = ImportRecord {
+            id: Some(state.last_import_id),
+            file_name: record.file_name.clone(),
+            file_hash: record.file_hash.clone(),
+            status: ImportStatus::Running.as_str().to_string(),
+            total_count: record.total_count,
+            success_count: 0,
+            exists_count: 0,
+            failed_count: 0,
+            created_at: Some(format_timestamp(&Utc::now().naive_utc())),
+            finished_at: None,
+        };
+        state.imports.push(created.clone());
+        Ok(created)
+    }
+
+    fn finish_import(&self, import_id: i32, outcome: &ImportOutcome) -> RepositoryResult<ImportRecord> {
+        let mut state = self.state();
+        let record = state.import_mut(import_id)?;
+        record.status = outcome.status.as_str().to_string();
+        record.success_count = outcome.success_count;
+        record.exists_count = outcome.exists_count;
+        record.failed_count = outcome.failed_count;
+        record.finished_at = Some(format_timestamp(&Utc::now().naive_utc()));
+        Ok(record.clone())
+    }
+
+    fn list_imports(&self) -> RepositoryResult<Vec<ImportRecord>> {
+        let mut imports = self.state().imports.clone();
+        imports.reverse();
+        Ok(imports)
+    }
+
+    fn find_import(&self, import_id: i32) -> RepositoryResult<Option<ImportRecord>> {
+        Ok(self.state().imports.iter().find(|i| i.id == Some(import_id)).cloned())
+    }
+
+    fn add_card_source(&self, source: &NewCardSource) -> RepositoryResult<CardSource> {
+        let mut state = self.state();
+        state.ensure_exist(&[source.card_id], &[])?;
+        if let Some(import_id) = source.import_id {
+            state.import_mut(import_id)?;
+        }
+        if let Some(card) = state.cards.iter_mut().find(|c| c.id == Some(source.card_id)) {
+            card.occurrence_count = card.occurrence_count.saturating_add(source.occurrence_count);
+        }
+
+        let existing = state
+            .card_sources
+            .iter_mut()
+            .find(|s| s.card_id == source.card_id && s.kind == source.kind && s.import_id == source.import_id);
+        if let Some(existing) = existing {
+            existing.created_card |= source.created_card;
+            existing.occurrence_count = existing.occurrence_count.saturating_add(source.occurrence_count);
+            return Ok(existing.clone());
+        }
+
+        state.last_source_id += 1;
+        let created = CardSource {
+            id: Some(state.last_source_id),
+            card_id: source.card_id,
+            kind: source.kind.clone(),
+            import_id: source.import_id,
+            created_card: source.created_card,
+            occurrence_count: source.occurrence_count,
+            created_at: Some(format_timestamp(&Utc::now().naive_utc())),
+        };
+        state.card_sources.push(created.clone());
+        Ok(created)
+    }
+
+    fn list_card_sources(&self, card_id: i32) -> RepositoryResult<Vec<CardSource>> {
+        Ok(self.state().card_sources.iter().filter(|s| s.card_id == card_id).cloned().collect())
+    }
+
+    fn list_import_cards(&self, import_id: i32) -> RepositoryResult<Vec<ImportedCard>> {
+        let state = self.state();
+        let mut cards = state
+            .card_sources
+            .iter()
+            .filter(|s| s.import_id == Some(import_id))
+            .filter_map(|s| {
+                let card = state.cards.iter().find(|c| c.id == Some(s.card_id))?;
+                Some((s.clone(), card.clone()))
+            })
+            .collect::<Vec<_>>();
+        cards.sort_by(|(_, a), (_, b)| a.word_key.cmp(&b.word_key));
+        Ok(cards)
+    }
+
+    fn undo_import(&self, import_id: i32) -> RepositoryResult<ImportUndoSummary> {
+        let mut state = self.state();
+        state.import_mut(import_id)?;
+
+        let (sources, remaining) = std::mem::take(&mut state.card_sources)
+            .into_iter()
+            .partition::<Vec<_>, _>(|s| s.import_id == Some(import_id));
+        state.card_sources = remaining;
+
+        let mut summary = ImportUndoSummary::default();
+        for source in sources {
+            let has_other_sources = state.card_sources.iter().any(|s| s.card_id == source.card_id);
+            if source.created_card && !has_other_sources {
+                summary.deleted_count += usize::from(state.remove_card(source.card_id));
+                continue;
+            }
+            if let Some(card) = state.cards.iter_mut().find(|c| c.id == Some(source.card_id)) {
+                card.occurrence_count = (card.occurrence_count - source.occurrence_count).max(0);
+            }
+            summary.updated_count += 1;
+        }
+
+        state.import_mut(import_id)?.status = ImportStatus::Undone.as_str().to_string();
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::in_memory =>
+        create_import_starts_running,
+        card_sources_accumulate_per_import,
+        card_sources_require_existing_card_and_import,
+        finish_import_records_outcome,
+        imports_are_listed_newest_first,
+        import_cards_are_sorted_by_word,
+        undo_import_removes_only_cards_it_created,
+        undo_import_requires_existing_import,
+        deleting_card_removes_its_sources,
+    );
+}
@@ -0,0 +1,51 @@
+use std::sync::MutexGuard;
+
+use crate::domain::entities::profiles::Profile;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::SchedulerKind;
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的設定檔儲存庫，一開始只有使用 SM-2 的預設設定檔
+pub struct InMemoryProfileRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryProfileRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+impl ProfileRepository for InMemoryProfileRepository {
+    fn find_active_profile(&self) -> RepositoryResult<Option<Profile>> {
+        Ok(self
+            .state()
+            .profiles
+            .iter()
+            .filter(|p| p.is_active == 1)
+            .min_by_key(|p| p.id)
+            .cloned())
+    }
+
+    fn update_scheduler(&self, profile_id: i32, kind: SchedulerKind) -> RepositoryResult<Option<Profile>> {
+        Ok(self.state().profiles.iter_mut().find(|p| p.id == Some(profile_id)).map(|profile| {
+            profile.scheduler = kind.as_str().to_string();
+            profile.clone()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::in_memory =>
+        default_profile_uses_sm2,
+        update_scheduler_returns_updated_profile,
+    );
+}
@@ -0,0 +1,115 @@
+use std::sync::MutexGuard;
+
+use crate::domain::entities::review_logs::{NewReviewLog, ReviewLog};
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::repositories::review_repository::{ReviewCounts, ReviewLogFilter, ReviewRepository};
+use crate::domain::repositories::word_card_repository::RepositoryResult;
+use crate::domain::value_objects::review::ReviewState;
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的作答紀錄與複習佇列儲存庫；與同一個 store 建立的單字卡儲存庫共用單字卡資料
+pub struct InMemoryReviewRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryReviewRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+fn in_decks(card: &WordCard, deck_ids: Option<&[i32]>) -> bool {
+    deck_ids.is_none_or(|ids| ids.contains(&card.deck_id))
+}
+
+/// 依序取出前 `limit` 張卡片
+fn take(mut cards: Vec<WordCard>, limit: i64) -> Vec<WordCard> {
+    cards.truncate(limit.max(0) as usize);
+    cards
+}
+
+impl ReviewRepository for InMemoryReviewRepository {
+    fn record_review(&self, card_id: i32, state: &ReviewState, log: &NewReviewLog) -> RepositoryResult<Option<WordCard>> {
+        let mut store = self.state();
+        let Some(card) = store.apply_review_state(card_id, state) else { return Ok(None) };
+        card.seen_count = Some(card.seen_count.unwrap_or(0) + 1);
+        let reviewed = card.clone();
+        store.add_review_log(log);
+        Ok(Some(reviewed))
+    }
+
+    fn list_review_logs(&self, filter: &ReviewLogFilter, limit: i64) -> RepositoryResult<Vec<ReviewLog>> {
+        let mut logs = self
+            .state()
+            .review_logs
+            .iter()
+            .filter(|l| filter.card_id.is_none_or(|c| l.card_id == c))
+            .filter(|l| filter.since.as_ref().is_none_or(|since| &l.reviewed_at >= since))
+            .filter(|l| filter.until.as_ref().is_none_or(|until| &l.reviewed_at < until))
+            .cloned()
+            .collect::<Vec<_>>();
+        logs.sort_by(|a, b| b.reviewed_at.cmp(&a.reviewed_at).then(b.id.cmp(&a.id)));
+        logs.truncate(limit.max(0) as usize);
+        Ok(logs)
+    }
+
+    fn count_reviews_since(&self, since: &str, deck_ids: Option<&[i32]>) -> RepositoryResult<ReviewCounts> {
+        let state = self.state();
+        let mut counts = ReviewCounts::default();
+        for log in state.review_logs.iter().filter(|l| l.reviewed_at.as_str() >= since) {
+            let card_in_decks = state.cards.iter().any(|c| c.id == Some(log.card_id) && in_decks(c, deck_ids));
+            if !card_in_decks {
+                continue;
+            }
+            // 作答前 due_at 為空代表該次作答是新卡片的第一次學習
+            if log.previous_review_state().is_none_or(|s| s.is_new()) {
+                counts.new += 1;
+            } else {
+                counts.reviews += 1;
+            }
+        }
+        Ok(counts)
+    }
+
+    fn list_due_cards(&self, due_before: &str, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>> {
+        let mut cards = self
+            .state()
+            .cards
+            .iter()
+            .filter(|c| in_decks(c, deck_ids) && c.due_at.as_deref().is_some_and(|d| d < due_before))
+            .cloned()
+            .collect::<Vec<_>>();
+        cards.sort_by(|a, b| a.due_at.cmp(&b.due_at).then(a.id.cmp(&b.id)));
+        Ok(take(cards, limit))
+    }
+
+    fn list_new_cards(&self, deck_ids: Option<&[i32]>, limit: i64) -> RepositoryResult<Vec<WordCard>> {
+        let mut cards = self
+            .state()
+            .cards
+            .iter()
+            .filter(|c| in_decks(c, deck_ids) && c.due_at.is_none())
+            .cloned()
+            .collect::<Vec<_>>();
+        cards.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        Ok(take(cards, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::in_memory =>
+        record_review_updates_state_and_writes_log,
+        record_review_skips_missing_cards,
+        review_logs_are_filtered_newest_first,
+        reviews_since_are_split_into_new_and_reviews,
+        due_cards_are_ordered_by_due_time,
+        new_cards_are_ordered_by_creation,
+    );
+}
@@ -0,0 +1,180 @@
+use chrono::Utc;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::domain::aggregates::deck::{Deck, DeckSettings, DEFAULT_DECK_ID};
+use crate::domain::entities::imports::{CardSource, ImportRecord};
+use crate::domain::entities::profiles::Profile;
+use crate::domain::entities::review_logs::{NewReviewLog, ReviewLog};
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::tags::{NewTag, Tag, TagCount};
+use crate::domain::entities::word_cards::WordCard;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::domain::value_objects::pronunciation::Pronunciation;
+use crate::domain::value_objects::review::{format_timestamp, ReviewState, SchedulerKind};
+
+/// 記憶體儲存庫共用的資料，相當於 SQLite 實作共用的連接池
+///
+/// 複製後指向同一份資料，因此以同一個 store 建立的各個儲存庫可以看到彼此的寫入。
+#[derive(Clone)]
+pub struct InMemoryStore {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+pub(super) struct State {
+    pub(super) cards: Vec<WordCard>,
+    pub(super) senses: HashMap<i32, Vec<Sense>>,
+    pub(super) pronunciations: HashMap<i32, Vec<Pronunciation>>,
+    pub(super) last_id: i32,
+    pub(super) tags: Vec<Tag>,
+    /// (card_id, tag_id)
+    pub(super) card_tags: BTreeSet<(i32, i32)>,
+    pub(super) last_tag_id: i32,
+    pub(super) decks: Vec<Deck>,
+    pub(super) last_deck_id: i32,
+    pub(super) imports: Vec<ImportRecord>,
+    pub(super) last_import_id: i32,
+    pub(super) card_sources: Vec<CardSource>,
+    pub(super) last_source_id: i32,
+    pub(super) review_logs: Vec<ReviewLog>,
+    pub(super) last_review_log_id: i32,
+    pub(super) profiles: Vec<Profile>,
+}
+
+impl State {
+    /// 刪除單字卡及其詞義、發音、標籤、來源與作答紀錄；回傳是否有卡片被刪除
+    pub(super) fn remove_card(&mut self, card_id: i32) -> bool {
+        let before = self.cards.len();
+        self.cards.retain(|c| c.id != Some(card_id));
+        self.senses.remove(&card_id);
+        self.pronunciations.remove(&card_id);
+        self.card_tags.retain(|(c, _)| *c != card_id);
+        self.card_sources.retain(|s| s.card_id != card_id);
+        self.review_logs.retain(|l| l.card_id != card_id);
+        self.cards.len() < before
+    }
+
+    /// 寫入排程狀態；回傳單字卡，不存在時為 `None`
+    pub(super) fn apply_review_state(&mut self, card_id: i32, state: &ReviewState) -> Option<&mut WordCard> {
+        let card = self.cards.iter_mut().find(|c| c.id == Some(card_id))?;
+        card.ease_factor = state.ease_factor;
+        card.interval_days = state.interval_days;
+        card.repetitions = state.repetitions;
+        card.due_at = state.due_at.as_ref().map(format_timestamp);
+        card.stability = state.stability;
+        card.difficulty = state.difficulty;
+        card.last_reviewed_at = state.last_reviewed_at.as_ref().map(format_timestamp);
+        card.familiarity = Some(state.familiarity);
+        card.learning_step = state.learning_step;
+        Some(card)
+    }
+
+    pub(super) fn add_review_log(&mut self, log: &NewReviewLog) {
+        self.last_review_log_id += 1;
+        self.review_logs.push(ReviewLog {
+            id: Some(self.last_review_log_id),
+            card_id: log.card_id,
+            reviewed_at: log.reviewed_at.clone(),
+            grade: log.grade,
+            scheduler: log.scheduler.clone(),
+            elapsed_days: log.elapsed_days,
+            duration_ms: log.duration_ms,
+            previous_state: log.previous_state.clone(),
+            new_state: log.new_state.clone(),
+        });
+    }
+
+    pub(super) fn import_mut(&mut self, import_id: i32) -> RepositoryResult<&mut ImportRecord> {
+        self.imports
+            .iter_mut()
+            .find(|i| i.id == Some(import_id))
+            .ok_or_else(|| RepositoryError::NotFound(format!("找不到匯入紀錄: {}", import_id)))
+    }
+
+    pub(super) fn tags_of(&self, card_id: i32) -> Vec<Tag> {
+        let mut tags = self
+            .tags
+            .iter()
+            .filter(|t| t.id.is_some_and(|tag_id| self.card_tags.contains(&(card_id, tag_id))))
+            .cloned()
+            .collect::<Vec<_>>();
+        tags.sort_by(|a, b| a.name_key.cmp(&b.name_key));
+        tags
+    }
+
+    fn tag_exists(&self, tag_id: i32) -> bool {
+        self.tags.iter().any(|t| t.id == Some(tag_id))
+    }
+
+    pub(super) fn tag_count(&self, tag: &Tag) -> TagCount {
+        let card_count = self.card_tags.iter().filter(|(_, t)| Some(*t) == tag.id).count() as i64;
+        TagCount { tag: tag.clone(), card_count }
+    }
+
+    /// 找出不存在的單字卡或標籤，回傳找不到資料的錯誤
+    pub(super) fn ensure_exist(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<()> {
+        let mut missing_cards = card_ids
+            .iter()
+            .copied()
+            .filter(|c| !self.cards.iter().any(|card| card.id == Some(*c)))
+            .collect::<Vec<_>>();
+        if !missing_cards.is_empty() {
+            missing_cards.sort_unstable();
+            missing_cards.dedup();
+            return Err(RepositoryError::NotFound(format!("找不到單字卡: {:?}", missing_cards)));
+        }
+        let mut missing_tags = tag_ids.iter().copied().filter(|t| !self.tag_exists(*t)).collect::<Vec<_>>();
+        if !missing_tags.is_empty() {
+            missing_tags.sort_unstable();
+            missing_tags.dedup();
+            return Err(RepositoryError::NotFound(format!("找不到標籤: {:?}", missing_tags)));
+        }
+        Ok(())
+    }
+
+    pub(super) fn ensure_unique_name(&self, tag: &NewTag, except: Option<i32>) -> RepositoryResult<()> {
+        if self.tags.iter().any(|t| t.name_key == tag.name_key && t.id != except) {
+            return Err(RepositoryError::Conflict(format!("標籤 '{}' 已存在", tag.name)));
+        }
+        Ok(())
+    }
+}
+
+impl InMemoryStore {
+    /// 與 migration 相同，一開始就有預設牌組與使用 SM-2 的預設設定檔
+    pub fn new() -> Self {
+        let now = format_timestamp(&Utc::now().naive_utc());
+        let default_deck = Deck {
+            id: Some(DEFAULT_DECK_ID),
+            name: "Default".to_string(),
+            parent_id: None,
+            settings: DeckSettings::default(),
+            created_at: Some(now.clone()),
+        };
+        let default_profile = Profile {
+            id: Some(1),
+            name: "default".to_string(),
+            scheduler: SchedulerKind::Sm2.as_str().to_string(),
+            is_active: 1,
+            created_at: Some(now),
+        };
+        let state = State {
+            decks: vec![default_deck],
+            last_deck_id: DEFAULT_DECK_ID,
+            profiles: vec![default_profile],
+            ..State::default()
+        };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    pub(super) fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for InMemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::Utc;
+use std::collections::BTreeSet;
+use std::sync::MutexGuard;
+
+use crate::domain::entities::tags::{NewTag, Tag, TagCount};
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::repositories::word_card_repository::{RepositoryError, RepositoryResult};
+use crate::domain::value_objects::review::format_timestamp;
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的標籤儲存庫；與同一個 store 建立的單字卡儲存庫共用單字卡資料
+pub struct InMemoryTagRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryTagRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+
+impl TagRepository for InMemoryTagRepository {
+    fn list_tags(&self) -> RepositoryResult<Vec<TagCount>> {
+        let state = self.state();
+        let mut tags = state.tags.iter().map(|t| state.tag_count(t)).collect::<Vec<_>>();
+        tags.sort_by(|a, b| a.tag.name_key.cmp(&b.tag.name_key).then(a.tag.id.cmp(&b.tag.id)));
+        Ok(tags)
+    }
+
+    fn find_tag(&self, tag_id: i32) -> RepositoryResult<Option<Tag>> {
+        Ok(self.state().tags.iter().find(|t| t.id == Some(tag_id)).cloned())
+    }
+
+    fn create_tag(&self, tag: &NewTag) -> RepositoryResult<Tag> {
+        let mut state = self.state();
+        state.ensure_unique_name(tag, None)?;
+
+        state.last_tag_id += 1;
+        let created = Tag {
+            id: Some(state.last_tag_id),
+            name: tag.name.clone(),
+            name_key: tag.name_key.clone(),
+            created_at: Some(format_timestamp(&Utc::now().naive_utc())),
+        };
+        state.tags.push(created.clone());
+        Ok(created)
+    }
+
+    fn rename_tag(&self, tag_id: i32, tag: &NewTag) -> RepositoryResult<Tag> {
+        let mut state = self.state();
+        state.ensure_exist(&[], &[tag_id])?;
+        state.ensure_unique_name(tag, Some(tag_id))?;
+
+        let existing = state
+            .tags
+            .iter_mut()
+            .find(|t| t.id == Some(tag_id))
+            .ok_or_else(|| RepositoryError::NotFound(format!("找不到標籤: {}", tag_id)))?;
+        existing.name.clone_from(&tag.name);
+        existing.name_key.clone_from(&tag.name_key);
+        Ok(existing.clone())
+    }
+
+    fn merge_tags(&self, source_ids: &[i32], target_id: i32) -> RepositoryResult<TagCount> {
+        let mut state = self.state();
+        state.ensure_exist(&[], &[target_id])?;
+        state.ensure_exist(&[], source_ids)?;
+
+        let sources = source_ids.iter().copied().filter(|i| *i != target_id).collect::<BTreeSet<_>>();
+        let moved = state
+            .card_tags
+            .iter()
+            .filter(|(_, t)| sources.contains(t))
+            .map(|(c, _)| (*c, target_id))
+            .collect::<Vec<_>>();
+        state.card_tags.retain(|(_, t)| !sources.contains(t));
+        state.card_tags.extend(moved);
+        state.tags.retain(|t| !t.id.is_some_and(|i| sources.contains(&i)));
+
+        let target = state
+            .tags
+            .iter()
+            .find(|t| t.id == Some(target_id))
+            .ok_or_else(|| RepositoryError::NotFound(format!("找不到標籤: {}", target_id)))?;
+        Ok(state.tag_count(target))
+    }
+
+    fn delete_tag(&self, tag_id: i32) -> RepositoryResult<bool> {
+        let mut state = self.state();
+        let before = state.tags.len();
+        state.tags.retain(|t| t.id != Some(tag_id));
+        state.card_tags.retain(|(_, t)| *t != tag_id);
+        Ok(state.tags.len() < before)
+    }
+
+    fn assign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize> {
+        let mut state = self.state();
+        state.ensure_exist(card_ids, tag_ids)?;
+
+        let mut inserted = 0;
+        for card_id in card_ids {
+            for tag_id in tag_ids {
+                if state.card_tags.insert((*card_id, *tag_id)) {
+                    inserted += 1;
+                }
+            }
+        }
+        Ok(inserted)
+    }
+
+    fn unassign_tags(&self, card_ids: &[i32], tag_ids: &[i32]) -> RepositoryResult<usize> {
+        let mut state = self.state();
+        let before = state.card_tags.len();
+        state
+            .card_tags
+            .retain(|(c, t)| !(card_ids.contains(c) && tag_ids.contains(t)));
+        Ok(before - state.card_tags.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repositories::tests::{contract_tests, Repositories};
+
+    contract_tests!(Repositories::in_memory =>
+        tag_names_are_unique_ignoring_case,
+        assign_tags_skips_existing_pairs,
+        assign_tags_requires_existing_cards_and_tags,
+        tag_expressions_filter_cards,
+        card_details_include_sorted_tags,
+        rename_tag_rejects_other_tag_names,
+        merge_tags_moves_cards_to_target,
+        bulk_tag_selections_are_batched,
+        deleting_removes_tag_assignments,
+    );
+}
@@ -0,0 +1,392 @@
+use chrono::Utc;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::sync::MutexGuard;
+
+use crate::domain::aggregates::deck::DEFAULT_DECK_ID;
+use crate::domain::entities::review_logs::NewReviewLog;
+use crate::domain::entities::senses::Sense;
+use crate::domain::entities::tags::Tag;
+use crate::domain::entities::word_cards::{MergePolicy, NewWordCard, SaveOutcome, WordCard, WordCardEntry};
+use crate::domain::repositories::word_card_repository::{
+    FullTextMatch, RepositoryError, RepositoryResult, SortDirection, WordCardFilter, WordCardRepository,
+    WordCardSortKey, HIGHLIGHT_END, HIGHLIGHT_START,
+};
+use crate::domain::value_objects::pronunciation::Pronunciation;
+use crate::domain::value_objects::review::{format_timestamp, ReviewState};
+use crate::infrastructure::repositories::in_memory_store::{InMemoryStore, State};
+
+/// 存放在記憶體中的單字卡儲存庫，供單元測試與不需要資料庫的情境使用
+///
+/// 行為與 SQLite 實作一致：新卡片的預設值與資料表的 DEFAULT 相同，篩選與排序規則也相同。
+pub struct InMemoryWordCardRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryWordCardRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.store.state()
+    }
+}
+
+fn matches(card: &WordCard, filter: &WordCardFilter, card_tags: &BTreeSet<(i32, i32)>) -> bool {
+    if let Some(text) = filter.text.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        let needle = text.to_lowercase();
+        let in_word = card.word.to_lowercase().contains(&needle);
+        let in_definition = card
+            .definition
+            .as_deref()
+            .is_some_and(|d| d.to_lowercase().contains(&needle));
+        if !in_word && !in_definition {
+            return false;
+        }
+    }
+
+    if let Some(levels) = &filter.familiarity {
+        if !card.familiarity.is_some_and(|f| levels.contains(&f)) {
+            return false;
+        }
+    }
+
+    if let Some(from) = &filter.created_from {
+        let after_start = card.created_at.as_ref().is_some_and(|c| c >= from);
+        if !after_start {
+            return false;
+        }
+    }
+
+    if let Some(to) = &filter.created_to {
+        let before_end = card.created_at.as_ref().is_some_and(|c| c < to);
+        if !before_end {
+            return false;
+        }
+    }
+
+    if let Some(ids) = &filter.deck_ids {
+        if !ids.contains(&card.deck_id) {
+            return false;
+        }
+    }
+
+    if let Some(expression) = &filter.tags {
+        let card_id = card.id.unwrap_or_default();
+        if !expression.matches(&|tag_id| card_tags.contains(&(card_id, tag_id))) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn compare(a: &WordCard, b: &WordCard, sort_key: WordCardSortKey) -> Ordering {
+    match sort_key {
+        WordCardSortKey::Word => a.word.to_lowercase().cmp(&b.word.to_lowercase()),
+        WordCardSortKey::Familiarity => a.familiarity.cmp(&b.familiarity),
+        WordCardSortKey::CreatedAt => a.created_at.cmp(&b.created_at),
+    }
+}
+
+/// 文字中以任一檢索詞開頭的詞的位元組範圍；切詞規則與 `search_terms` 相同，比對不分大小寫
+fn prefix_hits(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let mut hits = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let in_token = c.is_alphanumeric() || c == '\'' || c == '-';
+        match (in_token, start) {
+            (true, None) => start = Some(index),
+            (false, Some(begin)) => {
+                start = None;
+                let token = &text[begin..index];
+                let trimmed = token.trim_matches(['\'', '-']);
+                let lowered = trimmed.to_lowercase();
+                if !trimmed.is_empty() && terms.iter().any(|term| lowered.starts_with(term.as_str())) {
+                    let offset = begin + token.len() - token.trim_start_matches(['\'', '-']).len();
+                    hits.push(offset..offset + trimmed.len());
+                }
+            }
+            _ => {}
+        }
+    }
+    hits
+}
+
+/// 以 `<mark>` 標示命中的詞，與 SQLite 全文檢索的摘要格式相同
+fn highlight(text: &str, hits: &[Range<usize>]) -> String {
+    let mut snippet = String::with_capacity(text.len());
+    let mut last = 0;
+    for hit in hits {
+        snippet.push_str(&text[last..hit.start]);
+        snippet.push_str(HIGHLIGHT_START);
+        snippet.push_str(&text[hit.clone()]);
+        snippet.push_str(HIGHLIGHT_END);
+        last = hit.end;
+    }
+    snippet.push_str(&text[last..]);
+    snippet
+}
+
+impl WordCardRepository for InMemoryWordCardRepository {
+    fn find_by_id(&self, card_id: i32) -> RepositoryResult<Option<WordCard>> {
+        Ok(self.state().cards.iter().find(|c| c.id == Some(card_id)).cloned())
+    }
+
+    fn find_by_key(&self, key: &str) -> RepositoryResult<Option<WordCard>> {
+        Ok(self
+            .state()
+            .cards
+            .iter()
+            .find(|c| c.word_key.as_deref() == Some(key))
+            .cloned())
+    }
+
+    fn find_by_ids(&self, card_ids: &[i32]) -> RepositoryResult<Vec<WordCard>> {
+        Ok(self
+            .state()
+            .cards
+            .iter()
+            .filter(|c| c.id.is_some_and(|i| card_ids.contains(&i)))
+            .cloned()
+            .collect())
+    }
+
+    fn list(
+        &self,
+        filter: &WordCardFilter,
+        sort_key: WordCardSortKey,
+        sort_direction: SortDirection,
+        offset: i64,
+        limit: i64,
+    ) -> RepositoryResult<Vec<WordCard>> {
+        let state = self.state();
+        let mut cards = state
+            .cards
+            .iter()
+            .filter(|c| matches(c, filter, &state.card_tags))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        cards.sort_by(|a, b| {
+            let ordering = match sort_direction {
+                SortDirection::Asc => compare(a, b, sort_key),
+                SortDirection::Desc => compare(b, a, sort_key),
+            };
+            ordering.then(a.id.cmp(&b.id))
+        });
+
+        Ok(cards
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    fn count(&self, filter: &WordCardFilter) -> RepositoryResult<i64> {
+        let state = self.state();
+        Ok(state.cards.iter().filter(|c| matches(c, filter, &state.card_tags)).count() as i64)
+    }
+
+    fn search(&self, terms: &[String], limit: i64) -> RepositoryResult<Vec<FullTextMatch>> {
+        let terms = terms.iter().map(|t| t.to_lowercase()).collect::<Vec<_>>();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let state = self.state();
+        let mut found = state
+            .cards
+            .iter()
+            .filter_map(|card| {
+                let definition = card.definition.as_deref().unwrap_or_default();
+                let in_word = |term: &String| !prefix_hits(&card.word, std::slice::from_ref(term)).is_empty();
+                let in_definition = |term: &String| !prefix_hits(definition, std::slice::from_ref(term)).is_empty();
+                if !terms.iter().all(|t| in_word(t) || in_definition(t)) {
+                    return None;
+                }
+                // 單字欄位命中的權重高於定義，與 bm25 的欄位權重一致：分數越小越相關
+                let weight = 10 * terms.iter().filter(|t| in_word(t)).count() + terms.iter().filter(|t| in_definition(t)).count();
+                Some(FullTextMatch {
+                    card: card.clone(),
+                    score: -(weight as f64),
+                    snippet: highlight(definition, &prefix_hits(definition, &terms)),
+                })
+            })
+            .collect::<Vec<_>>();
+        found.sort_by(|a, b| a.score.total_cmp(&b.score).then(a.card.id.cmp(&b.card.id)));
+        found.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(found)
+    }
+
+    fn list_words(&self) -> RepositoryResult<Vec<(i32, String)>> {
+        let state = self.state();
+        let mut words = state
+            .cards
+            .iter()
+            .filter_map(|c| c.id.map(|id| (id, c.word.clone())))
+            .collect::<Vec<_>>();
+        words.sort_by_key(|(id, _)| *id);
+        Ok(words)
+    }
+
+    fn find_senses(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Sense>>> {
+        let state = self.state();
+        Ok(card_ids
+            .iter()
+            .filter_map(|i| state.senses.get(i).filter(|s| !s.is_empty()).map(|s| (*i, s.clone())))
+            .collect())
+    }
+
+    fn find_pronunciations(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Pronunciation>>> {
+        let state = self.state();
+        Ok(card_ids
+            .iter()
+            .filter_map(|i| state.pronunciations.get(i).filter(|p| !p.is_empty()).map(|p| (*i, p.clone())))
+            .collect())
+    }
+
+    fn find_tags(&self, card_ids: &[i32]) -> RepositoryResult<HashMap<i32, Vec<Tag>>> {
+        let state = self.state();
+        Ok(card_ids
+            .iter()
+            .map(|i| (*i, state.tags_of(*i)))
+            .filter(|(_, tags)| !tags.is_empty())
+            .collect())
+    }
+
+    fn save(&self, card: &NewWordCard, policy: MergePolicy) -> RepositoryResult<(SaveOutcome, WordCardEntry)> {
+        let card_key = card
+            .word_key
+            .clone()
+            .ok_or_else(|| RepositoryError::Storage(format!("單字 '{}' 尚未正規化", card.word)))?;
+        let mut state = self.state();
+        let existing_tags = state
+            .cards
+            .iter()
+            .find(|c| c.word_key.as_deref() == Some(&card_key))
+            .and_then(|c| c.id)
+            .map(|card_id| state.tags_of(card_id))
+            .unwrap_or_default();
+        let State { cards, senses, pronunciations, last_id, .. } = &mut *state;
+
+        if let Some(existing) = cards.iter_mut().find(|c| c.word_key.as_deref() == Some(&card_key)) {
+            let card_id = existing.id.unwrap_or_default();
+            let mut entry = WordCardEntry {
+                card: existing.clone(),
+                senses: senses.remove(&card_id).unwrap_or_default(),
+                pronunciations: pronunciations.remove(&card_id).unwrap_or_default(),
+                tags: existing_tags,
+            };
+            let outcome = entry.apply_incoming(card, policy);
+            entry.card.seen_count = Some(entry.card.seen_count.unwrap_or(0) + 1);
+            *existing = entry.card.clone();
+            senses.insert(card_id, entry.senses.clone());
+            pronunciations.insert(card_id, entry.pronunciations.clone());
+            return Ok((outcome, entry));
+        }
+
+        *last_id += 1;
+        let defaults = ReviewState::default();
+        let inserted = WordCard {
+            id: Some(*last_id),
+            word: card.word.clone(),
+            pos: card.pos.clone(),
+            definition: card.definition.clone(),
+            verbs: card.verbs.clone(),
+            familiarity: Some(card.familiarity.unwrap_or(0)),
+            seen_count: Some(card.seen_count.unwrap_or(1)),
+            created_at: Some(format_timestamp(&Utc::now().naive_utc())),
+            ease_factor: defaults.ease_factor,
+            interval_days: defaults.interval_days,
+            repetitions: defaults.repetitions,
+            due_at: None,
+            stability: None,
+            difficulty: None,
+            last_reviewed_at: None,
+            word_key: Some(card_key),
+            deck_id: DEFAULT_DECK_ID,
+            learning_step: None,
+            occurrence_count: 0,
+        };
+        cards.push(inserted.clone());
+        senses.insert(*last_id, card.senses.clone());
+        pronunciations.insert(*last_id, card.pronunciations.clone());
+        let entry = WordCardEntry {
+            card: inserted,
+            senses: card.senses.clone(),
+            pronunciations: card.pronunciations.clone(),
+            tags: Vec::new(),
+        };
+        Ok((SaveOutcome::Inserted, entry))
+    }
+
+    fn update(&self, card: &WordCard) -> RepositoryResult<()> {
+        let mut state = self.state();
+        if let Some(key) = &card.word_key {
+            if state.cards.iter().any(|c| c.id != card.id && c.word_key.as_ref() == Some(key)) {
+                return Err(RepositoryError::Conflict(format!("資料已存在: word_key '{}'", key)));
+            }
+        }
+
+        match state.cards.iter_mut().find(|c| c.id == card.id) {
+            Some(existing) => {
+                *existing = card.clone();
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound(format!("找不到單字卡: {}", card.id.unwrap_or_default()))),
+        }
+    }
+
+    fn update_familiarity(&self, card_id: i32, level: i32) -> RepositoryResult<bool> {
+        Ok(match self.state().cards.iter_mut().find(|c| c.id == Some(card_id)) {
+            Some(card) => {
+                card.familiarity = Some(level);
+                true
+            }
+            None => false,
+        })
+    }
+
+    fn increment_seen_count(&self, card_id: i32) -> RepositoryResult<bool> {
+        Ok(match self.state().cards.iter_mut().find(|c| c.id == Some(card_id)) {
+            Some(card) => {
+                card.seen_count = Some(card.seen_count.unwrap_or(0) + 1);
+                true
+            }
+            None => false,
+        })
+    }
+
+    fn restore_review_history(&self, card_id: i32, state: &ReviewState, logs: &[NewReviewLog]) -> RepositoryResult<bool> {
+        let mut store = self.state();
+        if store.apply_review_state(card_id, state).is_none() {
+            return Ok(false);
+        }
+        for log in logs {
+            store.add_review_log(log);
+        }
+        Ok(true)
+    }
+
+    fn delete(&self, card_id: i32) -> RepositoryResult<bool> {
+        Ok(self.state().remove_card(card_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::tests::{contract_tests, exercise_repository, Repositories};
+
+    #[test]
+    fn test_in_memory_repository_contract() {
+        exercise_repository(&InMemoryWordCardRepository::new(InMemoryStore::new()));
+    }
+
+    contract_tests!(Repositories::in_memory =>
+        search_ranks_word_matches_first,
+        list_words_returns_every_card_by_id,
+    );
+}
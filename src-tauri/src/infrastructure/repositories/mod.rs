@@ -1 +1,717 @@
-// Repository implementations will be defined here
+pub mod diesel_deck_repository;
+pub mod diesel_import_repository;
+pub mod diesel_profile_repository;
+pub mod diesel_review_repository;
+pub mod diesel_tag_repository;
+pub mod diesel_word_card_repository;
+pub mod in_memory_deck_repository;
+pub mod in_memory_import_repository;
+pub mod in_memory_profile_repository;
+pub mod in_memory_review_repository;
+pub mod in_memory_store;
+pub mod in_memory_tag_repository;
+pub mod in_memory_word_card_repository;
+
+pub use diesel_deck_repository::DieselDeckRepository;
+pub use diesel_import_repository::DieselImportRepository;
+pub use diesel_profile_repository::DieselProfileRepository;
+pub use diesel_review_repository::DieselReviewRepository;
+pub use diesel_tag_repository::DieselTagRepository;
+pub use diesel_word_card_repository::DieselWordCardRepository;
+pub use in_memory_deck_repository::InMemoryDeckRepository;
+pub use in_memory_import_repository::InMemoryImportRepository;
+pub use in_memory_profile_repository::InMemoryProfileRepository;
+pub use in_memory_review_repository::InMemoryReviewRepository;
+pub use in_memory_store::InMemoryStore;
+pub use in_memory_tag_repository::InMemoryTagRepository;
+pub use in_memory_word_card_repository::InMemoryWordCardRepository;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::domain::aggregates::deck::{Deck, DeckSettings, DEFAULT_DECK_ID};
+    use crate::domain::entities::imports::{ImportOutcome, ImportStatus, NewCardSource, NewImportRecord};
+    use crate::domain::entities::review_logs::NewReviewLog;
+    use crate::domain::entities::tags::NewTag;
+    use crate::domain::entities::word_cards::{MergePolicy, NewWordCard, SaveOutcome};
+    use crate::domain::repositories::deck_repository::DeckRepository;
+    use crate::domain::repositories::import_repository::ImportRepository;
+    use crate::domain::repositories::profile_repository::ProfileRepository;
+    use crate::domain::repositories::review_repository::{ReviewCounts, ReviewLogFilter, ReviewRepository};
+    use crate::domain::repositories::tag_repository::TagRepository;
+    use crate::domain::repositories::word_card_repository::{
+        search_terms, RepositoryError, SortDirection, WordCardFilter, WordCardRepository, WordCardSortKey,
+    };
+    use crate::domain::value_objects::pronunciation::{Pronunciation, Region};
+    use crate::domain::value_objects::review::{parse_timestamp, ReviewState, SchedulerKind};
+    use crate::domain::value_objects::tag::{TagExpression, TagName};
+    use crate::infrastructure::persistence::migrations::run_migrations;
+    use crate::infrastructure::persistence::DbPool;
+    use crate::infrastructure::repositories::{
+        DieselDeckRepository, DieselImportRepository, DieselProfileRepository, DieselReviewRepository,
+        DieselTagRepository, DieselWordCardRepository, InMemoryDeckRepository, InMemoryImportRepository,
+        InMemoryProfileRepository, InMemoryReviewRepository, InMemoryStore, InMemoryTagRepository,
+        InMemoryWordCardRepository,
+    };
+    use diesel::r2d2::{ConnectionManager, Pool};
+    use diesel::SqliteConnection;
+
+    /// 為每個共用行為測試產生一個 `#[test]`，以指定的函式建立儲存庫
+    macro_rules! contract_tests {
+        ($repositories:path => $($name:ident),+ $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    crate::infrastructure::repositories::tests::$name(&$repositories());
+                }
+            )+
+        };
+    }
+    pub(crate) use contract_tests;
+
+    /// 共用同一份資料的各個儲存庫，與應用程式中從同一個連接池建立的儲存庫相同
+    pub(crate) struct Repositories {
+        pub(crate) cards: Box<dyn WordCardRepository>,
+        pub(crate) tags: Box<dyn TagRepository>,
+        pub(crate) decks: Box<dyn DeckRepository>,
+        pub(crate) imports: Box<dyn ImportRepository>,
+        pub(crate) reviews: Box<dyn ReviewRepository>,
+        pub(crate) profiles: Box<dyn ProfileRepository>,
+    }
+
+    impl Repositories {
+        pub(crate) fn diesel() -> Self {
+            let pool = memory_pool();
+            Self {
+                cards: Box::new(DieselWordCardRepository::new(pool.clone())),
+                tags: Box::new(DieselTagRepository::new(pool.clone())),
+                decks: Box::new(DieselDeckRepository::new(pool.clone())),
+                imports: Box::new(DieselImportRepository::new(pool.clone())),
+                reviews: Box::new(DieselReviewRepository::new(pool.clone())),
+                profiles: Box::new(DieselProfileRepository::new(pool)),
+            }
+        }
+
+        pub(crate) fn in_memory() -> Self {
+            let store = InMemoryStore::new();
+            Self {
+                cards: Box::new(InMemoryWordCardRepository::new(store.clone())),
+                tags: Box::new(InMemoryTagRepository::new(store.clone())),
+                decks: Box::new(InMemoryDeckRepository::new(store.clone())),
+                imports: Box::new(InMemoryImportRepository::new(store.clone())),
+                reviews: Box::new(InMemoryReviewRepository::new(store.clone())),
+                profiles: Box::new(InMemoryProfileRepository::new(store)),
+            }
+        }
+    }
+
+    /// 已套用 migration 的記憶體資料庫
+    pub(crate) fn memory_pool() -> DbPool {
+        // 記憶體資料庫每個連接各自獨立，因此連接池只保留一個連接
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(ConnectionManager::<SqliteConnection>::new(":memory:"))
+            .unwrap();
+        run_migrations(&mut pool.get().unwrap()).unwrap();
+        pool
+    }
+
+    /// 測試用的單字卡內容，尚未正規化；需要驗證正規化的測試直接使用
+    pub(crate) fn card_input(word: &str, definition: &str) -> NewWordCard {
+        NewWordCard {
+            word: word.to_string(),
+            pos: None,
+            definition: Some(definition.to_string()),
+            verbs: None,
+            familiarity: None,
+            seen_count: None,
+            word_key: None,
+            senses: Vec::new(),
+            pronunciations: Vec::new(),
+        }
+    }
+
+    /// 正規化後的測試用單字卡，可直接交給儲存庫保存
+    pub(crate) fn new_card(word: &str, definition: &str) -> NewWordCard {
+        card_input(word, definition).normalized().unwrap()
+    }
+
+    /// 新增一張單字卡並回傳其 ID
+    pub(crate) fn add_card(cards: &dyn WordCardRepository, word: &str) -> i32 {
+        let (_, entry) = cards.save(&new_card(word, "[noun] a fruit"), MergePolicy::KeepExisting).unwrap();
+        entry.card.id.unwrap()
+    }
+
+    /// 兩種實作共用的行為測試，確保記憶體實作可以取代 SQLite 實作
+    pub(crate) fn exercise_repository(repository: &dyn WordCardRepository) {
+        let (outcome, banana) = repository.save(&new_card("Banana", "[noun] a yellow fruit"), MergePolicy::KeepExisting).unwrap();
+        assert_eq!(outcome, SaveOutcome::Inserted);
+        assert_eq!(banana.card.familiarity, Some(0));
+        assert_eq!(banana.card.seen_count, Some(1));
+        assert_eq!(banana.senses[0].definition, "a yellow fruit");
+        let mut apple_card = new_card("apple", "[noun] a round fruit");
+        apple_card.pronunciations = vec![Pronunciation {
+            region: Some(Region::Us),
+            ipa: Some("ˈæp.əl".to_string()),
+            ..Pronunciation::default()
+        }];
+        let (_, apple) = repository.save(&apple_card, MergePolicy::KeepExisting).unwrap();
+        repository.save(&new_card("cherry", "[noun] a small red fruit"), MergePolicy::KeepExisting).unwrap();
+
+        // 重複保存只增加查看次數
+        let (outcome, again) = repository.save(&new_card(" Apple", "[noun] ignored"), MergePolicy::KeepExisting).unwrap();
+        assert_eq!(outcome, SaveOutcome::Updated);
+        assert_eq!(again.card.id, apple.card.id);
+        assert_eq!(again.card.seen_count, Some(2));
+        assert_eq!(again.card.definition.as_deref(), Some("[noun] a round fruit"));
+
+        // 合併定義時附加新的詞義
+        let (outcome, merged) = repository
+            .save(&new_card("apple", "[noun] a tech company\n例句: Apple makes phones."), MergePolicy::MergeDefinitions)
+            .unwrap();
+        assert_eq!(outcome, SaveOutcome::Merged);
+        assert_eq!(merged.senses.len(), 2);
+        assert_eq!(merged.senses[1].examples, ["Apple makes phones."]);
+
+        let apple_id = apple.card.id.unwrap();
+        assert_eq!(repository.find_by_key("apple").unwrap().unwrap().id, apple.card.id);
+        let entries = repository.with_details(repository.find_by_ids(&[apple_id]).unwrap()).unwrap();
+        assert_eq!(entries[0].senses, merged.senses);
+        assert_eq!(entries[0].pronunciations, apple_card.pronunciations);
+        assert!(repository.find_by_key("durian").unwrap().is_none());
+        assert_eq!(repository.find_by_id(apple_id).unwrap().unwrap().word, "apple");
+        assert_eq!(repository.find_by_ids(&[apple_id, 999]).unwrap().len(), 1);
+
+        // 排序不分大小寫，篩選比對單字與定義
+        let all = WordCardFilter::default();
+        let words = |cards: Vec<crate::domain::entities::word_cards::WordCard>| {
+            cards.into_iter().map(|c| c.word).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            words(repository.list(&all, WordCardSortKey::Word, SortDirection::Asc, 0, 10).unwrap()),
+            ["apple", "Banana", "cherry"]
+        );
+        assert_eq!(
+            words(repository.list(&all, WordCardSortKey::Word, SortDirection::Desc, 1, 1).unwrap()),
+            ["Banana"]
+        );
+        let red = WordCardFilter {
+            text: Some("RED".to_string()),
+            ..WordCardFilter::default()
+        };
+        assert_eq!(repository.count(&red).unwrap(), 1);
+        assert_eq!(repository.count(&all).unwrap(), 3);
+
+        assert!(repository.update_familiarity(apple_id, 3).unwrap());
+        assert!(repository.increment_seen_count(apple_id).unwrap());
+        assert!(!repository.update_familiarity(999, 1).unwrap());
+        let cherry_id = repository.find_by_key("cherry").unwrap().unwrap().id.unwrap();
+        let restored = ReviewState {
+            interval_days: 12,
+            repetitions: 3,
+            due_at: parse_timestamp("2030-01-01 00:00:00"),
+            familiarity: 2,
+            ..ReviewState::default()
+        };
+        assert!(repository.restore_review_history(cherry_id, &restored, &[]).unwrap());
+        assert!(!repository.restore_review_history(999, &restored, &[]).unwrap());
+        let cherry = repository.find_by_id(cherry_id).unwrap().unwrap();
+        assert_eq!(cherry.review_state().interval_days, 12);
+        assert_eq!((cherry.due_at.as_deref(), cherry.familiarity), (Some("2030-01-01 00:00:00"), Some(2)));
+        let known = WordCardFilter {
+            familiarity: Some(vec![3]),
+            ..WordCardFilter::default()
+        };
+        let updated = repository.list(&known, WordCardSortKey::Word, SortDirection::Asc, 0, 10).unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].seen_count, Some(4));
+
+        let mut edited = updated[0].clone();
+        edited.interval_days = 6;
+        repository.update(&edited).unwrap();
+        assert_eq!(repository.find_by_id(apple_id).unwrap().unwrap().interval_days, 6);
+
+        assert!(repository.delete(apple_id).unwrap());
+        assert!(!repository.delete(apple_id).unwrap());
+        assert!(repository.find_senses(&[apple_id]).unwrap().is_empty());
+        assert!(repository.find_pronunciations(&[apple_id]).unwrap().is_empty());
+        assert_eq!(repository.count(&all).unwrap(), 2);
+    }
+
+    pub(crate) fn search_ranks_word_matches_first(repositories: &Repositories) {
+        let cards = &repositories.cards;
+        let save = |word: &str, definition: &str| {
+            let (_, entry) = cards.save(&new_card(word, definition), MergePolicy::KeepExisting).unwrap();
+            entry.card.id.unwrap()
+        };
+        let harvest = save("harvest", "[verb] to gather a crop");
+        let reap = save("reap", "[verb] to harvest a crop");
+        save("sow", "[verb] to plant seeds");
+
+        let hits = cards.search(&search_terms("harv"), 10).unwrap();
+        assert_eq!(hits.iter().map(|h| h.card.id.unwrap()).collect::<Vec<_>>(), [harvest, reap]);
+        assert!(hits[0].score < hits[1].score);
+        assert_eq!(hits[1].snippet, "[verb] to <mark>harvest</mark> a crop");
+
+        let hits = cards.search(&search_terms("crop reap"), 10).unwrap();
+        assert_eq!(hits.iter().map(|h| h.card.id.unwrap()).collect::<Vec<_>>(), [reap]);
+        assert_eq!(cards.search(&search_terms("crop"), 1).unwrap().len(), 1);
+        assert!(cards.search(&search_terms("wheat"), 10).unwrap().is_empty());
+        assert!(cards.search(&[], 10).unwrap().is_empty());
+    }
+
+    pub(crate) fn list_words_returns_every_card_by_id(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let words = repositories.cards.list_words().unwrap();
+        assert_eq!(
+            words,
+            [(apple, "apple".to_string()), (banana, "banana".to_string()), (cherry, "cherry".to_string())]
+        );
+    }
+
+    fn create_tag(repositories: &Repositories, name: &str) -> i32 {
+        let tag = NewTag::from(&TagName::parse(name).unwrap());
+        repositories.tags.create_tag(&tag).unwrap().id.unwrap()
+    }
+
+    /// 新增 apple、banana、cherry 三張單字卡，回傳其 ID
+    fn fruit_cards(repositories: &Repositories) -> [i32; 3] {
+        ["apple", "banana", "cherry"].map(|word| add_card(repositories.cards.as_ref(), word))
+    }
+
+    pub(crate) fn tag_names_are_unique_ignoring_case(repositories: &Repositories) {
+        create_tag(repositories, "Fruit");
+        let duplicate = NewTag::from(&TagName::parse("fruit ").unwrap());
+        assert!(matches!(repositories.tags.create_tag(&duplicate), Err(RepositoryError::Conflict(_))));
+    }
+
+    pub(crate) fn assign_tags_skips_existing_pairs(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let (fruit, red) = (create_tag(repositories, "Fruit"), create_tag(repositories, "red"));
+
+        assert_eq!(repositories.tags.assign_tags(&[apple, banana, cherry], &[fruit]).unwrap(), 3);
+        assert_eq!(repositories.tags.assign_tags(&[apple], &[fruit, red]).unwrap(), 1);
+        let counts = repositories.tags.list_tags().unwrap();
+        assert_eq!(counts.iter().map(|c| (c.tag.name.as_str(), c.card_count)).collect::<Vec<_>>(), [("Fruit", 3), ("red", 1)]);
+    }
+
+    pub(crate) fn assign_tags_requires_existing_cards_and_tags(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let red = create_tag(repositories, "red");
+
+        assert!(matches!(repositories.tags.assign_tags(&[apple, 999], &[red]), Err(RepositoryError::NotFound(_))));
+        assert!(matches!(repositories.tags.assign_tags(&[apple], &[999]), Err(RepositoryError::NotFound(_))));
+        assert_eq!(repositories.tags.list_tags().unwrap()[0].card_count, 0);
+    }
+
+    pub(crate) fn tag_expressions_filter_cards(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let [fruit, red, crimson] = ["Fruit", "red", "Crimson"].map(|name| create_tag(repositories, name));
+        repositories.tags.assign_tags(&[apple, banana, cherry], &[fruit]).unwrap();
+        repositories.tags.assign_tags(&[apple], &[red]).unwrap();
+        repositories.tags.assign_tags(&[cherry], &[crimson]).unwrap();
+
+        // 有 fruit 且沒有 red
+        let filter = |expression: TagExpression| WordCardFilter { tags: Some(expression), ..WordCardFilter::default() };
+        let fruit_not_red = filter(TagExpression::And(vec![
+            TagExpression::Tag(fruit),
+            TagExpression::Not(Box::new(TagExpression::Tag(red))),
+        ]));
+        let found = repositories.cards.list(&fruit_not_red, WordCardSortKey::Word, SortDirection::Asc, 0, 10).unwrap();
+        assert_eq!(found.iter().map(|c| c.word.as_str()).collect::<Vec<_>>(), ["banana", "cherry"]);
+        let red_or_crimson = filter(TagExpression::Or(vec![TagExpression::Tag(red), TagExpression::Tag(crimson)]));
+        assert_eq!(repositories.cards.count(&red_or_crimson).unwrap(), 2);
+        assert_eq!(repositories.cards.count(&filter(TagExpression::Or(Vec::new()))).unwrap(), 0);
+    }
+
+    pub(crate) fn card_details_include_sorted_tags(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let tag_ids = ["red", "Fruit"].map(|name| create_tag(repositories, name));
+        repositories.tags.assign_tags(&[apple], &tag_ids).unwrap();
+
+        let entries = repositories.cards.with_details(repositories.cards.find_by_ids(&[apple]).unwrap()).unwrap();
+        assert_eq!(entries[0].tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(), ["Fruit", "red"]);
+    }
+
+    pub(crate) fn rename_tag_rejects_other_tag_names(repositories: &Repositories) {
+        let tag = |name: &str| NewTag::from(&TagName::parse(name).unwrap());
+        let red = create_tag(repositories, "red");
+        create_tag(repositories, "Crimson");
+
+        // 只改大小寫不算衝突，改成其他標籤的名稱則衝突
+        assert_eq!(repositories.tags.rename_tag(red, &tag("Red")).unwrap().name, "Red");
+        assert!(matches!(repositories.tags.rename_tag(red, &tag("crimson")), Err(RepositoryError::Conflict(_))));
+        assert!(matches!(repositories.tags.rename_tag(999, &tag("x")), Err(RepositoryError::NotFound(_))));
+    }
+
+    pub(crate) fn merge_tags_moves_cards_to_target(repositories: &Repositories) {
+        let [apple, _, cherry] = fruit_cards(repositories);
+        let [red, crimson] = ["red", "Crimson"].map(|name| create_tag(repositories, name));
+        repositories.tags.assign_tags(&[apple, cherry], &[red]).unwrap();
+        repositories.tags.assign_tags(&[cherry], &[crimson]).unwrap();
+
+        // 目標標籤也在來源中時不會被刪除，兩個標籤都有的單字卡只算一次
+        let merged = repositories.tags.merge_tags(&[crimson, red], red).unwrap();
+        assert_eq!((merged.tag.id, merged.card_count), (Some(red), 2));
+        assert!(repositories.tags.find_tag(crimson).unwrap().is_none());
+        assert_eq!(repositories.tags.list_tags().unwrap().len(), 1);
+        assert!(matches!(repositories.tags.merge_tags(&[999], red), Err(RepositoryError::NotFound(_))));
+    }
+
+    pub(crate) fn bulk_tag_selections_are_batched(repositories: &Repositories) {
+        let [apple, banana, _] = fruit_cards(repositories);
+        let [fruit, red] = ["Fruit", "red"].map(|name| create_tag(repositories, name));
+        repositories.tags.assign_tags(&[apple, banana], &[fruit]).unwrap();
+        repositories.tags.assign_tags(&[apple], &[red]).unwrap();
+
+        // 大量選取時分批處理，不受 SQLite 綁定參數上限影響
+        let selection = [apple, banana].into_iter().chain(100_000..140_000).collect::<Vec<_>>();
+        assert_eq!(repositories.cards.find_by_ids(&selection).unwrap().len(), 2);
+        assert_eq!(repositories.cards.find_tags(&selection).unwrap().len(), 2);
+        assert_eq!(repositories.tags.unassign_tags(&selection, &[fruit, red]).unwrap(), 3);
+    }
+
+    pub(crate) fn deleting_removes_tag_assignments(repositories: &Repositories) {
+        let [apple, banana, _] = fruit_cards(repositories);
+        let [fruit, red] = ["Fruit", "red"].map(|name| create_tag(repositories, name));
+        repositories.tags.assign_tags(&[apple, banana], &[fruit, red]).unwrap();
+
+        assert!(repositories.cards.delete(apple).unwrap());
+        assert!(repositories.tags.delete_tag(red).unwrap());
+        assert!(!repositories.tags.delete_tag(red).unwrap());
+        let counts = repositories.tags.list_tags().unwrap();
+        assert_eq!(counts.iter().map(|c| (c.tag.id, c.card_count)).collect::<Vec<_>>(), [(Some(fruit), 1)]);
+        assert!(repositories.cards.find_tags(&[banana]).unwrap()[&banana].iter().all(|t| t.id == Some(fruit)));
+    }
+
+    fn create_deck(repositories: &Repositories, name: &str, parent_id: Option<i32>) -> i32 {
+        let settings = DeckSettings { learning_steps: vec![1, 10], ..DeckSettings::default() };
+        repositories.decks.save_deck(&Deck::new(name, parent_id, settings).unwrap()).unwrap().id.unwrap()
+    }
+
+    pub(crate) fn default_deck_exists(repositories: &Repositories) {
+        let decks = repositories.decks.list_decks().unwrap();
+        assert_eq!(decks.iter().map(|d| (d.id, d.name.as_str())).collect::<Vec<_>>(), [(Some(DEFAULT_DECK_ID), "Default")]);
+        let [apple, ..] = fruit_cards(repositories);
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().deck_id, DEFAULT_DECK_ID);
+    }
+
+    pub(crate) fn save_deck_creates_and_renames(repositories: &Repositories) {
+        let english_id = create_deck(repositories, "English", None);
+        let english = repositories.decks.find_deck(english_id).unwrap().unwrap();
+        assert_eq!(english.settings.learning_steps, [1, 10]);
+        assert!(english.created_at.is_some());
+
+        let mut renamed = english.clone();
+        renamed.rename("Languages").unwrap();
+        let saved = repositories.decks.save_deck(&renamed).unwrap();
+        assert_eq!((saved.name.as_str(), saved.created_at), ("Languages", english.created_at));
+        let missing = Deck { id: Some(999), ..renamed };
+        assert!(matches!(repositories.decks.save_deck(&missing), Err(RepositoryError::NotFound(_))));
+    }
+
+    pub(crate) fn move_cards_skips_cards_already_in_deck(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+
+        assert_eq!(repositories.decks.move_cards(&[apple, banana], toeic_id).unwrap(), 2);
+        assert_eq!(repositories.decks.move_cards(&[apple, cherry], toeic_id).unwrap(), 1);
+        let in_toeic = WordCardFilter { deck_ids: Some(vec![toeic_id]), ..WordCardFilter::default() };
+        assert_eq!(repositories.cards.count(&in_toeic).unwrap(), 3);
+    }
+
+    pub(crate) fn move_cards_requires_existing_deck_and_cards(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+
+        assert!(matches!(repositories.decks.move_cards(&[apple], 999), Err(RepositoryError::NotFound(_))));
+        assert!(matches!(repositories.decks.move_cards(&[apple, 999], toeic_id), Err(RepositoryError::NotFound(_))));
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().deck_id, DEFAULT_DECK_ID);
+    }
+
+    pub(crate) fn count_cards_by_deck_separates_new_learning_and_due(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+        repositories.decks.move_cards(&[apple, banana, cherry], toeic_id).unwrap();
+
+        // 一張學習中、一張已到期、一張新卡
+        let mut learning = repositories.cards.find_by_id(apple).unwrap().unwrap();
+        learning.due_at = Some("2025-01-01 09:10:00".to_string());
+        learning.learning_step = Some(1);
+        repositories.cards.update(&learning).unwrap();
+        let mut due = repositories.cards.find_by_id(banana).unwrap().unwrap();
+        due.due_at = Some("2025-01-01 00:00:00".to_string());
+        repositories.cards.update(&due).unwrap();
+
+        let counts = repositories.decks.count_cards_by_deck("2025-01-02 00:00:00").unwrap();
+        let toeic = counts[&toeic_id];
+        assert_eq!((toeic.new, toeic.learning, toeic.due), (1, 1, 1));
+        assert!(!counts.contains_key(&DEFAULT_DECK_ID));
+    }
+
+    pub(crate) fn delete_deck_moves_children_and_cards_up(repositories: &Repositories) {
+        let [apple, _, cherry] = fruit_cards(repositories);
+        let english_id = create_deck(repositories, "English", None);
+        let toeic_id = create_deck(repositories, "TOEIC", Some(english_id));
+        repositories.decks.move_cards(&[apple], toeic_id).unwrap();
+        repositories.decks.move_cards(&[cherry], english_id).unwrap();
+
+        // 刪除 English：TOEIC 改為最上層，English 本身的單字卡移到預設牌組
+        assert!(repositories.decks.delete_deck(english_id).unwrap());
+        assert!(!repositories.decks.delete_deck(english_id).unwrap());
+        assert_eq!(repositories.decks.find_deck(toeic_id).unwrap().unwrap().parent_id, None);
+        assert_eq!(repositories.cards.find_by_id(cherry).unwrap().unwrap().deck_id, DEFAULT_DECK_ID);
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().deck_id, toeic_id);
+
+        assert!(repositories.decks.delete_deck(toeic_id).unwrap());
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().deck_id, DEFAULT_DECK_ID);
+        assert_eq!(repositories.decks.list_decks().unwrap().len(), 1);
+    }
+
+    pub(crate) fn default_deck_cannot_be_deleted(repositories: &Repositories) {
+        assert!(matches!(repositories.decks.delete_deck(DEFAULT_DECK_ID), Err(RepositoryError::Conflict(_))));
+        assert!(repositories.decks.find_deck(DEFAULT_DECK_ID).unwrap().is_some());
+    }
+
+    fn create_import(repositories: &Repositories) -> i32 {
+        let record = NewImportRecord { file_name: Some("words.txt".to_string()), file_hash: Some("abc".to_string()), total_count: 3 };
+        repositories.imports.create_import(&record).unwrap().id.unwrap()
+    }
+
+    pub(crate) fn create_import_starts_running(repositories: &Repositories) {
+        let import_id = create_import(repositories);
+        let record = repositories.imports.find_import(import_id).unwrap().unwrap();
+        assert_eq!((record.status(), record.file_name.as_deref(), record.total_count), (ImportStatus::Running, Some("words.txt"), 3));
+        assert!(record.created_at.is_some());
+        assert!(record.finished_at.is_none());
+    }
+
+    pub(crate) fn card_sources_accumulate_per_import(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let import_id = create_import(repositories);
+
+        // 同一次匯入合併為一筆來源，查詢是另一種來源
+        repositories.imports.add_card_source(&NewCardSource::import(apple, import_id, true, 2)).unwrap();
+        let merged = repositories.imports.add_card_source(&NewCardSource::import(apple, import_id, false, 3)).unwrap();
+        assert!(merged.created_card);
+        assert_eq!(merged.occurrence_count, 5);
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().occurrence_count, 5);
+        repositories.imports.add_card_source(&NewCardSource::lookup(apple, false)).unwrap();
+        assert_eq!(repositories.imports.list_card_sources(apple).unwrap().len(), 2);
+    }
+
+    pub(crate) fn card_sources_require_existing_card_and_import(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+
+        assert!(matches!(
+            repositories.imports.add_card_source(&NewCardSource::import(apple, 999, true, 1)),
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert!(matches!(
+            repositories.imports.add_card_source(&NewCardSource::lookup(999, true)),
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert!(repositories.imports.list_card_sources(apple).unwrap().is_empty());
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().occurrence_count, 0);
+    }
+
+    pub(crate) fn finish_import_records_outcome(repositories: &Repositories) {
+        let import_id = create_import(repositories);
+
+        let outcome = ImportOutcome { status: ImportStatus::Completed, success_count: 2, exists_count: 1, failed_count: 0 };
+        let finished = repositories.imports.finish_import(import_id, &outcome).unwrap();
+        assert_eq!((finished.status(), finished.success_count, finished.exists_count), (ImportStatus::Completed, 2, 1));
+        assert!(finished.finished_at.is_some());
+        assert!(matches!(repositories.imports.finish_import(999, &outcome), Err(RepositoryError::NotFound(_))));
+    }
+
+    pub(crate) fn imports_are_listed_newest_first(repositories: &Repositories) {
+        let first = create_import(repositories);
+        let second = repositories.imports.create_import(&NewImportRecord { file_name: None, file_hash: None, total_count: 0 }).unwrap();
+
+        let listed = repositories.imports.list_imports().unwrap();
+        assert_eq!(listed.iter().map(|i| i.id).collect::<Vec<_>>(), [second.id, Some(first)]);
+    }
+
+    pub(crate) fn import_cards_are_sorted_by_word(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let import_id = create_import(repositories);
+        for card_id in [cherry, apple, banana] {
+            repositories.imports.add_card_source(&NewCardSource::import(card_id, import_id, true, 1)).unwrap();
+        }
+        repositories.imports.add_card_source(&NewCardSource::lookup(banana, false)).unwrap();
+
+        let cards = repositories.imports.list_import_cards(import_id).unwrap();
+        assert_eq!(cards.iter().map(|(_, card)| card.word.as_str()).collect::<Vec<_>>(), ["apple", "banana", "cherry"]);
+        assert!(cards.iter().all(|(source, _)| source.import_id == Some(import_id)));
+    }
+
+    pub(crate) fn undo_import_removes_only_cards_it_created(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let import_id = create_import(repositories);
+
+        // apple 由此匯入建立；banana 原本就有，只累加次數；cherry 由此匯入建立但之後也被手動查詢
+        repositories.imports.add_card_source(&NewCardSource::import(apple, import_id, true, 2)).unwrap();
+        repositories.imports.add_card_source(&NewCardSource::import(banana, import_id, false, 4)).unwrap();
+        repositories.imports.add_card_source(&NewCardSource::import(cherry, import_id, true, 1)).unwrap();
+        repositories.imports.add_card_source(&NewCardSource::lookup(cherry, false)).unwrap();
+        // banana 另外在較早的匯入中出現過 2 次
+        let earlier_id = create_import(repositories);
+        repositories.imports.add_card_source(&NewCardSource::import(banana, earlier_id, false, 2)).unwrap();
+
+        let summary = repositories.imports.undo_import(import_id).unwrap();
+        assert_eq!((summary.deleted_count, summary.updated_count), (1, 2));
+        assert!(repositories.cards.find_by_id(apple).unwrap().is_none());
+        assert_eq!(repositories.cards.find_by_id(banana).unwrap().unwrap().occurrence_count, 2);
+        assert!(repositories.cards.find_by_id(cherry).unwrap().is_some());
+        assert_eq!(repositories.imports.list_card_sources(cherry).unwrap().len(), 1);
+        assert!(repositories.imports.list_import_cards(import_id).unwrap().is_empty());
+        assert_eq!(repositories.imports.find_import(import_id).unwrap().unwrap().status(), ImportStatus::Undone);
+    }
+
+    pub(crate) fn undo_import_requires_existing_import(repositories: &Repositories) {
+        assert!(matches!(repositories.imports.undo_import(999), Err(RepositoryError::NotFound(_))));
+    }
+
+    pub(crate) fn deleting_card_removes_its_sources(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let import_id = create_import(repositories);
+        repositories.imports.add_card_source(&NewCardSource::import(apple, import_id, true, 1)).unwrap();
+        repositories.imports.add_card_source(&NewCardSource::lookup(apple, false)).unwrap();
+
+        assert!(repositories.cards.delete(apple).unwrap());
+        assert!(repositories.imports.list_card_sources(apple).unwrap().is_empty());
+        assert!(repositories.imports.list_import_cards(import_id).unwrap().is_empty());
+    }
+
+    /// 作答紀錄；`previous` 為作答前的排程狀態
+    fn review_log(card_id: i32, reviewed_at: &str, previous: &ReviewState) -> NewReviewLog {
+        NewReviewLog {
+            card_id,
+            reviewed_at: reviewed_at.to_string(),
+            grade: 3,
+            scheduler: "sm2".to_string(),
+            elapsed_days: 0,
+            duration_ms: None,
+            previous_state: serde_json::to_string(previous).unwrap(),
+            new_state: serde_json::to_string(&ReviewState::default()).unwrap(),
+        }
+    }
+
+    fn record(repositories: &Repositories, card_id: i32, reviewed_at: &str, previous: &ReviewState) {
+        let log = review_log(card_id, reviewed_at, previous);
+        repositories.reviews.record_review(card_id, &ReviewState::default(), &log).unwrap().unwrap();
+    }
+
+    /// 已排程、在 `due_at` 到期的狀態
+    fn scheduled(due_at: &str) -> ReviewState {
+        ReviewState { interval_days: 1, repetitions: 1, due_at: parse_timestamp(due_at), ..ReviewState::default() }
+    }
+
+    pub(crate) fn record_review_updates_state_and_writes_log(repositories: &Repositories) {
+        let [apple, ..] = fruit_cards(repositories);
+        let state = ReviewState { familiarity: 2, ..scheduled("2025-01-02 00:00:00") };
+
+        let reviewed = repositories
+            .reviews
+            .record_review(apple, &state, &review_log(apple, "2025-01-01 08:00:00", &ReviewState::default()))
+            .unwrap()
+            .unwrap();
+        assert_eq!((reviewed.due_at.as_deref(), reviewed.familiarity), (Some("2025-01-02 00:00:00"), Some(2)));
+        assert_eq!(reviewed.seen_count, Some(2));
+        assert_eq!(repositories.cards.find_by_id(apple).unwrap().unwrap().review_state(), reviewed.review_state());
+        let logs = repositories.reviews.list_review_logs(&ReviewLogFilter::default(), 10).unwrap();
+        assert_eq!(logs.iter().map(|l| (l.card_id, l.reviewed_at.as_str())).collect::<Vec<_>>(), [(apple, "2025-01-01 08:00:00")]);
+        assert_eq!(logs[0].previous_review_state(), Some(ReviewState::default()));
+    }
+
+    pub(crate) fn record_review_skips_missing_cards(repositories: &Repositories) {
+        let log = review_log(999, "2025-01-01 08:00:00", &ReviewState::default());
+        assert!(repositories.reviews.record_review(999, &ReviewState::default(), &log).unwrap().is_none());
+        assert!(repositories.reviews.list_review_logs(&ReviewLogFilter::default(), 10).unwrap().is_empty());
+    }
+
+    pub(crate) fn review_logs_are_filtered_newest_first(repositories: &Repositories) {
+        let [apple, banana, _] = fruit_cards(repositories);
+        record(repositories, apple, "2025-01-01 08:00:00", &ReviewState::default());
+        record(repositories, banana, "2025-01-02 08:00:00", &ReviewState::default());
+        record(repositories, apple, "2025-01-03 08:00:00", &scheduled("2025-01-02 00:00:00"));
+
+        let times = |filter: ReviewLogFilter, limit: i64| {
+            let logs = repositories.reviews.list_review_logs(&filter, limit).unwrap();
+            logs.into_iter().map(|l| l.reviewed_at[..10].to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(times(ReviewLogFilter::default(), 10), ["2025-01-03", "2025-01-02", "2025-01-01"]);
+        assert_eq!(times(ReviewLogFilter::default(), 1), ["2025-01-03"]);
+        assert_eq!(times(ReviewLogFilter { card_id: Some(apple), ..ReviewLogFilter::default() }, 10), ["2025-01-03", "2025-01-01"]);
+        let window = ReviewLogFilter {
+            since: Some("2025-01-02 00:00:00".to_string()),
+            until: Some("2025-01-03 08:00:00".to_string()),
+            ..ReviewLogFilter::default()
+        };
+        assert_eq!(times(window, 10), ["2025-01-02"]);
+    }
+
+    pub(crate) fn reviews_since_are_split_into_new_and_reviews(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+        repositories.decks.move_cards(&[cherry], toeic_id).unwrap();
+        record(repositories, apple, "2025-01-01 23:59:59", &ReviewState::default());
+        record(repositories, apple, "2025-01-02 08:00:00", &ReviewState::default());
+        record(repositories, banana, "2025-01-02 09:00:00", &scheduled("2025-01-02 00:00:00"));
+        record(repositories, cherry, "2025-01-02 10:00:00", &ReviewState::default());
+
+        let since = "2025-01-02 00:00:00";
+        assert_eq!(repositories.reviews.count_reviews_since(since, None).unwrap(), ReviewCounts { new: 2, reviews: 1 });
+        let in_default = repositories.reviews.count_reviews_since(since, Some(&[DEFAULT_DECK_ID])).unwrap();
+        assert_eq!(in_default, ReviewCounts { new: 1, reviews: 1 });
+        assert_eq!(repositories.reviews.count_reviews_since(since, Some(&[toeic_id])).unwrap(), ReviewCounts { new: 1, reviews: 0 });
+    }
+
+    pub(crate) fn due_cards_are_ordered_by_due_time(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+        repositories.decks.move_cards(&[cherry], toeic_id).unwrap();
+        repositories.cards.restore_review_history(apple, &scheduled("2025-01-01 12:00:00"), &[]).unwrap();
+        repositories.cards.restore_review_history(banana, &scheduled("2025-01-01 06:00:00"), &[]).unwrap();
+        repositories.cards.restore_review_history(cherry, &scheduled("2025-01-05 00:00:00"), &[]).unwrap();
+
+        let due = |due_before: &str, deck_ids: Option<&[i32]>, limit: i64| {
+            let cards = repositories.reviews.list_due_cards(due_before, deck_ids, limit).unwrap();
+            cards.into_iter().map(|c| c.word).collect::<Vec<_>>()
+        };
+        assert_eq!(due("2025-01-02 00:00:00", None, 10), ["banana", "apple"]);
+        assert_eq!(due("2025-01-02 00:00:00", None, 1), ["banana"]);
+        assert_eq!(due("2025-01-06 00:00:00", Some(&[toeic_id]), 10), ["cherry"]);
+        assert!(due("2025-01-01 00:00:00", None, 10).is_empty());
+    }
+
+    pub(crate) fn new_cards_are_ordered_by_creation(repositories: &Repositories) {
+        let [apple, banana, cherry] = fruit_cards(repositories);
+        let toeic_id = create_deck(repositories, "TOEIC", None);
+        repositories.decks.move_cards(&[cherry], toeic_id).unwrap();
+        repositories.cards.restore_review_history(apple, &scheduled("2025-01-01 12:00:00"), &[]).unwrap();
+
+        let new = |deck_ids: Option<&[i32]>, limit: i64| {
+            let cards = repositories.reviews.list_new_cards(deck_ids, limit).unwrap();
+            cards.into_iter().map(|c| c.id.unwrap()).collect::<Vec<_>>()
+        };
+        assert_eq!(new(None, 10), [banana, cherry]);
+        assert_eq!(new(None, 1), [banana]);
+        assert_eq!(new(Some(&[toeic_id]), 10), [cherry]);
+        assert!(new(None, 0).is_empty());
+    }
+
+    pub(crate) fn default_profile_uses_sm2(repositories: &Repositories) {
+        let profile = repositories.profiles.find_active_profile().unwrap().unwrap();
+        assert_eq!((profile.name.as_str(), profile.scheduler_kind()), ("default", SchedulerKind::Sm2));
+    }
+
+    pub(crate) fn update_scheduler_returns_updated_profile(repositories: &Repositories) {
+        let profile_id = repositories.profiles.find_active_profile().unwrap().unwrap().id.unwrap();
+
+        let updated = repositories.profiles.update_scheduler(profile_id, SchedulerKind::Fsrs).unwrap().unwrap();
+        assert_eq!(updated.scheduler_kind(), SchedulerKind::Fsrs);
+        assert_eq!(repositories.profiles.find_active_profile().unwrap().unwrap().scheduler_kind(), SchedulerKind::Fsrs);
+        assert!(repositories.profiles.update_scheduler(999, SchedulerKind::Sm2).unwrap().is_none());
+    }
+}
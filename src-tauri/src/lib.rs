@@ -1,4 +1,4 @@
-use log::{info, warn};
+use log::{error, info, warn};
 use crate::infrastructure::logging;
 
 // Domain layer - 領域層
@@ -54,17 +54,62 @@ pub fn run() {
     
     info!("Starting Tauri application...");
 
+    // 建立連接池並套用資料庫遷移；失敗時仍啟動應用程式，由前端顯示錯誤並提供重試
+    let pool = infrastructure::persistence::initialize_database();
+    if let Err(e) = &pool {
+        error!("資料庫初始化失敗: {}", e);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(infrastructure::persistence::Database::new(pool))
+        .manage(application::commands::imports::ImportJobs::new())
         .invoke_handler(
             tauri::generate_handler![
                 greet, // ✅ 這裡要有
+                presentation::commands::database::get_database_status,
+                presentation::commands::database::retry_database_initialization,
                 presentation::commands::word_cards::save_word_card,
                 presentation::commands::word_cards::get_word_card_by_word,
+                presentation::commands::word_cards::get_word_forms,
                 presentation::commands::word_cards::get_all_word_cards,
+                presentation::commands::word_cards::list_word_cards,
+                presentation::commands::word_cards::search_word_cards,
+                presentation::commands::word_cards::suggest_words,
                 presentation::commands::word_cards::update_word_card_familiarity,
                 presentation::commands::word_cards::delete_word_card,
                 presentation::commands::word_cards::increment_word_card_seen_count,
+                presentation::commands::tags::get_tags,
+                presentation::commands::tags::create_tag,
+                presentation::commands::tags::rename_tag,
+                presentation::commands::tags::merge_tags,
+                presentation::commands::tags::delete_tag,
+                presentation::commands::tags::assign_tags,
+                presentation::commands::tags::unassign_tags,
+                presentation::commands::decks::get_deck_counts,
+                presentation::commands::decks::create_deck,
+                presentation::commands::decks::rename_deck,
+                presentation::commands::decks::move_deck,
+                presentation::commands::decks::update_deck_settings,
+                presentation::commands::decks::delete_deck,
+                presentation::commands::decks::move_cards_to_deck,
+                presentation::commands::imports::start_import,
+                presentation::commands::imports::cancel_import,
+                presentation::commands::imports::get_import_report,
+                presentation::commands::imports::list_imports,
+                presentation::commands::imports::get_import_cards,
+                presentation::commands::imports::undo_import,
+                presentation::commands::imports::get_card_sources,
+                presentation::commands::vocabulary::extract_vocabulary,
+                presentation::commands::spreadsheets::preview_spreadsheet,
+                presentation::commands::spreadsheets::import_spreadsheet,
+                presentation::commands::anki::inspect_anki_package,
+                presentation::commands::anki::import_anki_package,
+                presentation::commands::review::review_word_card,
+                presentation::commands::review::get_review_logs,
+                presentation::commands::review::get_review_queue,
+                presentation::commands::profiles::get_active_profile,
+                presentation::commands::profiles::set_profile_scheduler,
                 // 測試和調試命令
                 presentation::commands::word_cards::get_all_word_cards_simple,
                 presentation::commands::word_cards::test_database_connection,
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use tauri::State;
+use super::{import_repository, word_card_repository};
+use crate::application::commands::anki::ImportAnkiPackage;
+use crate::application::dto::anki::{
+    AnkiImportReport, AnkiPackageSummary, ColumnField, ImportAnkiPackageCommand, InspectAnkiPackageQuery,
+};
+use crate::domain::entities::word_cards::MergePolicy;
+use crate::application::errors::AppError;
+use crate::application::queries::anki::InspectAnkiPackage;
+use crate::infrastructure::external::anki_package::AnkiPackageFile;
+use crate::infrastructure::persistence::Database;
+
+/// 檢視 Anki 套件（.apkg / .colpkg）的筆記類型、欄位對應與筆記、卡片、複習紀錄數量；不寫入資料庫
+///
+/// `field_map` 以 Anki 欄位名稱指定對應的單字卡欄位，未列出的欄位依名稱自動判斷。
+#[tauri::command(async)]
+pub fn inspect_anki_package(
+    path: String,
+    field_map: Option<HashMap<String, ColumnField>>,
+) -> Result<AnkiPackageSummary, AppError> {
+    let reader = AnkiPackageFile::new();
+    InspectAnkiPackage::new(&reader).handle(InspectAnkiPackageQuery { path, field_map: field_map.unwrap_or_default() })
+}
+
+/// 將 Anki 套件的筆記匯入為單字卡並記錄在匯入紀錄中；`include_history` 預設帶入排程狀態與作答紀錄
+#[tauri::command(async)]
+pub fn import_anki_package(
+    database: State<'_, Database>,
+    path: String,
+    field_map: Option<HashMap<String, ColumnField>>,
+    include_history: Option<bool>,
+    policy: Option<MergePolicy>,
+) -> Result<AnkiImportReport, AppError> {
+    let repository = word_card_repository(&database)?;
+    let imports = import_repository(&database)?;
+    let reader = AnkiPackageFile::new();
+    ImportAnkiPackage::new(repository.as_ref(), imports.as_ref(), &reader).handle(ImportAnkiPackageCommand {
+        path,
+        field_map: field_map.unwrap_or_default(),
+        include_history,
+        policy,
+    })
+}
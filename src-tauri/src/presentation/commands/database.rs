@@ -0,0 +1,40 @@
+use log::{info, error};
+use tauri::State;
+use crate::application::dto::database::DatabaseStatus;
+use crate::application::errors::AppError;
+use crate::infrastructure::persistence::{initialize_database, Database};
+
+fn status_of(database: &Database) -> DatabaseStatus {
+    match database.pool() {
+        Ok(_) => DatabaseStatus { ready: true, error: None },
+        Err(e) => DatabaseStatus { ready: false, error: Some(AppError::from(e)) },
+    }
+}
+
+/// 查詢資料庫是否已成功初始化
+#[tauri::command]
+pub fn get_database_status(database: State<'_, Database>) -> DatabaseStatus {
+    status_of(&database)
+}
+
+/// 重新初始化資料庫，用於啟動失敗且使用者排除問題（例如目錄權限）之後
+#[tauri::command]
+pub fn retry_database_initialization(database: State<'_, Database>) -> Result<DatabaseStatus, AppError> {
+    if database.is_ready() {
+        return Ok(status_of(&database));
+    }
+
+    info!("重新初始化資料庫");
+    match initialize_database() {
+        Ok(pool) => {
+            database.replace(Ok(pool));
+            info!("✅ 資料庫重新初始化成功");
+            Ok(status_of(&database))
+        }
+        Err(e) => {
+            error!("❌ 資料庫重新初始化失敗: {}", e);
+            database.replace(Err(e.clone()));
+            Err(AppError::from(e))
+        }
+    }
+}
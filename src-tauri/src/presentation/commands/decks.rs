@@ -0,0 +1,68 @@
+use chrono::Duration;
+use tauri::State;
+use super::deck_repository;
+use super::review::start_of_today_utc;
+use crate::application::commands::decks::{
+    CreateDeck, DeleteDeck, MoveCardsToDeck, MoveDeck, RenameDeck, UpdateDeckSettings,
+};
+use crate::application::dto::decks::{
+    CreateDeckCommand, Deck, DeckSettings, DeckSummary, DeleteDeckCommand, MoveCardsToDeckCommand, MoveDeckCommand,
+    RenameDeckCommand, UpdateDeckSettingsCommand,
+};
+use crate::application::errors::AppError;
+use crate::application::queries::decks::GetDeckCounts;
+use crate::domain::value_objects::review::format_timestamp;
+use crate::infrastructure::persistence::Database;
+
+/// 查詢所有牌組及其新卡片、學習中與今日到期的卡片數量，`total` 包含子牌組
+#[tauri::command]
+pub fn get_deck_counts(database: State<'_, Database>) -> Result<Vec<DeckSummary>, AppError> {
+    let repository = deck_repository(&database)?;
+    let today_end = format_timestamp(&(start_of_today_utc() + Duration::days(1)));
+    GetDeckCounts::new(repository.as_ref()).handle(&today_end)
+}
+
+/// 建立牌組；`parent_id` 為上層牌組，未指定設定時使用預設值
+#[tauri::command]
+pub fn create_deck(
+    database: State<'_, Database>,
+    name: String,
+    parent_id: Option<i32>,
+    settings: Option<DeckSettings>,
+) -> Result<Deck, AppError> {
+    let repository = deck_repository(&database)?;
+    CreateDeck::new(repository.as_ref()).handle(CreateDeckCommand { name, parent_id, settings })
+}
+
+#[tauri::command]
+pub fn rename_deck(database: State<'_, Database>, deck_id: i32, name: String) -> Result<Deck, AppError> {
+    let repository = deck_repository(&database)?;
+    RenameDeck::new(repository.as_ref()).handle(RenameDeckCommand { deck_id, name })
+}
+
+/// 將牌組移到 `parent_id` 之下；`parent_id` 為 `null` 時移到最上層
+#[tauri::command]
+pub fn move_deck(database: State<'_, Database>, deck_id: i32, parent_id: Option<i32>) -> Result<Deck, AppError> {
+    let repository = deck_repository(&database)?;
+    MoveDeck::new(repository.as_ref()).handle(MoveDeckCommand { deck_id, parent_id })
+}
+
+#[tauri::command]
+pub fn update_deck_settings(database: State<'_, Database>, deck_id: i32, settings: DeckSettings) -> Result<Deck, AppError> {
+    let repository = deck_repository(&database)?;
+    UpdateDeckSettings::new(repository.as_ref()).handle(UpdateDeckSettingsCommand { deck_id, settings })
+}
+
+/// 刪除牌組；子牌組與單字卡移到上層牌組，最上層牌組的單字卡移到預設牌組
+#[tauri::command]
+pub fn delete_deck(database: State<'_, Database>, deck_id: i32) -> Result<(), AppError> {
+    let repository = deck_repository(&database)?;
+    DeleteDeck::new(repository.as_ref()).handle(DeleteDeckCommand { deck_id })
+}
+
+/// 將單字卡移到指定牌組，回傳實際移動的數量
+#[tauri::command]
+pub fn move_cards_to_deck(database: State<'_, Database>, card_ids: Vec<i32>, deck_id: i32) -> Result<usize, AppError> {
+    let repository = deck_repository(&database)?;
+    MoveCardsToDeck::new(repository.as_ref()).handle(MoveCardsToDeckCommand { card_ids, deck_id })
+}
@@ -0,0 +1,114 @@
+use log::{info, warn};
+use std::thread;
+use tauri::{AppHandle, Emitter, State};
+use super::{import_repository, word_card_repository};
+use crate::application::commands::imports::{ImportJobs, ImportWords, UndoImport};
+use crate::application::dto::imports::{
+    CardSource, ImportEntry, ImportOptions, ImportProgress, ImportRecord, ImportReport, ImportUndoSummary,
+    ImportedCardEntry, UndoImportCommand,
+};
+use crate::application::errors::AppError;
+use crate::application::queries::imports::{GetCardSources, GetImportCards, ListImports};
+use crate::infrastructure::external::dictionary_api::DictionaryApiClient;
+use crate::infrastructure::persistence::Database;
+
+/// 每處理完一個單字送出的事件，內容為 `ImportProgress`
+pub const IMPORT_PROGRESS_EVENT: &str = "import://progress";
+
+/// 匯入工作結束（完成或取消）時送出的事件，內容為 `ImportReport`
+pub const IMPORT_FINISHED_EVENT: &str = "import://finished";
+
+/// 建立匯入紀錄後在背景執行緒批次匯入單字，立即回傳工作 ID（即匯入紀錄的 ID）
+///
+/// 每個單字處理完送出 `import://progress`，結束時送出 `import://finished` 並保存報告，
+/// 之後可用 `get_import_report` 取得。`words` 的每一筆可以是單字字串，或附上出現次數的 `{ word, count }`。
+#[tauri::command]
+pub fn start_import(
+    app: AppHandle,
+    database: State<'_, Database>,
+    jobs: State<'_, ImportJobs>,
+    words: Vec<ImportEntry>,
+    options: Option<ImportOptions>,
+) -> Result<u64, AppError> {
+    let words = ImportWords::prepare(words)?;
+    let options = options.unwrap_or_default();
+    let repository = word_card_repository(&database)?;
+    let imports = import_repository(&database)?;
+    let dictionary = DictionaryApiClient::new();
+    let record = ImportWords::new(repository.as_ref(), imports.as_ref(), &dictionary).begin(&words, &options)?;
+    let import_id = record.id.unwrap_or_default();
+    let job_id = import_id as u64;
+    let jobs = jobs.inner().clone();
+    let cancelled = jobs.register(job_id);
+    info!("建立匯入工作 {}: {} 個單字", job_id, words.len());
+
+    thread::spawn(move || {
+        let report = ImportWords::new(repository.as_ref(), imports.as_ref(), &dictionary).handle(
+            import_id,
+            &words,
+            &options,
+            &cancelled,
+            &mut |progress: &ImportProgress| {
+                if let Err(e) = app.emit(IMPORT_PROGRESS_EVENT, progress.clone()) {
+                    warn!("⚠️ 送出匯入進度失敗: {}", e);
+                }
+            },
+        );
+        jobs.finish(report.clone());
+        if let Err(e) = app.emit(IMPORT_FINISHED_EVENT, report) {
+            warn!("⚠️ 送出匯入結果失敗: {}", e);
+        }
+    });
+    Ok(job_id)
+}
+
+/// 取消匯入工作；正在處理的單字完成後停止，已匯入的單字卡會保留
+#[tauri::command]
+pub fn cancel_import(jobs: State<'_, ImportJobs>, job_id: u64) -> Result<(), AppError> {
+    info!("取消匯入工作: {}", job_id);
+    if !jobs.cancel(job_id) {
+        let error_msg = format!("找不到執行中的匯入工作: {}", job_id);
+        warn!("⚠️ {}", error_msg);
+        return Err(AppError::NotFound(error_msg));
+    }
+    Ok(())
+}
+
+/// 已結束的匯入工作的報告；工作仍在執行時為 `null`
+#[tauri::command]
+pub fn get_import_report(jobs: State<'_, ImportJobs>, job_id: u64) -> Result<Option<ImportReport>, AppError> {
+    Ok(jobs.report(job_id))
+}
+
+/// 所有匯入紀錄，新的在前
+#[tauri::command]
+pub fn list_imports(database: State<'_, Database>) -> Result<Vec<ImportRecord>, AppError> {
+    let imports = import_repository(&database)?;
+    ListImports::new(imports.as_ref()).handle()
+}
+
+/// 一次匯入新增或比對到的單字卡，附上此匯入累加的出現次數
+#[tauri::command]
+pub fn get_import_cards(database: State<'_, Database>, import_id: i32) -> Result<Vec<ImportedCardEntry>, AppError> {
+    let repository = word_card_repository(&database)?;
+    let imports = import_repository(&database)?;
+    GetImportCards::new(repository.as_ref(), imports.as_ref()).handle(import_id)
+}
+
+/// 復原整個匯入：由此匯入建立、且沒有其他來源的單字卡會被刪除，其餘單字卡扣回累加的出現次數
+#[tauri::command]
+pub fn undo_import(
+    database: State<'_, Database>,
+    jobs: State<'_, ImportJobs>,
+    import_id: i32,
+) -> Result<ImportUndoSummary, AppError> {
+    let imports = import_repository(&database)?;
+    UndoImport::new(imports.as_ref(), &jobs).handle(UndoImportCommand { import_id })
+}
+
+/// 單字卡的所有來源（匯入或手動查詢）
+#[tauri::command]
+pub fn get_card_sources(database: State<'_, Database>, card_id: i32) -> Result<Vec<CardSource>, AppError> {
+    let imports = import_repository(&database)?;
+    GetCardSources::new(imports.as_ref()).handle(card_id)
+}
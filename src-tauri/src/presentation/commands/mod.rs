@@ -1 +1,61 @@
+pub mod anki;
+pub mod database;
+pub mod decks;
+pub mod imports;
+pub mod profiles;
+pub mod review;
+pub mod spreadsheets;
+pub mod tags;
+pub mod vocabulary;
 pub mod word_cards;
+
+use log::error;
+use crate::application::errors::AppError;
+use crate::domain::repositories::deck_repository::DeckRepository;
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::repositories::review_repository::ReviewRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::repositories::word_card_repository::WordCardRepository;
+use crate::infrastructure::persistence::{Database, DbPool};
+use crate::infrastructure::repositories::{
+    DieselDeckRepository, DieselImportRepository, DieselProfileRepository, DieselReviewRepository, DieselTagRepository,
+    DieselWordCardRepository,
+};
+
+fn pool(database: &Database) -> Result<DbPool, AppError> {
+    database.pool().map_err(|e| {
+        error!("❌ 資料庫尚未就緒: {}", e);
+        AppError::from(e)
+    })
+}
+
+/// 取得單字卡儲存庫；資料庫尚未就緒時回傳初始化的錯誤
+pub(crate) fn word_card_repository(database: &Database) -> Result<Box<dyn WordCardRepository>, AppError> {
+    Ok(Box::new(DieselWordCardRepository::new(pool(database)?)))
+}
+
+/// 取得標籤儲存庫；與單字卡儲存庫使用同一個資料庫
+pub(crate) fn tag_repository(database: &Database) -> Result<Box<dyn TagRepository>, AppError> {
+    Ok(Box::new(DieselTagRepository::new(pool(database)?)))
+}
+
+/// 取得牌組儲存庫；與單字卡儲存庫使用同一個資料庫
+pub(crate) fn deck_repository(database: &Database) -> Result<Box<dyn DeckRepository>, AppError> {
+    Ok(Box::new(DieselDeckRepository::new(pool(database)?)))
+}
+
+/// 取得匯入紀錄儲存庫；與單字卡儲存庫使用同一個資料庫
+pub(crate) fn import_repository(database: &Database) -> Result<Box<dyn ImportRepository>, AppError> {
+    Ok(Box::new(DieselImportRepository::new(pool(database)?)))
+}
+
+/// 取得作答紀錄儲存庫；與單字卡儲存庫使用同一個資料庫
+pub(crate) fn review_repository(database: &Database) -> Result<Box<dyn ReviewRepository>, AppError> {
+    Ok(Box::new(DieselReviewRepository::new(pool(database)?)))
+}
+
+/// 取得設定檔儲存庫；與單字卡儲存庫使用同一個資料庫
+pub(crate) fn profile_repository(database: &Database) -> Result<Box<dyn ProfileRepository>, AppError> {
+    Ok(Box::new(DieselProfileRepository::new(pool(database)?)))
+}
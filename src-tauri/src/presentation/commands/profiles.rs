@@ -0,0 +1,20 @@
+use tauri::State;
+use super::profile_repository;
+use crate::application::commands::profiles::SetProfileScheduler;
+use crate::application::dto::profiles::{Profile, SetProfileSchedulerCommand};
+use crate::application::errors::AppError;
+use crate::application::queries::profiles::GetActiveProfile;
+use crate::infrastructure::persistence::Database;
+
+#[tauri::command]
+pub fn get_active_profile(database: State<'_, Database>) -> Result<Profile, AppError> {
+    let repository = profile_repository(&database)?;
+    GetActiveProfile::new(repository.as_ref()).handle()
+}
+
+/// 設定設定檔使用的排程演算法（"sm2" 或 "fsrs"）
+#[tauri::command]
+pub fn set_profile_scheduler(database: State<'_, Database>, profile_id: i32, scheduler_name: String) -> Result<Profile, AppError> {
+    let repository = profile_repository(&database)?;
+    SetProfileScheduler::new(repository.as_ref()).handle(SetProfileSchedulerCommand { profile_id, scheduler_name })
+}
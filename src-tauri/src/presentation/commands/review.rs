@@ -0,0 +1,49 @@
+use chrono::{Local, NaiveDateTime, TimeZone, Utc};
+use tauri::State;
+use super::{deck_repository, profile_repository, review_repository, word_card_repository};
+use crate::application::commands::review::ReviewWordCard;
+use crate::application::dto::review_logs::{ReviewLogDto, ReviewLogQuery, ReviewWordCardCommand};
+use crate::application::dto::review_queue::{ReviewQueue, ReviewQueueOptions};
+use crate::application::errors::AppError;
+use crate::application::queries::review::{GetReviewLogs, GetReviewQueue};
+use crate::domain::entities::word_cards::WordCard;
+use crate::infrastructure::persistence::Database;
+
+/// 複習單字卡：依作答評分以使用中設定檔的排程演算法與卡片所屬牌組的學習步驟計算下次複習時間，
+/// 並在同一交易中更新排程狀態與寫入作答紀錄
+///
+/// `duration_ms` 為作答所花的時間（毫秒），由前端計時後傳入。
+#[tauri::command]
+pub fn review_word_card(database: State<'_, Database>, card_id: i32, grade: i32, duration_ms: Option<i32>) -> Result<WordCard, AppError> {
+    let cards = word_card_repository(&database)?;
+    let decks = deck_repository(&database)?;
+    let profiles = profile_repository(&database)?;
+    let reviews = review_repository(&database)?;
+    ReviewWordCard::new(cards.as_ref(), decks.as_ref(), profiles.as_ref(), reviews.as_ref())
+        .handle(ReviewWordCardCommand { card_id, grade, duration_ms }, Utc::now().naive_utc())
+}
+
+/// 查詢作答紀錄，依作答時間由新到舊排序
+#[tauri::command]
+pub fn get_review_logs(database: State<'_, Database>, query: ReviewLogQuery) -> Result<Vec<ReviewLogDto>, AppError> {
+    let repository = review_repository(&database)?;
+    GetReviewLogs::new(repository.as_ref()).handle(query)
+}
+
+/// 本地時間今日零點對應的 UTC 時間
+pub(crate) fn start_of_today_utc() -> NaiveDateTime {
+    let midnight = Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap_or_default();
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|t| t.naive_utc())
+        .unwrap_or(midnight)
+}
+
+/// 取得今日的複習佇列；指定 `deck_id` 時只包含該牌組及其子牌組的卡片
+#[tauri::command]
+pub fn get_review_queue(database: State<'_, Database>, options: Option<ReviewQueueOptions>) -> Result<ReviewQueue, AppError> {
+    let decks = deck_repository(&database)?;
+    let reviews = review_repository(&database)?;
+    GetReviewQueue::new(decks.as_ref(), reviews.as_ref()).handle(options.unwrap_or_default(), start_of_today_utc())
+}
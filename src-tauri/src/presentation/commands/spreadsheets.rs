@@ -0,0 +1,42 @@
+use tauri::State;
+use super::{import_repository, word_card_repository};
+use crate::application::commands::spreadsheets::ImportSpreadsheet;
+use crate::application::dto::spreadsheets::{
+    ImportSpreadsheetCommand, PreviewSpreadsheetQuery, SpreadsheetImportReport, SpreadsheetOptions, SpreadsheetPreview,
+    SpreadsheetSource,
+};
+use crate::domain::entities::word_cards::MergePolicy;
+use crate::application::errors::AppError;
+use crate::application::queries::spreadsheets::PreviewSpreadsheet;
+use crate::infrastructure::persistence::Database;
+
+/// 預覽 CSV/TSV 匯入：判斷出的編碼、分隔符號、標題列與欄位對應，前 `rows` 列的轉換結果與所有列的驗證統計
+///
+/// `source` 為 `{ path }` 或 `{ content }`（檔案的原始位元組）；不寫入資料庫。
+#[tauri::command(async)]
+pub fn preview_spreadsheet(
+    source: SpreadsheetSource,
+    options: Option<SpreadsheetOptions>,
+    rows: Option<usize>,
+) -> Result<SpreadsheetPreview, AppError> {
+    PreviewSpreadsheet.handle(PreviewSpreadsheetQuery { source, options: options.unwrap_or_default(), rows })
+}
+
+/// 將 CSV/TSV 匯入為單字卡並記錄在匯入紀錄中；驗證失敗的列略過並在結果中回報
+#[tauri::command(async)]
+pub fn import_spreadsheet(
+    database: State<'_, Database>,
+    source: SpreadsheetSource,
+    options: Option<SpreadsheetOptions>,
+    file_name: Option<String>,
+    policy: Option<MergePolicy>,
+) -> Result<SpreadsheetImportReport, AppError> {
+    let repository = word_card_repository(&database)?;
+    let imports = import_repository(&database)?;
+    ImportSpreadsheet::new(repository.as_ref(), imports.as_ref()).handle(ImportSpreadsheetCommand {
+        source,
+        options: options.unwrap_or_default(),
+        file_name,
+        policy,
+    })
+}
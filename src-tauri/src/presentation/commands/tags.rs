@@ -0,0 +1,56 @@
+use tauri::State;
+use super::tag_repository;
+use crate::application::commands::tags::{AssignTags, CreateTag, DeleteTag, MergeTags, RenameTag, UnassignTags};
+use crate::application::dto::tags::{
+    CreateTagCommand, DeleteTagCommand, MergeTagsCommand, RenameTagCommand, Tag, TagAssignmentCommand, TagCount,
+};
+use crate::application::errors::AppError;
+use crate::application::queries::tags::ListTags;
+use crate::infrastructure::persistence::Database;
+
+/// 查詢所有標籤及其單字卡數量；以標籤篩選單字卡請使用 `list_word_cards` 的 `filter.tags`
+#[tauri::command]
+pub fn get_tags(database: State<'_, Database>) -> Result<Vec<TagCount>, AppError> {
+    let repository = tag_repository(&database)?;
+    ListTags::new(repository.as_ref()).handle()
+}
+
+/// 建立標籤；名稱不分大小寫不能重複
+#[tauri::command]
+pub fn create_tag(database: State<'_, Database>, name: String) -> Result<Tag, AppError> {
+    let repository = tag_repository(&database)?;
+    CreateTag::new(repository.as_ref()).handle(CreateTagCommand { name })
+}
+
+#[tauri::command]
+pub fn rename_tag(database: State<'_, Database>, tag_id: i32, name: String) -> Result<Tag, AppError> {
+    let repository = tag_repository(&database)?;
+    RenameTag::new(repository.as_ref()).handle(RenameTagCommand { tag_id, name })
+}
+
+/// 將 `source_ids` 的標籤併入 `target_id`，回傳合併後的標籤與單字卡數量
+#[tauri::command]
+pub fn merge_tags(database: State<'_, Database>, source_ids: Vec<i32>, target_id: i32) -> Result<TagCount, AppError> {
+    let repository = tag_repository(&database)?;
+    MergeTags::new(repository.as_ref()).handle(MergeTagsCommand { source_ids, target_id })
+}
+
+#[tauri::command]
+pub fn delete_tag(database: State<'_, Database>, tag_id: i32) -> Result<(), AppError> {
+    let repository = tag_repository(&database)?;
+    DeleteTag::new(repository.as_ref()).handle(DeleteTagCommand { tag_id })
+}
+
+/// 為每張單字卡加上每個標籤，回傳新增的數量
+#[tauri::command]
+pub fn assign_tags(database: State<'_, Database>, card_ids: Vec<i32>, tag_ids: Vec<i32>) -> Result<usize, AppError> {
+    let repository = tag_repository(&database)?;
+    AssignTags::new(repository.as_ref()).handle(TagAssignmentCommand { card_ids, tag_ids })
+}
+
+/// 移除每張單字卡的每個標籤，回傳移除的數量
+#[tauri::command]
+pub fn unassign_tags(database: State<'_, Database>, card_ids: Vec<i32>, tag_ids: Vec<i32>) -> Result<usize, AppError> {
+    let repository = tag_repository(&database)?;
+    UnassignTags::new(repository.as_ref()).handle(TagAssignmentCommand { card_ids, tag_ids })
+}
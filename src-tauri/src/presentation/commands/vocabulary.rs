@@ -0,0 +1,11 @@
+use crate::application::dto::vocabulary::{ExtractVocabularyQuery, ExtractedVocabulary};
+use crate::application::errors::AppError;
+use crate::application::queries::vocabulary::ExtractVocabulary;
+
+/// 從文字檔（`path`）或文字（`text`）中擷取不重複的單字與片語及其出現次數，並附上內容的 SHA-256
+///
+/// 在背景執行緒處理，避免大型檔案阻塞介面；結果可附上次數傳給 `start_import`。
+#[tauri::command(async)]
+pub fn extract_vocabulary(path: Option<String>, text: Option<String>) -> Result<ExtractedVocabulary, AppError> {
+    ExtractVocabulary.handle(ExtractVocabularyQuery { path, text })
+}
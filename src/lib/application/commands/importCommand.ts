@@ -1,90 +1,89 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { toast } from 'svelte-sonner';
 import { importStore } from '$lib/application/stores/importStore';
-import { searchWord } from '$lib/application/services/searchDictionary';
-import { WordCardService } from '$lib/application/services/wordCardService';
-import type { DictionaryEntry } from '$lib/domain/models/dictionary';
+import type { ImportEntry, ImportItemResult, ImportOptions, ImportProgress, ImportReport } from '$lib/domain/types/import';
+import { toCommandError } from '$lib/infrastructure/tauri';
 
-export interface ImportResult {
-  word: string;
-  status: 'success' | 'failed' | 'exists' | 'processing';
-  entry?: DictionaryEntry;
-  error?: string;
-}
+export type ImportResult = ImportItemResult;
+
+const PROGRESS_EVENT = 'import://progress';
+const FINISHED_EVENT = 'import://finished';
 
+/** 批次匯入由後端的背景工作執行，前端只負責顯示進度事件與最終報告 */
 export class ImportCommand {
-  static async execute(words: string[]): Promise<void> {
+  private static jobId: number | null = null;
+
+  /** `words` 可以是單字字串，或附上出現次數的 `{ word, count }` */
+  static async execute(words: Array<string | ImportEntry>, options?: ImportOptions): Promise<void> {
     if (words.length === 0) {
       toast.error('沒有單字需要匯入');
       return;
     }
 
     importStore.startImport(words.length);
-    const results: ImportResult[] = [];
+    this.jobId = null;
 
-    for (let i = 0; i < words.length; i++) {
-      const word = words[i];
-      importStore.setCurrentWord(word, i + 1);
+    // 事件可能在 start_import 回傳工作 ID 之前送達，尚不知道 ID 時先全部接受
+    const isCurrentJob = (jobId: number) => this.jobId === null || this.jobId === jobId;
+    const unlisteners: UnlistenFn[] = [];
 
-      try {
-        // 檢查是否已存在
-        const existingCard = await WordCardService.getWordCard(word);
-        
-        if (existingCard) {
-          results.push({
-            word,
-            status: 'exists',
-            entry: WordCardService.mapWordCardToDictionaryEntry(existingCard)
-          });
-          importStore.incrementExists();
-          continue;
-        }
+    try {
+      let resolveReport: (report: ImportReport) => void = () => {};
+      const finished = new Promise<ImportReport>((resolve) => {
+        resolveReport = resolve;
+      });
 
-        // 查詢字典 API
-        const entry = await searchWord(word);
-        
-        // 儲存單字卡
-        await WordCardService.saveWordCard(entry);
-        
-        results.push({
-          word,
-          status: 'success',
-          entry
-        });
-        
-        importStore.incrementSuccess();
-        
-        // 避免 API 請求過於頻繁
-        await new Promise(resolve => setTimeout(resolve, 100));
-        
-      } catch (error) {
-        results.push({
-          word,
-          status: 'failed',
-          error: String(error)
-        });
-        importStore.incrementFailed();
-      }
+      unlisteners.push(
+        await listen<ImportProgress>(PROGRESS_EVENT, (event) => {
+          if (isCurrentJob(event.payload.job_id)) {
+            importStore.recordProgress(event.payload);
+          }
+        }),
+        await listen<ImportReport>(FINISHED_EVENT, (event) => {
+          if (isCurrentJob(event.payload.job_id)) {
+            resolveReport(event.payload);
+          }
+        })
+      );
+
+      this.jobId = await invoke<number>('start_import', { words, options: options ?? null });
+      const report = await finished;
+      importStore.completeImport(report.results, report.status === 'cancelled');
+      this.showSummary(report);
+    } catch (error) {
+      console.error('Failed to import words:', error);
+      importStore.completeImport([]);
+      toast.error(toCommandError(error, '匯入失敗').message);
+    } finally {
+      unlisteners.forEach((unlisten) => unlisten());
+      this.jobId = null;
     }
+  }
+
+  static async cancel(): Promise<void> {
+    if (this.jobId === null) return;
 
-    importStore.completeImport(results);
-    
-    const { successCount, failedCount, existsCount } = importStore.getStats();
-    
-    if (successCount > 0) {
-      toast.success(`成功匯入 ${successCount} 個單字`);
+    try {
+      await invoke('cancel_import', { jobId: this.jobId });
+      toast.info('匯入已取消');
+    } catch (error) {
+      console.error('Failed to cancel import:', error);
+      toast.error(toCommandError(error, '取消匯入失敗').message);
     }
-    
-    if (failedCount > 0) {
-      toast.error(`${failedCount} 個單字匯入失敗`);
+  }
+
+  private static showSummary(report: ImportReport): void {
+    if (report.success_count > 0) {
+      toast.success(`成功匯入 ${report.success_count} 個單字`);
     }
-    
-    if (existsCount > 0) {
-      toast.info(`${existsCount} 個單字已存在`);
+
+    if (report.failed_count > 0) {
+      toast.error(`${report.failed_count} 個單字匯入失敗`);
     }
-  }
 
-  static cancel(): void {
-    importStore.cancelImport();
-    toast.info('匯入已取消');
+    if (report.exists_count > 0) {
+      toast.info(`${report.exists_count} 個單字已存在`);
+    }
   }
 }
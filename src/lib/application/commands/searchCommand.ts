@@ -22,6 +22,9 @@ export class SearchCommand {
         const entry = WordCardService.mapWordCardToDictionaryEntry(existingCard);
         dictionaryStore.setEntry(entry);
         dictionaryStore.setExistsInCard(true);
+        if (existingCard.matched_by === 'lemma') {
+          toast.info(`「${word}」為「${existingCard.word}」的變化形`);
+        }
       } else {
         // Search from external dictionary API
         const entry = await searchWord(word);
@@ -0,0 +1,30 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { AnkiFieldMap, AnkiImportReport, AnkiImportRequest, AnkiPackageSummary } from '$lib/domain/types/import';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** Anki 套件（.apkg / .colpkg）的檢視與匯入；匯入會記錄在匯入紀錄中，可用 `ImportHistoryService.undoImport` 復原 */
+export class AnkiService {
+  /** 不寫入資料庫，回傳筆記類型與套用 `fieldMap` 後的欄位對應 */
+  static async inspect(path: string, fieldMap: AnkiFieldMap = {}): Promise<AnkiPackageSummary> {
+    try {
+      return await invoke<AnkiPackageSummary>('inspect_anki_package', { path, fieldMap });
+    } catch (error) {
+      console.error('Failed to inspect Anki package:', error);
+      throw toCommandError(error, 'Failed to inspect Anki package');
+    }
+  }
+
+  static async import(request: AnkiImportRequest): Promise<AnkiImportReport> {
+    try {
+      return await invoke<AnkiImportReport>('import_anki_package', {
+        path: request.path,
+        fieldMap: request.field_map ?? {},
+        includeHistory: request.include_history ?? null,
+        policy: request.policy ?? null,
+      });
+    } catch (error) {
+      console.error('Failed to import Anki package:', error);
+      throw toCommandError(error, 'Failed to import Anki package');
+    }
+  }
+}
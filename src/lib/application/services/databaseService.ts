@@ -0,0 +1,23 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { DatabaseStatus } from '$lib/domain/types/appError';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+export class DatabaseService {
+  static async getStatus(): Promise<DatabaseStatus> {
+    try {
+      return await invoke<DatabaseStatus>('get_database_status');
+    } catch (error) {
+      console.error('Failed to get database status:', error);
+      throw toCommandError(error, 'Failed to get database status');
+    }
+  }
+
+  static async retryInitialization(): Promise<DatabaseStatus> {
+    try {
+      return await invoke<DatabaseStatus>('retry_database_initialization');
+    } catch (error) {
+      console.error('Failed to initialize database:', error);
+      throw toCommandError(error, 'Failed to initialize database');
+    }
+  }
+}
@@ -0,0 +1,73 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { Deck, DeckSettings, DeckSummary } from '$lib/domain/types/wordCard';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** 牌組管理；複習單一牌組請使用 `WordCardService.getReviewQueue` 的 `deck_id` */
+export class DeckService {
+  /** 所有牌組及其新卡片、學習中與今日到期的卡片數量 */
+  static async getDeckCounts(): Promise<DeckSummary[]> {
+    try {
+      return await invoke<DeckSummary[]>('get_deck_counts');
+    } catch (error) {
+      console.error('Failed to get deck counts:', error);
+      throw toCommandError(error, 'Failed to get deck counts');
+    }
+  }
+
+  static async createDeck(name: string, parentId?: number | null, settings?: DeckSettings): Promise<Deck> {
+    try {
+      return await invoke<Deck>('create_deck', { name, parentId: parentId ?? null, settings: settings ?? null });
+    } catch (error) {
+      console.error('Failed to create deck:', error);
+      throw toCommandError(error, 'Failed to create deck');
+    }
+  }
+
+  static async renameDeck(deckId: number, name: string): Promise<Deck> {
+    try {
+      return await invoke<Deck>('rename_deck', { deckId, name });
+    } catch (error) {
+      console.error('Failed to rename deck:', error);
+      throw toCommandError(error, 'Failed to rename deck');
+    }
+  }
+
+  /** `parentId` 為 null 時移到最上層 */
+  static async moveDeck(deckId: number, parentId: number | null): Promise<Deck> {
+    try {
+      return await invoke<Deck>('move_deck', { deckId, parentId });
+    } catch (error) {
+      console.error('Failed to move deck:', error);
+      throw toCommandError(error, 'Failed to move deck');
+    }
+  }
+
+  static async updateDeckSettings(deckId: number, settings: DeckSettings): Promise<Deck> {
+    try {
+      return await invoke<Deck>('update_deck_settings', { deckId, settings });
+    } catch (error) {
+      console.error('Failed to update deck settings:', error);
+      throw toCommandError(error, 'Failed to update deck settings');
+    }
+  }
+
+  /** 子牌組與單字卡會移到上層牌組 */
+  static async deleteDeck(deckId: number): Promise<void> {
+    try {
+      await invoke('delete_deck', { deckId });
+    } catch (error) {
+      console.error('Failed to delete deck:', error);
+      throw toCommandError(error, 'Failed to delete deck');
+    }
+  }
+
+  /** 將單字卡移到指定牌組，回傳實際移動的數量 */
+  static async moveCardsToDeck(cardIds: number[], deckId: number): Promise<number> {
+    try {
+      return await invoke<number>('move_cards_to_deck', { cardIds, deckId });
+    } catch (error) {
+      console.error('Failed to move cards to deck:', error);
+      throw toCommandError(error, 'Failed to move cards to deck');
+    }
+  }
+}
@@ -0,0 +1,44 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { CardSource, ImportedCardEntry, ImportRecord, ImportUndoSummary } from '$lib/domain/types/import';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** 匯入紀錄與單字卡來源；執行匯入請使用 `ImportCommand` */
+export class ImportHistoryService {
+  /** 所有匯入紀錄，新的在前 */
+  static async listImports(): Promise<ImportRecord[]> {
+    try {
+      return await invoke<ImportRecord[]>('list_imports');
+    } catch (error) {
+      console.error('Failed to list imports:', error);
+      throw toCommandError(error, 'Failed to list imports');
+    }
+  }
+
+  static async getImportCards(importId: number): Promise<ImportedCardEntry[]> {
+    try {
+      return await invoke<ImportedCardEntry[]>('get_import_cards', { importId });
+    } catch (error) {
+      console.error('Failed to get import cards:', error);
+      throw toCommandError(error, 'Failed to get import cards');
+    }
+  }
+
+  /** 由此匯入建立、且沒有其他來源的單字卡會被刪除，其餘單字卡扣回累加的出現次數 */
+  static async undoImport(importId: number): Promise<ImportUndoSummary> {
+    try {
+      return await invoke<ImportUndoSummary>('undo_import', { importId });
+    } catch (error) {
+      console.error('Failed to undo import:', error);
+      throw toCommandError(error, 'Failed to undo import');
+    }
+  }
+
+  static async getCardSources(cardId: number): Promise<CardSource[]> {
+    try {
+      return await invoke<CardSource[]>('get_card_sources', { cardId });
+    } catch (error) {
+      console.error('Failed to get card sources:', error);
+      throw toCommandError(error, 'Failed to get card sources');
+    }
+  }
+}
@@ -1,2 +1,9 @@
 export * from './searchDictionary';
 export * from './wordCardService';
+export * from './databaseService';
+export * from './tagService';
+export * from './deckService';
+export * from './vocabularyService';
+export * from './importHistoryService';
+export * from './spreadsheetService';
+export * from './ankiService';
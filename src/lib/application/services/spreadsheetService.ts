@@ -0,0 +1,46 @@
+import { invoke } from '@tauri-apps/api/core';
+import type {
+  SpreadsheetImportReport,
+  SpreadsheetImportRequest,
+  SpreadsheetOptions,
+  SpreadsheetPreview,
+  SpreadsheetSource,
+} from '$lib/domain/types/import';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** CSV/TSV 單字表的預覽與匯入；匯入會記錄在匯入紀錄中，可用 `ImportHistoryService.undoImport` 復原 */
+export class SpreadsheetService {
+  /** 將瀏覽器讀取的檔案轉為 `SpreadsheetSource`，編碼由後端判斷 */
+  static async sourceFromFile(file: File): Promise<SpreadsheetSource> {
+    const buffer = await file.arrayBuffer();
+    return { content: Array.from(new Uint8Array(buffer)) };
+  }
+
+  /** 不寫入資料庫，回傳判斷出的設定與前 `rows` 列的轉換結果 */
+  static async preview(
+    source: SpreadsheetSource,
+    options: SpreadsheetOptions = {},
+    rows?: number
+  ): Promise<SpreadsheetPreview> {
+    try {
+      return await invoke<SpreadsheetPreview>('preview_spreadsheet', { source, options, rows: rows ?? null });
+    } catch (error) {
+      console.error('Failed to preview spreadsheet:', error);
+      throw toCommandError(error, 'Failed to preview spreadsheet');
+    }
+  }
+
+  static async import(request: SpreadsheetImportRequest): Promise<SpreadsheetImportReport> {
+    try {
+      return await invoke<SpreadsheetImportReport>('import_spreadsheet', {
+        source: request.source,
+        options: request.options ?? {},
+        fileName: request.file_name ?? null,
+        policy: request.policy ?? null,
+      });
+    } catch (error) {
+      console.error('Failed to import spreadsheet:', error);
+      throw toCommandError(error, 'Failed to import spreadsheet');
+    }
+  }
+}
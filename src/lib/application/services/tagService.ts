@@ -0,0 +1,71 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { Tag, TagCount } from '$lib/domain/types/wordCard';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** 標籤管理；以標籤篩選單字卡請使用 `WordCardService.listWordCards` 的 `filter.tags` */
+export class TagService {
+  static async getTags(): Promise<TagCount[]> {
+    try {
+      return await invoke<TagCount[]>('get_tags');
+    } catch (error) {
+      console.error('Failed to get tags:', error);
+      throw toCommandError(error, 'Failed to get tags');
+    }
+  }
+
+  static async createTag(name: string): Promise<Tag> {
+    try {
+      return await invoke<Tag>('create_tag', { name });
+    } catch (error) {
+      console.error('Failed to create tag:', error);
+      throw toCommandError(error, 'Failed to create tag');
+    }
+  }
+
+  static async renameTag(tagId: number, name: string): Promise<Tag> {
+    try {
+      return await invoke<Tag>('rename_tag', { tagId, name });
+    } catch (error) {
+      console.error('Failed to rename tag:', error);
+      throw toCommandError(error, 'Failed to rename tag');
+    }
+  }
+
+  static async mergeTags(sourceIds: number[], targetId: number): Promise<TagCount> {
+    try {
+      return await invoke<TagCount>('merge_tags', { sourceIds, targetId });
+    } catch (error) {
+      console.error('Failed to merge tags:', error);
+      throw toCommandError(error, 'Failed to merge tags');
+    }
+  }
+
+  static async deleteTag(tagId: number): Promise<void> {
+    try {
+      await invoke('delete_tag', { tagId });
+    } catch (error) {
+      console.error('Failed to delete tag:', error);
+      throw toCommandError(error, 'Failed to delete tag');
+    }
+  }
+
+  /** 為每張單字卡加上每個標籤，回傳新增的數量 */
+  static async assignTags(cardIds: number[], tagIds: number[]): Promise<number> {
+    try {
+      return await invoke<number>('assign_tags', { cardIds, tagIds });
+    } catch (error) {
+      console.error('Failed to assign tags:', error);
+      throw toCommandError(error, 'Failed to assign tags');
+    }
+  }
+
+  /** 移除每張單字卡的每個標籤，回傳移除的數量 */
+  static async unassignTags(cardIds: number[], tagIds: number[]): Promise<number> {
+    try {
+      return await invoke<number>('unassign_tags', { cardIds, tagIds });
+    } catch (error) {
+      console.error('Failed to unassign tags:', error);
+      throw toCommandError(error, 'Failed to unassign tags');
+    }
+  }
+}
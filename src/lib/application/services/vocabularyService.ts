@@ -0,0 +1,24 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { VocabularyExtraction } from '$lib/domain/types/import';
+import { toCommandError } from '$lib/infrastructure/tauri';
+
+/** 由後端擷取文字中的單字與片語並計算出現次數，結果可直接傳給 `ImportCommand.execute` */
+export class VocabularyService {
+  static async extractFromText(text: string): Promise<VocabularyExtraction> {
+    try {
+      return await invoke<VocabularyExtraction>('extract_vocabulary', { path: null, text });
+    } catch (error) {
+      console.error('Failed to extract vocabulary:', error);
+      throw toCommandError(error, 'Failed to extract vocabulary');
+    }
+  }
+
+  static async extractFromFile(path: string): Promise<VocabularyExtraction> {
+    try {
+      return await invoke<VocabularyExtraction>('extract_vocabulary', { path, text: null });
+    } catch (error) {
+      console.error('Failed to extract vocabulary:', error);
+      throw toCommandError(error, 'Failed to extract vocabulary');
+    }
+  }
+}
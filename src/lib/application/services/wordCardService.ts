@@ -1,17 +1,42 @@
 import { invoke } from '@tauri-apps/api/core';
 import type { DictionaryEntry } from '$lib/domain/models/dictionary';
-import type { WordCard, WordCardPayload } from '$lib/domain/types/wordCard';
-import { parsePronunciation, stringifyPronunciation } from '$lib/presentation/utils';
+import type {
+  ListWordCardsQuery,
+  MergePolicy,
+  ReviewGrade,
+  ReviewQueue,
+  ReviewQueueOptions,
+  SaveWordCardResult,
+  Sense,
+  WordCard,
+  WordCardLookup,
+  WordCardPage,
+  WordCardPayload,
+  WordCardSearchHit,
+  WordForms,
+  WordSuggestion,
+} from '$lib/domain/types/wordCard';
+import { toCommandError } from '$lib/infrastructure/tauri';
+import { parsePronunciation, toPronunciations } from '$lib/presentation/utils';
 
 export class WordCardService {
-  static async getWordCard(word: string): Promise<WordCard | null> {
+  static async getWordCard(word: string): Promise<WordCardLookup | null> {
     try {
-      return await invoke<WordCard | null>('get_word_card_by_word', {
+      return await invoke<WordCardLookup | null>('get_word_card_by_word', {
         wordQuery: word,
       });
     } catch (error) {
       console.error('Failed to get word card:', error);
-      throw new Error(`Failed to get word card: ${error}`);
+      throw toCommandError(error, 'Failed to get word card');
+    }
+  }
+
+  static async getWordForms(word: string): Promise<WordForms> {
+    try {
+      return await invoke<WordForms>('get_word_forms', { word });
+    } catch (error) {
+      console.error('Failed to get word forms:', error);
+      throw toCommandError(error, 'Failed to get word forms');
     }
   }
 
@@ -20,18 +45,53 @@ export class WordCardService {
       return await invoke<WordCard[]>('get_all_word_cards');
     } catch (error) {
       console.error('Failed to get all word cards:', error);
-      throw new Error(`Failed to get all word cards: ${error}`);
+      throw toCommandError(error, 'Failed to get all word cards');
+    }
+  }
+
+  static async listWordCards(query: ListWordCardsQuery): Promise<WordCardPage> {
+    try {
+      return await invoke<WordCardPage>('list_word_cards', { query });
+    } catch (error) {
+      console.error('Failed to list word cards:', error);
+      throw toCommandError(error, 'Failed to list word cards');
+    }
+  }
+
+  static async searchWordCards(query: string, limit?: number): Promise<WordCardSearchHit[]> {
+    try {
+      return await invoke<WordCardSearchHit[]>('search_word_cards', { searchQuery: query, limit });
+    } catch (error) {
+      console.error('Failed to search word cards:', error);
+      throw toCommandError(error, 'Failed to search word cards');
+    }
+  }
+
+  static async suggestWords(
+    word: string,
+    limit?: number,
+    includeWordList?: boolean,
+  ): Promise<WordSuggestion[]> {
+    try {
+      return await invoke<WordSuggestion[]>('suggest_words', {
+        wordQuery: word,
+        limit,
+        includeWordList,
+      });
+    } catch (error) {
+      console.error('Failed to suggest words:', error);
+      throw toCommandError(error, 'Failed to suggest words');
     }
   }
 
-  static async saveWordCard(entry: DictionaryEntry): Promise<void> {
+  static async saveWordCard(entry: DictionaryEntry, policy?: MergePolicy): Promise<SaveWordCardResult> {
     const payload = this.prepareCardPayload(entry);
     
     try {
-      await invoke('save_word_card', { card: payload });
+      return await invoke<SaveWordCardResult>('save_word_card', { card: payload, policy: policy ?? null });
     } catch (error) {
       console.error('Failed to save word card:', error);
-      throw new Error(`Failed to save word card: ${error}`);
+      throw toCommandError(error, 'Failed to save word card');
     }
   }
 
@@ -43,7 +103,7 @@ export class WordCardService {
       });
     } catch (error) {
       console.error('Failed to update familiarity:', error);
-      throw new Error(`Failed to update familiarity: ${error}`);
+      throw toCommandError(error, 'Failed to update familiarity');
     }
   }
 
@@ -52,7 +112,7 @@ export class WordCardService {
       await invoke('delete_word_card', { cardId });
     } catch (error) {
       console.error('Failed to delete word card:', error);
-      throw new Error(`Failed to delete word card: ${error}`);
+      throw toCommandError(error, 'Failed to delete word card');
     }
   }
 
@@ -61,113 +121,76 @@ export class WordCardService {
       await invoke('increment_word_card_seen_count', { cardId });
     } catch (error) {
       console.error('Failed to increment seen count:', error);
-      throw new Error(`Failed to increment seen count: ${error}`);
+      throw toCommandError(error, 'Failed to increment seen count');
     }
   }
 
-  static mapWordCardToDictionaryEntry(card: WordCard): DictionaryEntry {
-    const pronunciation = parsePronunciation(card.pronunciation);
-    
-    // 解析存儲的詞性
-    let partOfSpeechList: string[] = [];
+  static async reviewCard(cardId: number, grade: ReviewGrade, durationMs?: number): Promise<WordCard> {
     try {
-      partOfSpeechList = JSON.parse(card.pos || '[]');
-    } catch {
-      partOfSpeechList = card.pos ? [card.pos] : [];
+      return await invoke<WordCard>('review_word_card', { cardId, grade, durationMs });
+    } catch (error) {
+      console.error('Failed to review word card:', error);
+      throw toCommandError(error, 'Failed to review word card');
     }
+  }
 
-    // 解析定義文本，重建 meanings 結構
-    const definitionText = card.definition || '';
-    const definitionBlocks = definitionText.split('\n\n');
-    
-    const meanings = partOfSpeechList.map((pos, index) => {
-      // 找到對應此詞性的定義
-      const relevantDefinitions = definitionBlocks.filter(block => 
-        block.includes(`[${pos}]`)
-      );
-      
-      const definitions = relevantDefinitions.length > 0 
-        ? relevantDefinitions.map(block => {
-            // 提取定義文本（移除詞性標記）
-            const lines = block.split('\n');
-            const definitionLine = lines[0].replace(`[${pos}] `, '');
-            
-            // 提取例句
-            const exampleLine = lines.find(line => line.startsWith('例句: '));
-            const example = exampleLine ? exampleLine.replace('例句: ', '') : undefined;
-            
-            return {
-              definition: definitionLine,
-              example,
-            };
-          })
-        : [
-            {
-              definition: definitionText || '',
-              example: undefined,
-            },
-          ];
-
-      return {
-        partOfSpeech: pos,
-        definitions,
-      };
-    });
+  static async getReviewQueue(options?: ReviewQueueOptions): Promise<ReviewQueue> {
+    try {
+      return await invoke<ReviewQueue>('get_review_queue', { options });
+    } catch (error) {
+      console.error('Failed to get review queue:', error);
+      throw toCommandError(error, 'Failed to get review queue');
+    }
+  }
+
+  static mapWordCardToDictionaryEntry(card: WordCard): DictionaryEntry {
+    const pronunciation = parsePronunciation(card.pronunciations);
+
+    // 依詞性分組，保留詞義原本的順序
+    const meanings: DictionaryEntry['meanings'] = [];
+    for (const sense of card.senses ?? []) {
+      let meaning = meanings.find((m) => m.partOfSpeech === sense.part_of_speech);
+      if (!meaning) {
+        meaning = { partOfSpeech: sense.part_of_speech, definitions: [] };
+        meanings.push(meaning);
+      }
+      meaning.definitions.push({
+        definition: sense.definition,
+        example: sense.examples[0],
+        synonyms: sense.synonyms.length > 0 ? sense.synonyms : undefined,
+        antonyms: sense.antonyms.length > 0 ? sense.antonyms : undefined,
+      });
+    }
 
     return {
       word: card.word,
-      phonetic: pronunciation?.phonetic ?? '',
+      // 與字典 API 相同，音標以斜線包住
+      phonetic: pronunciation?.phonetic ? `/${pronunciation.phonetic}/` : '',
       audio: pronunciation?.audio ?? '',
-      meanings: meanings.length > 0 ? meanings : [
-        {
-          partOfSpeech: partOfSpeechList[0] || '',
-          definitions: [
-            {
-              definition: definitionText || '',
-              example: undefined,
-            },
-          ],
-        },
-      ],
+      meanings,
     };
   }
 
   private static prepareCardPayload(entry: DictionaryEntry): WordCardPayload {
-    // 收集所有定義，按詞性分組
-    const allDefinitions = entry.meanings.flatMap(meaning => 
-      meaning.definitions.map((def, index) => {
-        let definitionText = `[${meaning.partOfSpeech}] ${def.definition}`;
-        
-        // 加入例句
-        if (def.example) {
-          definitionText += `\n例句: ${def.example}`;
-        }
-        
-        // 加入同義詞（優先使用定義層級的，再使用詞性層級的）
-        const synonyms = def.synonyms || meaning.synonyms;
-        if (synonyms && synonyms.length > 0) {
-          definitionText += `\n同義詞: ${synonyms.join(', ')}`;
-        }
-        
-        // 加入反義詞（優先使用定義層級的，再使用詞性層級的）
-        const antonyms = def.antonyms || meaning.antonyms;
-        if (antonyms && antonyms.length > 0) {
-          definitionText += `\n反義詞: ${antonyms.join(', ')}`;
-        }
-        
-        return definitionText;
-      })
+    // 每個定義為一個詞義；定義層級沒有同義詞、反義詞時使用詞性層級的
+    const senses: Sense[] = entry.meanings.flatMap((meaning) =>
+      meaning.definitions.map((def) => ({
+        part_of_speech: meaning.partOfSpeech,
+        definition: def.definition,
+        examples: def.example ? [def.example] : [],
+        synonyms: def.synonyms ?? meaning.synonyms ?? [],
+        antonyms: def.antonyms ?? meaning.antonyms ?? [],
+      }))
     );
 
     return {
       word: entry.word,
       pos: JSON.stringify(entry.meanings.map((m) => m.partOfSpeech)),
-      definition: allDefinitions.join('\n\n'), // 用雙換行分隔不同定義
-      pronunciation: stringifyPronunciation({
-        phonetic: entry.phonetic || '',
-        audio: entry.audio || '',
+      senses,
+      pronunciations: toPronunciations({
+        phonetic: entry.phonetic,
+        audio: entry.audio,
       }),
-      verbs: JSON.stringify({}), // Future expansion
       familiarity: 0,
       seen_count: 1,
     };
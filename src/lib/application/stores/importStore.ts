@@ -1,5 +1,6 @@
 import { writable, derived } from 'svelte/store';
 import type { ImportResult } from '$lib/application/commands/importCommand';
+import type { ImportProgress } from '$lib/domain/types/import';
 
 interface ImportState {
   isImporting: boolean;
@@ -47,6 +48,19 @@ function createImportStore() {
       }));
     },
 
+    /** 依後端的進度事件更新目前單字與統計；總數以去除重複後的數量為準 */
+    recordProgress: (progress: ImportProgress) => {
+      update(state => ({
+        ...state,
+        totalWords: progress.total,
+        currentWord: progress.result.word,
+        currentIndex: progress.index,
+        successCount: state.successCount + (progress.result.status === 'success' ? 1 : 0),
+        failedCount: state.failedCount + (progress.result.status === 'failed' ? 1 : 0),
+        existsCount: state.existsCount + (progress.result.status === 'exists' ? 1 : 0)
+      }));
+    },
+
     incrementSuccess: () => {
       update(state => ({
         ...state,
@@ -68,10 +82,11 @@ function createImportStore() {
       }));
     },
 
-    completeImport: (results: ImportResult[]) => {
+    completeImport: (results: ImportResult[], cancelled = false) => {
       update(state => ({
         ...state,
         isImporting: false,
+        cancelled,
         results,
         currentWord: '',
         currentIndex: 0
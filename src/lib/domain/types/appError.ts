@@ -0,0 +1,23 @@
+/** 後端指令回傳的錯誤代碼 */
+export type AppErrorCode =
+  | 'CONNECTION_FAILED'
+  | 'INITIALIZATION_FAILED'
+  | 'DIRECTORY_CREATION_FAILED'
+  | 'MIGRATION_FAILED'
+  | 'SCHEMA_TOO_NEW'
+  | 'QUERY_FAILED'
+  | 'NOT_FOUND'
+  | 'VALIDATION'
+  | 'CONFLICT';
+
+/** 後端指令回傳的錯誤 */
+export interface AppError {
+  code: AppErrorCode;
+  message: string;
+  details: string | null;
+}
+
+export interface DatabaseStatus {
+  ready: boolean;
+  error: AppError | null;
+}
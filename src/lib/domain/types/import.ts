@@ -0,0 +1,258 @@
+import type { MergePolicy, Pronunciation, Sense, WordCard, WordForms } from './wordCard';
+
+export interface ImportOptions {
+  /** 每次查詢字典後等待的毫秒數，預設 100 */
+  delay_ms?: number;
+  /** 來源檔案名稱，記錄在匯入紀錄中 */
+  file_name?: string | null;
+  /** 來源檔案內容的 SHA-256，使用 `extract_vocabulary` 回傳的 `content_hash` */
+  file_hash?: string | null;
+}
+
+/** 匯入清單中的一筆；`count` 為在來源檔案中出現的次數，會累加到單字卡上 */
+export interface ImportEntry {
+  word: string;
+  count?: number;
+}
+
+/** 從文字中擷取到的單字或片語 */
+export interface VocabularyCandidate {
+  word: string;
+  count: number;
+}
+
+/** `extract_vocabulary` 的結果 */
+export interface VocabularyExtraction {
+  /** 依出現次數由多到少排序 */
+  candidates: VocabularyCandidate[];
+  /** 非空白的行數 */
+  line_count: number;
+  /** 擷取到的單字與片語總數（含重複） */
+  token_count: number;
+  /** 來源內容的 SHA-256（十六進位小寫） */
+  content_hash: string;
+}
+
+export type ImportItemStatus = 'success' | 'exists' | 'failed';
+
+export interface ImportItemResult {
+  word: string;
+  status: ImportItemStatus;
+  /** 新增或已存在的單字卡 */
+  card?: WordCard | null;
+  error?: string | null;
+}
+
+/** `import://progress` 事件，每處理完一個單字送出一次 */
+export interface ImportProgress {
+  /** 與匯入紀錄的 ID 相同 */
+  job_id: number;
+  /** 已處理的數量（從 1 開始） */
+  index: number;
+  total: number;
+  result: ImportItemResult;
+}
+
+export type ImportJobStatus = 'completed' | 'cancelled';
+
+/** `import://finished` 事件與 `get_import_report` 的結果 */
+export interface ImportReport {
+  job_id: number;
+  status: ImportJobStatus;
+  /** 去除空白行與重複後的單字數 */
+  total: number;
+  success_count: number;
+  exists_count: number;
+  failed_count: number;
+  results: ImportItemResult[];
+}
+
+export type ImportStatus = 'running' | 'completed' | 'cancelled' | 'undone';
+
+/** `list_imports` 的匯入紀錄 */
+export interface ImportRecord {
+  id: number;
+  file_name: string | null;
+  /** 檔案內容的 SHA-256 */
+  file_hash: string | null;
+  status: ImportStatus;
+  total_count: number;
+  success_count: number;
+  exists_count: number;
+  failed_count: number;
+  created_at: string | null;
+  finished_at: string | null;
+}
+
+export type CardSourceKind = 'import' | 'lookup';
+
+/** 單字卡的來源：批次匯入或手動查詢 */
+export interface CardSource {
+  id: number;
+  card_id: number;
+  kind: CardSourceKind;
+  /** `kind` 為 `import` 時的匯入紀錄 ID */
+  import_id: number | null;
+  /** 單字卡是否由此來源建立 */
+  created_card: boolean;
+  occurrence_count: number;
+  created_at: string | null;
+}
+
+/** `get_import_cards` 的結果 */
+export interface ImportedCardEntry {
+  source: CardSource;
+  card: WordCard;
+}
+
+/** `undo_import` 的結果 */
+export interface ImportUndoSummary {
+  /** 由此匯入建立、且沒有其他來源而刪除的單字卡數量 */
+  deleted_count: number;
+  /** 保留下來、扣回出現次數的單字卡數量 */
+  updated_count: number;
+}
+
+/** 試算表檔案的文字編碼 */
+export type TextEncoding = 'utf-8' | 'utf-16le' | 'utf-16be' | 'big5';
+
+/** 試算表欄位對應到的單字卡欄位 */
+export type ColumnField =
+  | 'word'
+  | 'part_of_speech'
+  | 'definition'
+  | 'example'
+  | 'synonyms'
+  | 'antonyms'
+  | 'ipa'
+  | 'ignore';
+
+/** 試算表的來源：檔案路徑或檔案的原始位元組，兩者擇一 */
+export type SpreadsheetSource = { path: string } | { content: number[] };
+
+/** 解析試算表的選項，未指定的項目由後端自動判斷 */
+export interface SpreadsheetOptions {
+  encoding?: TextEncoding | null;
+  /** 單一 ASCII 字元，如 `,`、`\t`、`;` */
+  delimiter?: string | null;
+  has_header?: boolean | null;
+  /** 依欄位順序對應，超出的欄位略過 */
+  mapping?: ColumnField[] | null;
+}
+
+/** 預覽中由一列轉換出的單字卡 */
+export interface SpreadsheetCard {
+  word: string;
+  pos: string | null;
+  definition: string | null;
+  verbs: WordForms | null;
+  familiarity: number | null;
+  seen_count: number | null;
+  senses: Sense[];
+  pronunciations: Pronunciation[];
+}
+
+export interface SpreadsheetRowPreview {
+  /** 在檔案中的行號（從 1 開始） */
+  line: number;
+  values: string[];
+  card: SpreadsheetCard | null;
+  /** 驗證失敗的原因 */
+  error: string | null;
+}
+
+/** `preview_spreadsheet` 的結果 */
+export interface SpreadsheetPreview {
+  encoding: TextEncoding;
+  /** 有無法解碼的內容，通常代表編碼判斷錯誤 */
+  lossy: boolean;
+  delimiter: string;
+  has_header: boolean;
+  headers: string[];
+  mapping: ColumnField[];
+  column_count: number;
+  total_rows: number;
+  valid_count: number;
+  error_count: number;
+  rows: SpreadsheetRowPreview[];
+  content_hash: string;
+}
+
+export interface SpreadsheetImportRequest {
+  source: SpreadsheetSource;
+  options?: SpreadsheetOptions;
+  file_name?: string | null;
+  /** 單字已有單字卡時的處理方式，預設保留原有內容 */
+  policy?: MergePolicy | null;
+}
+
+export interface SpreadsheetRowError {
+  line: number;
+  word: string | null;
+  message: string;
+}
+
+/** `import_spreadsheet` 的結果；同一個單字的多列合併為一張單字卡 */
+export interface SpreadsheetImportReport {
+  import_id: number;
+  total_rows: number;
+  inserted_count: number;
+  exists_count: number;
+  /** 驗證或保存失敗的列數 */
+  failed_count: number;
+  errors: SpreadsheetRowError[];
+}
+
+/** Anki 欄位名稱（不分大小寫）對應到單字卡欄位；未列出的欄位依名稱自動判斷 */
+export type AnkiFieldMap = Record<string, ColumnField>;
+
+export interface AnkiFieldMapping {
+  name: string;
+  field: ColumnField;
+}
+
+export interface AnkiNoteTypeSummary {
+  id: number;
+  name: string;
+  fields: AnkiFieldMapping[];
+  note_count: number;
+  /** 欄位對應無法匯入時的原因，此類型的筆記會全部略過 */
+  error: string | null;
+}
+
+/** `inspect_anki_package` 的結果 */
+export interface AnkiPackageSummary {
+  note_types: AnkiNoteTypeSummary[];
+  note_count: number;
+  card_count: number;
+  review_count: number;
+  content_hash: string;
+}
+
+export interface AnkiImportRequest {
+  /** .apkg 或 .colpkg 檔案的路徑 */
+  path: string;
+  field_map?: AnkiFieldMap;
+  /** 是否帶入排程狀態與作答紀錄，預設為是；只套用到新增的單字卡 */
+  include_history?: boolean | null;
+  policy?: MergePolicy | null;
+}
+
+export interface AnkiSkippedNote {
+  note_id: number;
+  note_type: string;
+  word: string | null;
+  reason: string;
+}
+
+/** `import_anki_package` 的結果；同一個單字的多則筆記合併為一張單字卡 */
+export interface AnkiImportReport {
+  import_id: number;
+  note_count: number;
+  inserted_count: number;
+  exists_count: number;
+  /** 帶入排程狀態的單字卡數量 */
+  history_count: number;
+  review_log_count: number;
+  skipped: AnkiSkippedNote[];
+}
@@ -8,3 +8,6 @@ export interface WordCard {
 }
 
 export * from './wordCard';
+
+export * from './appError';
+export * from './import';
@@ -1,22 +1,194 @@
+/** 單字的一個詞義 */
+export interface Sense {
+  part_of_speech: string;
+  definition: string;
+  examples: string[];
+  synonyms: string[];
+  antonyms: string[];
+}
+
+/** 發音的地區 */
+export type Region = 'uk' | 'us';
+
+/** 單字的一種發音；IPA 不含外圍的斜線 */
+export interface Pronunciation {
+  region: Region | null;
+  ipa: string | null;
+  audio_url: string | null;
+  local_path: string | null;
+}
+
+/** 詞形變化；只會包含適用於該詞性的欄位 */
+export interface WordForms {
+  past?: string;
+  past_participle?: string;
+  third_person?: string;
+  present_participle?: string;
+  plural?: string;
+  comparative?: string;
+  superlative?: string;
+}
+
+/** 標籤；`name_key` 為不分大小寫的唯一名稱 */
+export interface Tag {
+  id: number;
+  name: string;
+  name_key: string;
+  created_at?: string | null;
+}
+
+/** 標籤連同使用中的單字卡數量，供側邊欄顯示 */
+export interface TagCount extends Tag {
+  card_count: number;
+}
+
+/** 以標籤篩選單字卡的條件；空的 and 視為成立、空的 or 視為不成立 */
+export type TagExpression =
+  | { tag: number }
+  | { and: TagExpression[] }
+  | { or: TagExpression[] }
+  | { not: TagExpression };
+
+/** 牌組的複習設定；學習步驟以分鐘為單位，空陣列代表不使用學習步驟 */
+export interface DeckSettings {
+  new_cards_per_day: number;
+  reviews_per_day: number;
+  learning_steps: number[];
+  relearning_steps: number[];
+}
+
+/** 牌組；`parent_id` 為上層牌組，ID 1 為不能刪除的預設牌組 */
+export interface Deck {
+  id: number;
+  name: string;
+  parent_id?: number | null;
+  settings: DeckSettings;
+  created_at?: string | null;
+}
+
+export interface DeckCounts {
+  new: number;
+  /** 正在學習或重新學習步驟中的卡片 */
+  learning: number;
+  /** 今日到期的複習卡片 */
+  due: number;
+}
+
+/** 牌組與卡片數量；`counts` 只算牌組本身，`total` 包含子牌組 */
+export interface DeckSummary extends Deck {
+  counts: DeckCounts;
+  total: DeckCounts;
+}
+
 export interface WordCard {
   id?: number;
   word: string;
   pos?: string;
   definition: string;
-  pronunciation?: string;
-  verbs?: string;
+  /** 由後端依單字與詞性產生 */
+  verbs?: WordForms | null;
   familiarity: number;
   seen_count: number;
   created_at?: string;
   updated_at?: string;
+  ease_factor?: number;
+  interval_days?: number;
+  repetitions?: number;
+  due_at?: string | null;
+  word_key?: string | null;
+  /** 結構化的詞義；`definition` 只是由詞義產生的檢索用文字 */
+  senses?: Sense[];
+  pronunciations?: Pronunciation[];
+  tags?: Tag[];
+  deck_id?: number;
+  /** 學習步驟中的位置；不在學習步驟中時為 null */
+  learning_step?: number | null;
+  /** 在匯入的檔案與文字中累計出現的次數 */
+  occurrence_count?: number;
 }
 
+/** 查詢單字時的比對方式：完全相同，或查詢的是變化形而比對到詞元 */
+export type WordMatch = 'exact' | 'lemma';
+
+/** `get_word_card_by_word` 的結果 */
+export interface WordCardLookup extends WordCard {
+  /** 查詢時輸入的詞形，例如以 "running" 查到 "run" 時為 "running" */
+  surface_form: string;
+  matched_by: WordMatch;
+}
+
+/** 保存已存在單字時的合併策略 */
+export type MergePolicy = 'keep_existing' | 'overwrite' | 'merge_definitions';
+
+export type SaveOutcome = 'inserted' | 'updated' | 'merged';
+
+export interface SaveWordCardResult {
+  outcome: SaveOutcome;
+  card: WordCard;
+}
+
+/** 複習評分：0 Again、1 Hard、2 Good、3 Easy */
+export type ReviewGrade = 0 | 1 | 2 | 3;
+
 export interface WordCardPayload {
   word: string;
   pos: string;
-  definition: string;
-  pronunciation: string;
-  verbs: string;
+  senses: Sense[];
+  pronunciations: Pronunciation[];
   familiarity: number;
   seen_count: number;
 }
+
+export interface ReviewQueueOptions {
+  new_cards_per_day?: number;
+  reviews_per_day?: number;
+  /** 只複習此牌組及其子牌組；未指定上限時使用該牌組的設定 */
+  deck_id?: number;
+}
+
+export interface ReviewQueue {
+  cards: WordCard[];
+  new_count: number;
+  review_count: number;
+  new_done_today: number;
+  reviews_done_today: number;
+}
+
+export interface WordCardFilter {
+  text?: string;
+  familiarity?: number[];
+  created_from?: string;
+  created_to?: string;
+  deck_ids?: number[];
+  tags?: TagExpression;
+}
+
+export interface ListWordCardsQuery {
+  filter?: WordCardFilter;
+  sort_key?: 'word' | 'familiarity' | 'created_at';
+  sort_direction?: 'asc' | 'desc';
+  offset?: number;
+  limit?: number;
+}
+
+export interface WordCardPage {
+  items: WordCard[];
+  total: number;
+  offset: number;
+  limit: number;
+}
+
+export interface WordCardSearchHit {
+  card: WordCard;
+  score: number;
+  /** 定義中的命中摘要，命中詞以 <mark> 標示 */
+  snippet: string;
+}
+
+export interface WordSuggestion {
+  word: string;
+  distance: number;
+  phonetic_match: boolean;
+  source: 'card' | 'dictionary';
+  card_id?: number | null;
+}
@@ -1,2 +1,31 @@
-// Tauri integration utilities will be defined here
-export {}
\ No newline at end of file
+import type { AppError, AppErrorCode } from '$lib/domain/types/appError';
+
+/** 保留後端錯誤代碼的 Error，前端可依 `code` 判斷錯誤種類 */
+export class CommandError extends Error {
+  readonly code: AppErrorCode | null;
+  readonly details: string | null;
+
+  constructor(message: string, code: AppErrorCode | null = null, details: string | null = null) {
+    super(message);
+    this.name = 'CommandError';
+    this.code = code;
+    this.details = details;
+  }
+}
+
+export function isAppError(value: unknown): value is AppError {
+  return (
+    typeof value === 'object' &&
+    value !== null &&
+    typeof (value as AppError).code === 'string' &&
+    typeof (value as AppError).message === 'string'
+  );
+}
+
+/** 將 invoke 拋出的錯誤轉為 CommandError，並在訊息前加上操作說明 */
+export function toCommandError(error: unknown, context: string): CommandError {
+  if (isAppError(error)) {
+    return new CommandError(`${context}: ${error.message}`, error.code, error.details);
+  }
+  return new CommandError(`${context}: ${error}`);
+}
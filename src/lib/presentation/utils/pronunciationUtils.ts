@@ -1,52 +1,50 @@
+import type { Pronunciation } from '$lib/domain/types/wordCard';
+
 export interface PronunciationData {
   phonetic?: string;
   audio?: string;
 }
 
 /**
- * Parse pronunciation JSON string safely
+ * Pick the phonetic and audio to display from a card's pronunciations
  */
-export function parsePronunciation(json: string | null | undefined): PronunciationData | null {
-  try {
-    return json ? JSON.parse(json) : null;
-  } catch (error) {
-    console.warn('Failed to parse pronunciation data:', error);
-    return null;
-  }
+export function parsePronunciation(
+  pronunciations: Pronunciation[] | null | undefined
+): PronunciationData | null {
+  if (!pronunciations || pronunciations.length === 0) return null;
+  return {
+    phonetic: pronunciations.find((p) => p.ipa)?.ipa ?? undefined,
+    audio: pronunciations.find((p) => p.audio_url)?.audio_url ?? undefined,
+  };
 }
 
 /**
- * Stringify pronunciation data safely
+ * Build the pronunciations payload from phonetic and audio data
  */
-export function stringifyPronunciation(data: PronunciationData): string {
-  try {
-    return JSON.stringify(data);
-  } catch (error) {
-    console.warn('Failed to stringify pronunciation data:', error);
-    return JSON.stringify({});
-  }
+export function toPronunciations(data: PronunciationData): Pronunciation[] {
+  const ipa = data.phonetic?.trim() || null;
+  const audio_url = data.audio?.trim() || null;
+  if (!ipa && !audio_url) return [];
+  return [{ region: null, ipa, audio_url, local_path: null }];
 }
 
 /**
  * Check if pronunciation data has audio
  */
-export function hasAudio(pronunciation: string | null | undefined): boolean {
-  const data = parsePronunciation(pronunciation);
-  return Boolean(data?.audio);
+export function hasAudio(pronunciations: Pronunciation[] | null | undefined): boolean {
+  return Boolean(parsePronunciation(pronunciations)?.audio);
 }
 
 /**
  * Get phonetic from pronunciation data
  */
-export function getPhonetic(pronunciation: string | null | undefined): string {
-  const data = parsePronunciation(pronunciation);
-  return data?.phonetic || '';
+export function getPhonetic(pronunciations: Pronunciation[] | null | undefined): string {
+  return parsePronunciation(pronunciations)?.phonetic || '';
 }
 
 /**
  * Get audio URL from pronunciation data
  */
-export function getAudioUrl(pronunciation: string | null | undefined): string {
-  const data = parsePronunciation(pronunciation);
-  return data?.audio || '';
+export function getAudioUrl(pronunciations: Pronunciation[] | null | undefined): string {
+  return parsePronunciation(pronunciations)?.audio || '';
 }